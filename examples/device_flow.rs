@@ -27,7 +27,7 @@ async fn main() -> octocrab::Result<()> {
                 octocrab::auth::Continue::SlowDown => {
                     // We were request to slow down. We add five seconds to the polling
                     // duration.
-                    interval += Duration::from_secs(5);
+                    interval = cont.next_interval(interval);
                     clock = tokio::time::interval(interval);
                     // The first tick happens instantly, so we tick that off immediately.
                     clock.tick().await;
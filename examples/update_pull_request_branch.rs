@@ -18,9 +18,10 @@ async fn main() -> octocrab::Result<()> {
     let update = octocrab
         .pulls("XAMPPRocky", "octocrab")
         .update_branch(200)
+        .send()
         .await?;
 
-    println!("Result of pull request update: {update}",);
+    println!("Result of pull request update: {update:?}",);
 
     Ok(())
 }
@@ -14,6 +14,7 @@ pub mod orgs;
 pub mod projects;
 pub mod pulls;
 pub mod ratelimit;
+mod reactions;
 pub mod repos;
 pub mod search;
 pub mod teams;
@@ -13,17 +13,24 @@ use crate::models::{
     workflows::WorkflowDispatch, workflows::WorkflowListArtifact, ArtifactId, RepositoryId, RunId,
 };
 use crate::models::{RunnerGroupId, RunnerId};
-use crate::{params, FromResponse, Octocrab, Page};
-use http::request::Builder;
-use http::{header::HeaderMap, Method, StatusCode, Uri};
+use crate::{params, Octocrab, Page};
+use http::Uri;
 
+#[derive(serde::Serialize)]
 pub struct ListWorkflowRunArtifacts<'octo> {
+    #[serde(skip)]
     crab: &'octo Octocrab,
+    #[serde(skip)]
     owner: String,
+    #[serde(skip)]
     repo: String,
+    #[serde(skip)]
     run_id: RunId,
+    #[serde(skip_serializing_if = "Option::is_none")]
     per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     page: Option<u32>,
+    #[serde(skip)]
     etag: Option<EntityTag>,
 }
 
@@ -59,36 +66,15 @@ impl<'octo> ListWorkflowRunArtifacts<'octo> {
     }
 
     pub async fn send(self) -> crate::Result<Etagged<Page<WorkflowListArtifact>>> {
-        let path = format!(
+        let route = format!(
             "/repos/{owner}/{repo}/actions/runs/{run_id}/artifacts",
             owner = self.owner,
             repo = self.repo,
             run_id = self.run_id
         );
-        let uri = Uri::builder()
-            .path_and_query(path)
-            .build()
-            .context(HttpSnafu)?;
-        let mut headers = HeaderMap::new();
-        if let Some(etag) = self.etag {
-            EntityTag::insert_if_none_match_header(&mut headers, etag)?;
-        }
-
-        let request = self
-            .crab
-            .build_request(Builder::new().method(Method::GET).uri(uri), None::<&()>)?;
-        let response = self.crab.execute(request).await?;
-        let etag = EntityTag::extract_from_response(&response);
-        if response.status() == StatusCode::NOT_MODIFIED {
-            Ok(Etagged { etag, value: None })
-        } else {
-            <Page<WorkflowListArtifact>>::from_response(crate::map_github_error(response).await?)
-                .await
-                .map(|page| Etagged {
-                    etag,
-                    value: Some(page),
-                })
-        }
+        self.crab
+            .get_etagged(route, Some(&self), self.etag.clone())
+            .await
     }
 }
 
@@ -154,7 +140,9 @@ impl<'octo> WorkflowDispatchBuilder<'octo> {
 
 /// Handler for GitHub's actions API.
 ///
-/// Created with [`Octocrab::actions`].
+/// Created with [`Octocrab::actions`]. Workflow runs are listed through
+/// [`WorkflowsHandler::list_runs`](crate::workflows::WorkflowsHandler::list_runs)
+/// and [`WorkflowsHandler::list_all_runs`](crate::workflows::WorkflowsHandler::list_all_runs).
 pub struct ActionsHandler<'octo> {
     crab: &'octo Octocrab,
 }
@@ -280,7 +268,8 @@ impl<'octo> ActionsHandler<'octo> {
     }
 
     /// Downloads and returns the raw data representing a zip of the logs from
-    /// the workflow run specified by `run_id`.
+    /// the workflow run specified by `run_id`. GitHub redirects to a storage
+    /// host, which is sent the request without the `Authorization` header.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
     /// octocrab::instance()
@@ -313,7 +302,8 @@ impl<'octo> ActionsHandler<'octo> {
     }
 
     /// Downloads and returns the raw data representing an artifact from a
-    /// repository.
+    /// repository. Like [`Self::download_workflow_run_logs`], the redirect to
+    /// the storage host is followed without the `Authorization` header.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
     /// use octocrab::params::actions::ArchiveFormat;
@@ -405,6 +395,98 @@ impl<'octo> ActionsHandler<'octo> {
         self.crab.get(route, None::<&()>).await
     }
 
+    /// Lists the names of a repository's secrets along with when they were
+    /// created and updated. The values of secrets are never returned.
+    /// Shorthand for [`RepoSecretsHandler::get_secrets`].
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let secrets = octocrab.actions().list_repo_secrets("owner", "repo").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RepoSecretsHandler::get_secrets`]: crate::repos::RepoSecretsHandler::get_secrets
+    pub async fn list_repo_secrets(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+    ) -> crate::Result<crate::models::repos::secrets::RepositorySecrets> {
+        self.crab.repos(owner, repo).secrets().get_secrets().await
+    }
+
+    /// Get a repository's public key, which you need to encrypt secret values
+    /// before creating or updating secrets. Shorthand for
+    /// [`RepoSecretsHandler::get_public_key`].
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let public_key = octocrab.actions().get_repo_public_key("owner", "repo").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RepoSecretsHandler::get_public_key`]: crate::repos::RepoSecretsHandler::get_public_key
+    pub async fn get_repo_public_key(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+    ) -> crate::Result<crate::models::PublicKey> {
+        self.crab
+            .repos(owner, repo)
+            .secrets()
+            .get_public_key()
+            .await
+    }
+
+    /// Creates or updates a repository secret from its plaintext `value`.
+    /// The repository's public key is fetched first and used to encrypt the
+    /// value locally, so the plaintext is never sent to GitHub.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// use octocrab::models::repos::secrets::CreateRepositorySecretResponse;
+    ///
+    /// let result = octocrab
+    ///     .actions()
+    ///     .create_or_update_repo_secret("owner", "repo", "GH_TOKEN", "Very secret value")
+    ///     .await?;
+    ///
+    /// match result {
+    ///     CreateRepositorySecretResponse::Created => println!("Created secret!"),
+    ///     CreateRepositorySecretResponse::Updated => println!("Updated secret!"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "secrets-encryption")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "secrets-encryption")))]
+    pub async fn create_or_update_repo_secret(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        secret_name: impl AsRef<str>,
+        value: impl AsRef<[u8]>,
+    ) -> crate::Result<crate::models::repos::secrets::CreateRepositorySecretResponse> {
+        let repo = self.crab.repos(owner, repo);
+        let secrets = repo.secrets();
+        let public_key = secrets.get_public_key().await?;
+        let encrypted_value = public_key.encrypt(value.as_ref())?;
+
+        secrets
+            .create_or_update_secret(
+                secret_name,
+                &crate::models::repos::secrets::CreateRepositorySecret {
+                    encrypted_value: &encrypted_value,
+                    key_id: &public_key.key_id,
+                },
+            )
+            .await
+    }
+
     /// Lists artifacts for a workflow run. Anyone with read access to the
     /// repository can use this endpoint. If the repository is private you
     /// must use an access token with the `repo` scope. GitHub Apps must have
@@ -20,4 +20,55 @@ impl<'octo> ActivityHandler<'octo> {
     pub fn notifications(&self) -> notifications::NotificationsHandler<'octo> {
         notifications::NotificationsHandler::new(self.crab)
     }
+
+    /// Stars a repository for the authenticated user.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance().activity().star("owner", "repo").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn star(&self, owner: impl AsRef<str>, repo: impl AsRef<str>) -> crate::Result<()> {
+        let route = starred_route(owner.as_ref(), repo.as_ref());
+        crate::map_github_error(self.crab._put(route, None::<&()>).await?)
+            .await
+            .map(drop)
+    }
+
+    /// Unstars a repository for the authenticated user.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance().activity().unstar("owner", "repo").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn unstar(&self, owner: impl AsRef<str>, repo: impl AsRef<str>) -> crate::Result<()> {
+        let route = starred_route(owner.as_ref(), repo.as_ref());
+        crate::map_github_error(self.crab._delete(route, None::<&()>).await?)
+            .await
+            .map(drop)
+    }
+
+    /// Checks whether the authenticated user has starred a repository.
+    /// GitHub answers with `404 Not Found` when it isn't starred, which is
+    /// returned as `false` rather than an error.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let starred = octocrab::instance().activity().is_starred("owner", "repo").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn is_starred(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+    ) -> crate::Result<bool> {
+        let route = starred_route(owner.as_ref(), repo.as_ref());
+        let response = self.crab._get(route).await?;
+        crate::exists_via_status(response).await
+    }
+}
+
+fn starred_route(owner: &str, repo: &str) -> String {
+    format!("/user/starred/{owner}/{repo}")
 }
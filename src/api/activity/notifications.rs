@@ -109,6 +109,10 @@ impl<'octo> NotificationsHandler<'octo> {
     /// If you provide a `last_read_at` parameter,
     /// anything updated since this time will not be marked as read.
     ///
+    /// GitHub answers `205 Reset Content` when the notifications were marked
+    /// right away, and `202 Accepted` when there are too many and it does so
+    /// in the background. Both are treated as success.
+    ///
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
     /// octocrab::instance()
@@ -186,7 +190,7 @@ impl<'octo> NotificationsHandler<'octo> {
         let route = format!("/notifications/threads/{thread}/subscription");
         let body = Inner { ignored };
 
-        self.crab.get(route, Some(&body)).await
+        self.crab.put(route, Some(&body)).await
     }
 
     /// Mutes the whole thread conversation until you comment or get mentioned.
@@ -253,7 +257,7 @@ impl<'octo> NotificationsHandler<'octo> {
     }
 }
 
-/// A builder pattern struct for listing pull requests.
+/// A builder pattern struct for listing notifications.
 ///
 /// Created by [`NotificationsHandler::list`].
 ///
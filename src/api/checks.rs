@@ -3,11 +3,27 @@ use chrono::{DateTime, Utc};
 use crate::models::checks::{AutoTriggerCheck, CheckSuite, CheckSuitePreferences};
 use crate::models::{AppId, CheckRunId, CheckSuiteId};
 use crate::params::checks::{
-    CheckRunAnnotation, CheckRunConclusion, CheckRunOutput, CheckRunStatus,
+    CheckRunAnnotation, CheckRunConclusion, CheckRunOutput, CheckRunOutputAnnotation,
+    CheckRunStatus,
 };
 use crate::params::repos::Commitish;
 use crate::{models, Octocrab, Result};
 
+/// The most annotations GitHub accepts in a single check run request.
+const MAX_ANNOTATIONS_PER_REQUEST: usize = 50;
+
+/// Splits off the annotations of `output` that don't fit in one request.
+fn split_off_extra_annotations(
+    output: &mut Option<CheckRunOutput>,
+) -> Vec<CheckRunOutputAnnotation> {
+    match output {
+        Some(output) if output.annotations.len() > MAX_ANNOTATIONS_PER_REQUEST => {
+            output.annotations.split_off(MAX_ANNOTATIONS_PER_REQUEST)
+        }
+        _ => Vec::new(),
+    }
+}
+
 /// Handler for GitHub's Checks API.
 ///
 /// Created with [`Octocrab::checks`].
@@ -87,19 +103,28 @@ impl<'octo, 'r> CreateCheckRunBuilder<'octo, 'r> {
     /// Check runs can accept a variety of data in the output object,
     /// including a title and summary and can optionally provide
     /// descriptive details about the run.
+    ///
+    /// GitHub accepts at most 50 annotations per request, so any further
+    /// annotations are sent in follow-up updates of the check run, along
+    /// with the same title, summary and text.
     pub fn output(mut self, output: CheckRunOutput) -> Self {
         self.output = Some(output);
         self
     }
 
     /// Sends the actual request.
-    pub async fn send(self) -> Result<models::checks::CheckRun> {
+    pub async fn send(mut self) -> Result<models::checks::CheckRun> {
         let route = format!(
             "/repos/{owner}/{repo}/check-runs",
             owner = self.handler.owner,
             repo = self.handler.repo
         );
-        self.handler.crab.post(route, Some(&self)).await
+        let annotations = split_off_extra_annotations(&mut self.output);
+        let check_run: models::checks::CheckRun =
+            self.handler.crab.post(route, Some(&self)).await?;
+        self.handler
+            .add_annotations(check_run, &self.output, annotations)
+            .await
     }
 }
 
@@ -189,20 +214,29 @@ impl<'octo, 'r> UpdateCheckRunBuilder<'octo, 'r> {
     /// Check runs can accept a variety of data in the output object,
     /// including a title and summary and can optionally provide
     /// descriptive details about the run.
+    ///
+    /// GitHub accepts at most 50 annotations per request, so any further
+    /// annotations are sent in follow-up updates of the check run, along
+    /// with the same title, summary and text.
     pub fn output(mut self, output: CheckRunOutput) -> Self {
         self.output = Some(output);
         self
     }
 
     /// Sends the actual request.
-    pub async fn send(self) -> Result<models::checks::CheckRun> {
+    pub async fn send(mut self) -> Result<models::checks::CheckRun> {
         let route = format!(
             "/repos/{owner}/{repo}/check-runs/{check_run_id}",
             owner = self.handler.owner,
             repo = self.handler.repo,
             check_run_id = self.check_run_id
         );
-        self.handler.crab.patch(route, Some(&self)).await
+        let annotations = split_off_extra_annotations(&mut self.output);
+        let check_run: models::checks::CheckRun =
+            self.handler.crab.patch(route, Some(&self)).await?;
+        self.handler
+            .add_annotations(check_run, &self.output, annotations)
+            .await
     }
 }
 
@@ -368,6 +402,46 @@ impl<'octo> ChecksHandler<'octo> {
         Self { crab, owner, repo }
     }
 
+    /// Adds `annotations` to `check_run` in chunks GitHub accepts, returning
+    /// the check run as of the last update.
+    async fn add_annotations(
+        &self,
+        mut check_run: models::checks::CheckRun,
+        output: &Option<CheckRunOutput>,
+        mut annotations: Vec<CheckRunOutputAnnotation>,
+    ) -> Result<models::checks::CheckRun> {
+        let Some(output) = output else {
+            return Ok(check_run);
+        };
+        let route = format!(
+            "/repos/{owner}/{repo}/check-runs/{check_run_id}",
+            owner = self.owner,
+            repo = self.repo,
+            check_run_id = check_run.id
+        );
+
+        while !annotations.is_empty() {
+            let rest = annotations.split_off(annotations.len().min(MAX_ANNOTATIONS_PER_REQUEST));
+            let chunk = std::mem::replace(&mut annotations, rest);
+            // GitHub replaces the title, summary and text on every update
+            // but adds annotations and images to those already sent, so the
+            // images only go out with the first request.
+            let output = CheckRunOutput {
+                title: output.title.clone(),
+                summary: output.summary.clone(),
+                text: output.text.clone(),
+                annotations: chunk,
+                images: Vec::new(),
+            };
+            check_run = self
+                .crab
+                .patch(&route, Some(&serde_json::json!({ "output": output })))
+                .await?;
+        }
+
+        Ok(check_run)
+    }
+
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
     /// let check_runs = octocrab::instance()
@@ -9,6 +9,7 @@ pub use associated_pull_requests::PullRequestTarget;
 pub use self::create_comment::CreateCommentBuilder;
 use crate::params::repos::Reference;
 use crate::{models, Octocrab, Result};
+use snafu::ResultExt;
 
 pub struct CommitHandler<'octo> {
     crab: &'octo Octocrab,
@@ -25,6 +26,19 @@ impl<'octo> CommitHandler<'octo> {
     //     create::CreateIssueBuilder::new(self, title.into())
     // }
 
+    /// Compare two commits, branches or tags, e.g. to list the commits
+    /// between two releases.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let comparison = octocrab::instance()
+    ///     .commits("owner", "repo")
+    ///     .compare("v1.0.0", "v1.1.0")
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn compare(
         &self,
         base: impl Into<String>,
@@ -55,13 +69,63 @@ impl<'octo> CommitHandler<'octo> {
         create_comment::CreateCommentBuilder::new(self, sha.into(), body.into())
     }
 
+    /// Get a single commit, including its `stats` and the `files` it changed.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let commit = octocrab::instance().commits("owner", "repo").get("main").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub async fn get(&self, reference: impl Into<String>) -> Result<models::repos::RepoCommit> {
-        let route = format!(
+        self.crab
+            .get(self.route(reference.into()), None::<&()>)
+            .await
+    }
+
+    /// Get a single commit's `diff`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let diff = octocrab::instance().commits("owner", "repo").get_diff("main").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_diff(&self, reference: impl Into<String>) -> Result<String> {
+        self.get_as_text(reference.into(), "diff").await
+    }
+
+    /// Get a single commit's patch.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let patch = octocrab::instance().commits("owner", "repo").get_patch("main").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_patch(&self, reference: impl Into<String>) -> Result<String> {
+        self.get_as_text(reference.into(), "patch").await
+    }
+
+    /// Gets a commit in a non-JSON `media_type`, returning the response body
+    /// as is.
+    async fn get_as_text(&self, reference: String, media_type: &str) -> Result<String> {
+        let uri = http::Uri::builder()
+            .path_and_query(self.route(reference))
+            .build()
+            .context(crate::error::HttpSnafu)?;
+        let request = http::request::Builder::new()
+            .method(http::Method::GET)
+            .uri(uri)
+            .header(http::header::ACCEPT, crate::format_media_type(media_type));
+        let request = self.crab.build_request(request, None::<&()>)?;
+        let response = crate::map_github_error(self.crab.execute(request).await?).await?;
+
+        self.crab.body_to_string(response).await
+    }
+
+    fn route(&self, reference: String) -> String {
+        format!(
             "/repos/{owner}/{repo}/commits/{reference}",
             owner = self.owner,
             repo = self.repo,
-            reference = reference.into(),
-        );
-        self.crab.get(route, None::<&()>).await
+        )
     }
 }
@@ -4,8 +4,12 @@ use super::*;
 pub struct CompareCommitsBuilder<'octo, 'r> {
     #[serde(skip)]
     handler: &'r super::CommitHandler<'octo>,
+    #[serde(skip)]
     base: String,
+    #[serde(skip)]
     head: String,
+    #[serde(skip)]
+    two_dot: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     per_page: Option<u8>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -22,12 +26,21 @@ impl<'octo, 'r> CompareCommitsBuilder<'octo, 'r> {
             handler,
             base,
             head,
+            two_dot: false,
             page: None,
             per_page: None,
         }
     }
 
-    /// Results per page (max 100).
+    /// Compare `base..head` instead of `base...head`, i.e. diff `head`
+    /// against `base` itself rather than against their merge base.
+    pub fn two_dot(mut self, two_dot: bool) -> Self {
+        self.two_dot = two_dot;
+        self
+    }
+
+    /// Results per page (max 100). This pages through the `commits` of the
+    /// comparison; `files` are only included in the first page.
     pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
         self.per_page = Some(per_page.into());
         self
@@ -42,10 +55,11 @@ impl<'octo, 'r> CompareCommitsBuilder<'octo, 'r> {
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<models::commits::CommitComparison> {
         let route = format!(
-            "/repos/{owner}/{repo}/compare/{base}...{head}",
+            "/repos/{owner}/{repo}/compare/{base}{separator}{head}",
             owner = self.handler.owner,
             repo = self.handler.repo,
             base = self.base,
+            separator = if self.two_dot { ".." } else { "..." },
             head = self.head,
         );
 
@@ -60,13 +74,17 @@ mod tests {
     async fn compare_commits_serializes_correctly() {
         let octocrab = crate::Octocrab::default();
         let handler = octocrab.commits("owner", "repo");
-        let comparison = handler.compare("base", "head");
+        let comparison = handler
+            .compare("base", "head")
+            .two_dot(true)
+            .per_page(100)
+            .page(2u32);
 
         assert_eq!(
             serde_json::to_value(comparison).unwrap(),
             serde_json::json!({
-                "base": "base",
-                "head": "head",
+                "per_page": 100,
+                "page": 2,
             })
         );
     }
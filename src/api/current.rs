@@ -1,5 +1,6 @@
 //! Get data about the currently authenticated user.
 
+use crate::repos::CreateRepositoryBuilder;
 use crate::{
     models::{self, gists::Gist, orgs::MembershipInvitation, Installation, Repository},
     Octocrab, Page, Result,
@@ -21,8 +22,45 @@ impl<'octo> CurrentAuthHandler<'octo> {
     }
 
     /// Fetches information about the current user.
+    ///
+    /// Returns an [`Error::MissingAuth`](crate::Error::MissingAuth) if GitHub
+    /// rejects the request because the client has no credentials.
     pub async fn user(&self) -> Result<models::Author> {
-        self.crab.get("/user", None::<&()>).await
+        self.get_authenticated("/user").await
+    }
+
+    /// Fetches the full profile of the current user, including their `bio`,
+    /// `company` and follower counts.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let profile = octocrab::instance().current().profile().await?;
+    /// println!("{} has {} followers", profile.login, profile.followers);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Returns an [`Error::MissingAuth`](crate::Error::MissingAuth) if GitHub
+    /// rejects the request because the client has no credentials.
+    pub async fn profile(&self) -> Result<models::UserProfile> {
+        self.get_authenticated("/user").await
+    }
+
+    /// Sends a `GET` request to an endpoint that requires authentication,
+    /// turning the `401 Unauthorized` GitHub returns to a client without
+    /// credentials into an [`Error::MissingAuth`](crate::Error::MissingAuth).
+    /// Invalid credentials are still returned as an
+    /// [`Error::GitHub`](crate::Error::GitHub).
+    async fn get_authenticated<R: crate::FromResponse>(&self, route: &str) -> Result<R> {
+        match self.crab.get(route, None::<&()>).await {
+            Err(crate::Error::GitHub { source, .. })
+                if source.status_code == http::StatusCode::UNAUTHORIZED
+                    && !self.crab.sends_credentials() =>
+            {
+                crate::error::MissingAuthSnafu.fail()
+            }
+            result => result,
+        }
     }
 
     /// Fetches information about the currently authenticated app.
@@ -80,6 +118,26 @@ impl<'octo> CurrentAuthHandler<'octo> {
         ListReposForAuthenticatedUserBuilder::new(self.crab)
     }
 
+    /// Creates a new repository named `name` owned by the authenticated user.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let repo = octocrab::instance()
+    ///     .current()
+    ///     .create_repo("octocrab")
+    ///     .private(true)
+    ///     .auto_init(true)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [See the GitHub API documentation](https://docs.github.com/en/rest/repos/repos#create-a-repository-for-the-authenticated-user)
+    pub fn create_repo(&self, name: impl Into<String>) -> CreateRepositoryBuilder<'octo> {
+        CreateRepositoryBuilder::new(self.crab, "/user/repos".to_string(), name.into())
+    }
+
     /// List gists for the current authenticated user.
     ///
     /// # Examples
@@ -163,6 +221,101 @@ impl<'octo> CurrentAuthHandler<'octo> {
     ) -> ListOrgMembershipsForAuthenticatedUserBuilder<'octo> {
         ListOrgMembershipsForAuthenticatedUserBuilder::new(self.crab)
     }
+
+    /// Lists the open invitations for the current user to collaborate on
+    /// repositories.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let invitations = octocrab::instance()
+    ///     .current()
+    ///     .list_repository_invitations()
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [See the GitHub API documentation](https://docs.github.com/en/rest/collaborators/invitations#list-repository-invitations-for-the-authenticated-user)
+    pub fn list_repository_invitations(&self) -> ListRepositoryInvitationsBuilder<'octo> {
+        ListRepositoryInvitationsBuilder::new(self.crab)
+    }
+
+    /// Accepts an invitation to collaborate on a repository.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .current()
+    ///     .accept_invitation(1u64)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [See the GitHub API documentation](https://docs.github.com/en/rest/collaborators/invitations#accept-a-repository-invitation)
+    pub async fn accept_invitation(
+        &self,
+        invitation_id: impl Into<models::RepoInvitationId>,
+    ) -> Result<()> {
+        let route = Self::invitation_route(invitation_id.into());
+        crate::map_github_error(self.crab._patch(route, None::<&()>).await?)
+            .await
+            .map(drop)
+    }
+
+    /// Declines an invitation to collaborate on a repository.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .current()
+    ///     .decline_invitation(1u64)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [See the GitHub API documentation](https://docs.github.com/en/rest/collaborators/invitations#decline-a-repository-invitation)
+    pub async fn decline_invitation(
+        &self,
+        invitation_id: impl Into<models::RepoInvitationId>,
+    ) -> Result<()> {
+        let route = Self::invitation_route(invitation_id.into());
+        crate::map_github_error(self.crab._delete(route, None::<&()>).await?)
+            .await
+            .map(drop)
+    }
+
+    /// Lists the repositories the current installation can access. The
+    /// page's `total_count` is set from the response.
+    ///
+    /// Only available when the client is authenticated as an installation,
+    /// e.g. with [`Octocrab::installation`]; otherwise `send` fails with an
+    /// [`Error::Other`](crate::Error::Other) before sending the request.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let repos = octocrab
+    ///     .installation(1.into())
+    ///     .current()
+    ///     .list_installation_repos()
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [See the GitHub API documentation](https://docs.github.com/en/rest/apps/installations#list-repositories-accessible-to-the-app-installation)
+    pub fn list_installation_repos(&self) -> ListInstallationReposBuilder<'octo> {
+        ListInstallationReposBuilder::new(self.crab)
+    }
+
+    fn invitation_route(invitation_id: models::RepoInvitationId) -> String {
+        format!("/user/repository_invitations/{invitation_id}")
+    }
 }
 
 /// A builder pattern struct for listing starred repositories.
@@ -587,3 +740,101 @@ impl<'octo> ListAppInstallationsAccessibleToUserBuilder<'octo> {
         self.crab.get("/user/installations", (&self).into()).await
     }
 }
+
+/// A builder pattern struct for listing the repository invitations of the
+/// current user.
+///
+/// Created by [`CurrentAuthHandler::list_repository_invitations`].
+#[derive(serde::Serialize)]
+pub struct ListRepositoryInvitationsBuilder<'octo> {
+    #[serde(skip)]
+    crab: &'octo Octocrab,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u8>,
+}
+
+impl<'octo> ListRepositoryInvitationsBuilder<'octo> {
+    fn new(crab: &'octo Octocrab) -> Self {
+        Self {
+            crab,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Page<models::repos::RepoInvitation>> {
+        self.crab
+            .get("/user/repository_invitations", Some(&self))
+            .await
+    }
+}
+
+/// A builder pattern struct for listing the repositories accessible to the
+/// current installation.
+///
+/// Created by [`CurrentAuthHandler::list_installation_repos`].
+#[derive(serde::Serialize)]
+pub struct ListInstallationReposBuilder<'octo> {
+    #[serde(skip)]
+    crab: &'octo Octocrab,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u8>,
+}
+
+impl<'octo> ListInstallationReposBuilder<'octo> {
+    fn new(crab: &'octo Octocrab) -> Self {
+        Self {
+            crab,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Page<Repository>> {
+        if !matches!(self.crab.auth_state, crate::AuthState::Installation { .. }) {
+            return Err(crate::Error::Other {
+                source: "listing installation repositories requires the client to be \
+                         authenticated as an installation, see `Octocrab::installation`"
+                    .into(),
+                backtrace: snafu::GenerateImplicitData::generate(),
+            });
+        }
+        self.crab
+            .get("/installation/repositories", Some(&self))
+            .await
+    }
+}
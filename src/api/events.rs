@@ -3,10 +3,8 @@
 use crate::{
     etag::{EntityTag, Etagged},
     models::events,
-    FromResponse, Octocrab, Page,
+    Octocrab, Page,
 };
-use http::request::Builder;
-use http::{header::HeaderMap, Method, StatusCode};
 
 pub struct EventsBuilder<'octo> {
     crab: &'octo Octocrab,
@@ -58,30 +56,8 @@ impl<'octo> EventsBuilder<'octo> {
 
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<Etagged<Page<events::Event>>> {
-        let route = "/events".to_string();
-        let uri = self.crab.parameterized_uri(route, Some(&self.params))?;
-
-        let mut headers = HeaderMap::new();
-        if let Some(etag) = self.headers.etag {
-            EntityTag::insert_if_none_match_header(&mut headers, etag)?;
-        }
-        let mut builder = Builder::new().method(Method::GET).uri(uri);
-        for (key, value) in headers.iter() {
-            builder = builder.header(key, value);
-        }
-        let request = self.crab.build_request(builder, None::<&()>)?;
-
-        let response = self.crab.execute(request).await?;
-        let etag = EntityTag::extract_from_response(&response);
-        if response.status() == StatusCode::NOT_MODIFIED {
-            Ok(Etagged { etag, value: None })
-        } else {
-            <Page<events::Event>>::from_response(crate::map_github_error(response).await?)
-                .await
-                .map(|page| Etagged {
-                    etag,
-                    value: Some(page),
-                })
-        }
+        self.crab
+            .get_etagged("/events", Some(&self.params), self.headers.etag)
+            .await
     }
 }
@@ -269,12 +269,7 @@ impl<'octo> GistsHandler<'octo> {
         let gist_id = gist_id.as_ref();
         let response = self.crab._get(format!("/gists/{gist_id}/star")).await?;
         // Gist API returns 204 (NO CONTENT) if a gist is starred
-
-        match response.status() {
-            StatusCode::NO_CONTENT => Ok(true),
-            StatusCode::NOT_FOUND => Ok(false),
-            _ => Err(crate::map_github_error(response).await.unwrap_err()),
-        }
+        crate::exists_via_status(response).await
     }
 
     /// Star the given gist. See [GitHub API Documentation][docs] more
@@ -466,7 +461,18 @@ struct UpdateGist {
     #[serde(skip_serializing_if = "Option::is_none")]
     description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    files: Option<BTreeMap<String, Option<UpdateGistFile>>>,
+    files: Option<BTreeMap<String, GistFileChange>>,
+}
+
+/// What to do with a file of a gist being updated. Files without a change
+/// are left out of the request and stay as they are.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+enum GistFileChange {
+    /// Sent as `null`, which GitHub takes as deleting the file.
+    Delete,
+    /// Rename the file and/or overwrite its content.
+    Update(UpdateGistFile),
 }
 
 #[derive(Debug, Default, Serialize)]
@@ -481,7 +487,6 @@ pub struct UpdateGistFileBuilder<'octo> {
     builder: UpdateGistBuilder<'octo>,
     filename: String,
     file: Option<UpdateGistFile>,
-    ready: bool,
 }
 
 impl<'octo> UpdateGistFileBuilder<'octo> {
@@ -490,39 +495,43 @@ impl<'octo> UpdateGistFileBuilder<'octo> {
             builder,
             filename: filename.into(),
             file: None,
-            ready: false,
         }
     }
 
     fn build(mut self) -> UpdateGistBuilder<'octo> {
-        if self.ready {
+        let change = self.file.take().map(GistFileChange::Update);
+        self.finish(change)
+    }
+
+    fn finish(mut self, change: Option<GistFileChange>) -> UpdateGistBuilder<'octo> {
+        if let Some(change) = change {
             self.builder
                 .data
                 .files
                 .get_or_insert_with(BTreeMap::new)
-                .insert(self.filename, self.file);
+                .insert(self.filename, change);
         }
         self.builder
     }
 
+    fn update(&mut self) -> &mut UpdateGistFile {
+        self.file.get_or_insert_with(Default::default)
+    }
+
     /// Delete the file from the gist.
-    pub fn delete(mut self) -> UpdateGistBuilder<'octo> {
-        self.ready = true;
-        self.file = None;
-        self.build()
+    pub fn delete(self) -> UpdateGistBuilder<'octo> {
+        self.finish(Some(GistFileChange::Delete))
     }
 
     /// Rename the file to `filename`.
     pub fn rename_to(mut self, filename: impl Into<String>) -> Self {
-        self.ready = true;
-        self.file.get_or_insert_with(Default::default).filename = Some(filename.into());
+        self.update().filename = Some(filename.into());
         self
     }
 
     /// Update the content of the file and overwrite it with `content`.
     pub fn with_content(mut self, content: impl Into<String>) -> Self {
-        self.ready = true;
-        self.file.get_or_insert_with(Default::default).content = Some(content.into());
+        self.update().content = Some(content.into());
         self
     }
 
@@ -547,3 +556,35 @@ impl<'octo> UpdateGistFileBuilder<'octo> {
         self.build().send().await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[tokio::test]
+    async fn serialize_update_distinguishes_deleted_and_untouched_files() {
+        let octocrab = crate::Octocrab::default();
+        let update = octocrab
+            .gists()
+            .update("aa5a315d61ae9438b18d")
+            .description("Updated!")
+            .file("hello_world.rs")
+            .rename_to("fibonacci.rs")
+            .with_content("fn main() {}")
+            .file("untouched.rs")
+            .file("delete_me.rs")
+            .delete();
+
+        assert_eq!(
+            serde_json::to_value(update.data).unwrap(),
+            serde_json::json!({
+                "description": "Updated!",
+                "files": {
+                    "hello_world.rs": {
+                        "filename": "fibonacci.rs",
+                        "content": "fn main() {}",
+                    },
+                    "delete_me.rs": null,
+                },
+            })
+        )
+    }
+}
@@ -1,6 +1,7 @@
 //! The issue API.
 
 mod create;
+mod labels;
 mod list;
 mod list_labels;
 mod update;
@@ -14,6 +15,7 @@ use snafu::ResultExt;
 
 pub use self::{
     create::CreateIssueBuilder,
+    labels::UpdateLabelBuilder,
     list::ListIssuesBuilder,
     list_labels::{ListLabelsForIssueBuilder, ListLabelsForRepoBuilder},
     update::UpdateIssueBuilder,
@@ -132,6 +134,8 @@ impl<'octo> IssueHandler<'octo> {
     }
 
     /// Users with push access can lock an issue or pull request's conversation.
+    /// `reason` may be `None` to lock it without giving a reason. Returns
+    /// whether GitHub answered with `204 No Content`.
     ///
     /// See also: https://docs.github.com/en/rest/issues/issues#lock-an-issue
     /// ```no_run
@@ -178,6 +182,7 @@ impl<'octo> IssueHandler<'octo> {
     }
 
     /// Users with push access can unlock an issue or pull request's conversation.
+    /// Returns whether GitHub answered with `204 No Content`.
     ///
     /// See also: https://docs.github.com/en/rest/issues/issues#unlock-an-issue
     /// ```no_run
@@ -209,6 +214,10 @@ impl<'octo> IssueHandler<'octo> {
 impl<'octo> IssueHandler<'octo> {
     /// Adds up to 10 assignees to an issue. Users already assigned to an issue
     /// are not replaced.
+    ///
+    /// GitHub silently ignores users who can't be assigned, e.g. because they
+    /// lack push access, so the returned issue may have fewer assignees than
+    /// requested. Use [`IssueHandler::check_assignee`] to check beforehand.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
     /// # let octocrab = octocrab::Octocrab::default();
@@ -233,6 +242,32 @@ impl<'octo> IssueHandler<'octo> {
             .await
     }
 
+    /// Removes assignees from an issue. Users who weren't assigned are
+    /// ignored, and the issue is returned unchanged for them.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let issue = octocrab.issues("owner", "repo").remove_assignees(101, &["username1"]).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_assignees(
+        &self,
+        number: u64,
+        assignees: &[&str],
+    ) -> Result<models::issues::Issue> {
+        let route = format!(
+            "/repos/{owner}/{repo}/issues/{issue}/assignees",
+            owner = self.owner,
+            repo = self.repo,
+            issue = number
+        );
+
+        self.crab
+            .delete(route, Some(&serde_json::json!({ "assignees": assignees })))
+            .await
+    }
+
     /// Checks if a user has permission to be assigned to an issue in
     /// the repository.
     /// ```no_run
@@ -256,15 +291,7 @@ impl<'octo> IssueHandler<'octo> {
             .context(HttpSnafu)?;
 
         let response = self.crab._get(uri).await?;
-        let status = response.status();
-
-        if status == 204 {
-            Ok(true)
-        } else if status == 404 {
-            Ok(false)
-        } else {
-            Err(crate::map_github_error(response).await.unwrap_err())
-        }
+        crate::exists_via_status(response).await
     }
 
     /// Lists the available assignees for issues in a repository.
@@ -407,12 +434,37 @@ impl<'octo> IssueHandler<'octo> {
             .await
     }
 
+    /// Removes all labels from an issue.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .issues("owner", "repo")
+    ///     .clear_labels(101)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn clear_labels(&self, number: u64) -> Result<()> {
+        let route = format!(
+            "/repos/{owner}/{repo}/issues/{issue}/labels",
+            owner = self.owner,
+            repo = self.repo,
+            issue = number
+        );
+
+        crate::map_github_error(self.crab._delete(route, None::<&()>).await?).await?;
+        Ok(())
+    }
+
     /// Creates a label in the repository.
+    ///
+    /// The `color` is a hexadecimal color code, with or without a leading
+    /// `#`. An empty `description` is left out of the request.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
     /// let label = octocrab::instance()
     ///     .issues("owner", "repo")
-    ///     .create_label("help wanted", "59dd5a", "")
+    ///     .create_label("help wanted", "#59dd5a", "")
     ///     .await?;
     /// # Ok(())
     /// # }
@@ -428,19 +480,39 @@ impl<'octo> IssueHandler<'octo> {
             owner = self.owner,
             repo = self.repo,
         );
+        let description = Some(description.as_ref()).filter(|d| !d.is_empty());
 
         self.crab
             .post(
                 route,
-                Some(&serde_json::json!({
-                    "name": name.as_ref(),
-                    "color": color.as_ref(),
-                    "description": description.as_ref()
-                })),
+                Some(&labels::CreateLabel::new(
+                    name.as_ref(),
+                    color.as_ref(),
+                    description,
+                )),
             )
             .await
     }
 
+    /// Updates a label in the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let label = octocrab::instance()
+    ///     .issues("owner", "repo")
+    ///     .update_label("help wanted")
+    ///     // Optional Parameters
+    ///     .new_name("good first issue")
+    ///     .color("#7057ff")
+    ///     .description("Good for newcomers")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update_label(&self, name: impl Into<String>) -> labels::UpdateLabelBuilder<'_, '_> {
+        labels::UpdateLabelBuilder::new(self, name.into())
+    }
+
     /// Gets a label from the repository.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
@@ -456,7 +528,7 @@ impl<'octo> IssueHandler<'octo> {
             "/repos/{owner}/{repo}/labels/{name}",
             owner = self.owner,
             repo = self.repo,
-            name = name.as_ref(),
+            name = utf8_percent_encode(name.as_ref(), NON_ALPHANUMERIC),
         );
 
         self.crab.get(route, None::<&()>).await
@@ -477,7 +549,7 @@ impl<'octo> IssueHandler<'octo> {
             "/repos/{owner}/{repo}/labels/{name}",
             owner = self.owner,
             repo = self.repo,
-            name = name.as_ref(),
+            name = utf8_percent_encode(name.as_ref(), NON_ALPHANUMERIC),
         );
 
         self.crab._delete(route, None::<&()>).await?;
@@ -524,6 +596,10 @@ impl<'octo> IssueHandler<'octo> {
 /// # Comments
 impl<'octo> IssueHandler<'octo> {
     /// Creates a comment in the issue.
+    ///
+    /// As every pull request is also an issue, this is also how to comment on
+    /// a pull request's conversation; use `number` of the pull request. For
+    /// comments on the diff see [`crate::pulls::PullRequestHandler::list_comments`].
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
     /// let comment = octocrab::instance()
@@ -592,7 +668,7 @@ impl<'octo> IssueHandler<'octo> {
         );
 
         self.crab
-            .post(route, Some(&serde_json::json!({ "body": body.as_ref() })))
+            .patch(route, Some(&serde_json::json!({ "body": body.as_ref() })))
             .await
     }
 
@@ -786,10 +862,14 @@ impl<'octo, 'r> ListIssueCommentsBuilder<'octo, 'r> {
     }
 }
 
+/// A builder pattern struct for listing the timeline events of an issue.
+///
+/// Created by [`IssueHandler::list_timeline_events`].
 #[derive(serde::Serialize)]
 pub struct ListTimelineEventsBuilder<'octo, 'r> {
     #[serde(skip)]
     handler: &'r IssueHandler<'octo>,
+    #[serde(skip)]
     issue_number: u64,
     #[serde(skip_serializing_if = "Option::is_none")]
     per_page: Option<u8>,
@@ -827,14 +907,25 @@ impl<'octo, 'r> ListTimelineEventsBuilder<'octo, 'r> {
             repo = self.handler.repo,
             issue = self.issue_number,
         );
+        // Older GitHub Enterprise Server versions only serve the timeline
+        // with its preview enabled.
+        let mut headers = http::header::HeaderMap::new();
+        headers.insert(
+            http::header::ACCEPT,
+            http::HeaderValue::from_static("application/vnd.github.mockingbird-preview+json"),
+        );
 
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .get_with_headers(route, Some(&self), Some(headers))
+            .await
     }
 }
 
 // Timeline
 impl<'octo> IssueHandler<'octo> {
-    /// Lists events in the issue timeline.
+    /// Lists events in the issue timeline, such as labels being added,
+    /// comments, commits and cross references, oldest first.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
     /// let timeline = octocrab::instance()
@@ -895,6 +986,7 @@ impl<'octo> IssueHandler<'octo> {
 pub struct ListReactionsBuilder<'octo, 'r> {
     #[serde(skip)]
     handler: &'r IssueHandler<'octo>,
+    #[serde(skip)]
     issue_number: u64,
     #[serde(skip_serializing_if = "Option::is_none")]
     per_page: Option<u8>,
@@ -941,6 +1033,7 @@ impl<'octo, 'r> ListReactionsBuilder<'octo, 'r> {
 pub struct ListCommentReactionsBuilder<'octo, 'r> {
     #[serde(skip)]
     handler: &'r IssueHandler<'octo>,
+    #[serde(skip)]
     comment_id: CommentId,
     #[serde(skip_serializing_if = "Option::is_none")]
     per_page: Option<u8>,
@@ -1005,9 +1098,7 @@ impl<'octo> IssueHandler<'octo> {
             repo = self.repo,
         );
 
-        self.crab
-            .post(route, Some(&serde_json::json!({ "content": content })))
-            .await
+        super::reactions::create(self.crab, route, content).await
     }
 
     /// Creates a reaction for an issue comment.
@@ -1032,9 +1123,7 @@ impl<'octo> IssueHandler<'octo> {
             comment_id = comment_id.into(),
         );
 
-        self.crab
-            .post(route, Some(&serde_json::json!({ "content": content })))
-            .await
+        super::reactions::create(self.crab, route, content).await
     }
 }
 
@@ -1055,15 +1144,12 @@ impl<'octo> IssueHandler<'octo> {
         reaction_id: impl Into<ReactionId>,
     ) -> Result<()> {
         let route = format!(
-            "/repos/{owner}/{repo}/issues/{issue_number}/reactions/{reaction_id}",
+            "/repos/{owner}/{repo}/issues/{issue_number}/reactions",
             owner = self.owner,
             repo = self.repo,
-            reaction_id = reaction_id.into(),
         );
 
-        self.crab._delete(route, None::<&()>).await?;
-
-        Ok(())
+        super::reactions::delete(self.crab, route, reaction_id.into()).await
     }
 
     /// Deletes a reaction for an issue comment.
@@ -1082,15 +1168,12 @@ impl<'octo> IssueHandler<'octo> {
         reaction_id: impl Into<ReactionId>,
     ) -> Result<()> {
         let route = format!(
-            "/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions/{reaction_id}",
+            "/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions",
             owner = self.owner,
             repo = self.repo,
             comment_id = comment_id.into(),
-            reaction_id = reaction_id.into(),
         );
 
-        crate::map_github_error(self.crab._delete(route, None::<&()>).await?).await?;
-
-        Ok(())
+        super::reactions::delete(self.crab, route, reaction_id.into()).await
     }
 }
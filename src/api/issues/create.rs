@@ -54,16 +54,20 @@ impl<'octo, 'r> CreateIssueBuilder<'octo, 'r> {
 
     /// Labels to associate with this issue. *NOTE: Only users with push access
     /// can set labels for new issues. Labels are silently dropped otherwise.*
+    ///
+    /// An empty list is left out of the request.
     pub fn labels(mut self, labels: impl Into<Option<Vec<String>>>) -> Self {
-        self.labels = labels.into();
+        self.labels = labels.into().filter(|labels| !labels.is_empty());
         self
     }
 
     /// Logins for Users to assign to this issue. *NOTE: Only users with push
     /// access can set assignees for new issues. Assignees are silently
     /// dropped otherwise.*
+    ///
+    /// An empty list is left out of the request.
     pub fn assignees(mut self, assignees: impl Into<Option<Vec<String>>>) -> Self {
-        self.assignees = assignees.into();
+        self.assignees = assignees.into().filter(|assignees| !assignees.is_empty());
         self
     }
 }
@@ -93,4 +97,21 @@ mod tests {
             })
         )
     }
+
+    #[tokio::test]
+    async fn serialize_without_empty_lists() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.issues("owner", "repo");
+        let list = handler
+            .create("test-issue")
+            .labels(Vec::new())
+            .assignees(Vec::new());
+
+        assert_eq!(
+            serde_json::to_value(list).unwrap(),
+            serde_json::json!({
+                "title": "test-issue",
+            })
+        )
+    }
 }
@@ -0,0 +1,135 @@
+use super::*;
+
+/// Strips a leading `#` from a hex `color`, as GitHub expects colours
+/// without one.
+fn normalize_color(color: &str) -> &str {
+    color.strip_prefix('#').unwrap_or(color)
+}
+
+/// The body of a request to create a label.
+#[derive(serde::Serialize)]
+pub(crate) struct CreateLabel<'a> {
+    name: &'a str,
+    color: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+}
+
+impl<'a> CreateLabel<'a> {
+    pub(crate) fn new(name: &'a str, color: &'a str, description: Option<&'a str>) -> Self {
+        Self {
+            name,
+            color: normalize_color(color),
+            description,
+        }
+    }
+}
+
+/// A builder pattern struct for updating a label in a repository.
+///
+/// Created by [`IssueHandler::update_label`].
+#[derive(serde::Serialize)]
+pub struct UpdateLabelBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r IssueHandler<'octo>,
+    #[serde(skip)]
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    new_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+}
+
+impl<'octo, 'r> UpdateLabelBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r IssueHandler<'octo>, name: String) -> Self {
+        Self {
+            handler,
+            name,
+            new_name: None,
+            color: None,
+            description: None,
+        }
+    }
+
+    /// The new name of the label.
+    pub fn new_name(mut self, new_name: impl Into<String>) -> Self {
+        self.new_name = Some(new_name.into());
+        self
+    }
+
+    /// The hexadecimal color code of the label, with or without a leading `#`.
+    pub fn color(mut self, color: impl AsRef<str>) -> Self {
+        self.color = Some(normalize_color(color.as_ref()).to_owned());
+        self
+    }
+
+    /// A short description of the label.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Send the actual request.
+    pub async fn send(self) -> Result<models::Label> {
+        let route = format!(
+            "/repos/{owner}/{repo}/labels/{name}",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+            name = utf8_percent_encode(&self.name, NON_ALPHANUMERIC),
+        );
+
+        self.handler.crab.patch(route, Some(&self)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CreateLabel;
+
+    #[test]
+    fn create_strips_leading_hash_and_omits_missing_description() {
+        let label = CreateLabel::new("bug", "#f29513", None);
+
+        assert_eq!(
+            serde_json::to_value(label).unwrap(),
+            serde_json::json!({
+                "name": "bug",
+                "color": "f29513",
+            })
+        )
+    }
+
+    #[test]
+    fn create_keeps_color_without_hash() {
+        let label = CreateLabel::new("bug", "f29513", Some("Something isn't working"));
+
+        assert_eq!(
+            serde_json::to_value(label).unwrap(),
+            serde_json::json!({
+                "name": "bug",
+                "color": "f29513",
+                "description": "Something isn't working",
+            })
+        )
+    }
+
+    #[tokio::test]
+    async fn update_serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.issues("rust-lang", "rust");
+        let update = handler
+            .update_label("bug")
+            .new_name("bug :bug:")
+            .color("#b01104");
+
+        assert_eq!(
+            serde_json::to_value(update).unwrap(),
+            serde_json::json!({
+                "new_name": "bug :bug:",
+                "color": "b01104",
+            })
+        )
+    }
+}
@@ -27,6 +27,8 @@ pub struct ListIssuesBuilder<'octo, 'b, 'c, 'd> {
     per_page: Option<u8>,
     #[serde(skip_serializing_if = "Option::is_none")]
     page: Option<u32>,
+    #[serde(skip)]
+    exclude_pull_requests: bool,
 }
 
 impl<'octo, 'b, 'c, 'd> ListIssuesBuilder<'octo, 'b, 'c, 'd> {
@@ -44,21 +46,23 @@ impl<'octo, 'b, 'c, 'd> ListIssuesBuilder<'octo, 'b, 'c, 'd> {
             since: None,
             per_page: None,
             page: None,
+            exclude_pull_requests: false,
         }
     }
 
-    /// If an integer is passed, it should refer to a milestone by its number
-    /// field. If the string `"*"` is passed, issues with any milestone are
-    /// accepted. If the string none is passed, issues without milestones
-    /// are returned.
+    /// Filter by milestone. An integer refers to a milestone by its number
+    /// field. Pass [`Filter::Any`](params::issues::Filter::Any) for issues
+    /// with any milestone, and [`Filter::None`](params::issues::Filter::None)
+    /// for issues without one.
     pub fn milestone(mut self, milestone: impl Into<params::issues::Filter<u64>>) -> Self {
         self.milestone = Some(milestone.into());
         self
     }
 
-    /// Filter by assignee, can be the name of a user. Pass in the string
-    /// `"none"` for issues with no assigned user, and `"*"` for issues assigned
-    /// to any user.
+    /// Filter by assignee, can be the name of a user. Pass
+    /// [`Filter::None`](params::issues::Filter::None) for issues with no
+    /// assigned user, and [`Filter::Any`](params::issues::Filter::Any) for
+    /// issues assigned to any user.
     pub fn assignee(mut self, assignee: impl Into<params::issues::Filter<&'c str>>) -> Self {
         self.assignee = Some(assignee.into());
         self
@@ -70,19 +74,19 @@ impl<'octo, 'b, 'c, 'd> ListIssuesBuilder<'octo, 'b, 'c, 'd> {
         self
     }
 
-    /// Filter by the creator of the issue.
+    /// Filter by a user mentioned in the issue.
     pub fn mentioned(mut self, mentioned: impl Into<String>) -> Self {
         self.mentioned = Some(mentioned.into());
         self
     }
 
-    /// Filter pull requests by `state`.
+    /// Filter issues by `state`.
     pub fn state(mut self, state: params::State) -> Self {
         self.state = Some(state);
         self
     }
 
-    /// Filter issues by label.
+    /// Filter issues by label, only returning those with all of `labels`.
     pub fn labels(mut self, labels: &'d (impl AsRef<[String]> + ?Sized)) -> Self {
         self.labels = Some(labels.as_ref());
         self
@@ -122,6 +126,15 @@ impl<'octo, 'b, 'c, 'd> ListIssuesBuilder<'octo, 'b, 'c, 'd> {
         self
     }
 
+    /// Leave out the pull requests, which GitHub returns as issues, from the
+    /// results. They are filtered out after fetching, so pages may hold
+    /// fewer than `per_page` issues; later pages fetched through
+    /// [`Octocrab::get_page`](crate::Octocrab::get_page) aren't filtered.
+    pub fn exclude_pull_requests(mut self) -> Self {
+        self.exclude_pull_requests = true;
+        self
+    }
+
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<crate::Page<models::issues::Issue>> {
         let route = format!(
@@ -129,7 +142,12 @@ impl<'octo, 'b, 'c, 'd> ListIssuesBuilder<'octo, 'b, 'c, 'd> {
             owner = self.handler.owner,
             repo = self.handler.repo
         );
-        self.handler.crab.get(route, Some(&self)).await
+        let mut page: crate::Page<models::issues::Issue> =
+            self.handler.crab.get(route, Some(&self)).await?;
+        if self.exclude_pull_requests {
+            page.items.retain(|issue| issue.pull_request.is_none());
+        }
+        Ok(page)
     }
 }
 
@@ -181,4 +199,31 @@ mod tests {
             })
         )
     }
+
+    #[tokio::test]
+    async fn serialize_filter_sentinels() {
+        use crate::params::issues::Filter;
+
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.issues("rust-lang", "rust");
+        let query = |list| serde_urlencoded::to_string(list).unwrap();
+
+        assert_eq!(
+            query(handler.list().milestone(Filter::Any).assignee(Filter::Any)),
+            "milestone=*&assignee=*"
+        );
+        assert_eq!(
+            query(
+                handler
+                    .list()
+                    .milestone(Filter::None)
+                    .assignee(Filter::None)
+            ),
+            "milestone=none&assignee=none"
+        );
+        assert_eq!(
+            query(handler.list().milestone(42).assignee("ferris")),
+            "milestone=42&assignee=ferris"
+        );
+    }
 }
@@ -16,8 +16,9 @@ pub struct UpdateIssueBuilder<'octo, 'a, 'b, 'c, 'd, 'e> {
     state: Option<models::IssueState>,
     #[serde(skip_serializing_if = "Option::is_none")]
     state_reason: Option<models::issues::IssueStateReason>,
+    // `Some(None)` is sent as `null`, which removes the milestone.
     #[serde(skip_serializing_if = "Option::is_none")]
-    milestone: Option<u64>,
+    milestone: Option<Option<u64>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     labels: Option<&'e [String]>,
 }
@@ -61,7 +62,11 @@ impl<'octo, 'a, 'b, 'c, 'd, 'e> UpdateIssueBuilder<'octo, 'a, 'b, 'c, 'd, 'e> {
         self
     }
 
-    /// The state reason of the issue.
+    /// Why the issue is in its state, e.g. closed as `not_planned` rather
+    /// than `completed`. `completed` and `not_planned` only apply to closed
+    /// issues and `reopened` to open ones, so [`Self::send`] fails if
+    /// [`Self::state`] is set to the other state. Without a state, the
+    /// reason must match the current state of the issue.
     pub fn state_reason(
         mut self,
         state_reason: impl Into<models::issues::IssueStateReason>,
@@ -72,7 +77,13 @@ impl<'octo, 'a, 'b, 'c, 'd, 'e> UpdateIssueBuilder<'octo, 'a, 'b, 'c, 'd, 'e> {
 
     /// The milestone of the issue.
     pub fn milestone(mut self, milestone: impl Into<u64>) -> Self {
-        self.milestone = Some(milestone.into());
+        self.milestone = Some(Some(milestone.into()));
+        self
+    }
+
+    /// Removes the issue from its milestone.
+    pub fn clear_milestone(mut self) -> Self {
+        self.milestone = Some(None);
         self
     }
 
@@ -84,6 +95,7 @@ impl<'octo, 'a, 'b, 'c, 'd, 'e> UpdateIssueBuilder<'octo, 'a, 'b, 'c, 'd, 'e> {
 
     /// Send the actual request.
     pub async fn send(self) -> Result<models::issues::Issue> {
+        self.validate_state_reason()?;
         let route = format!(
             "/repos/{owner}/{repo}/issues/{issue}",
             owner = self.handler.owner,
@@ -93,6 +105,28 @@ impl<'octo, 'a, 'b, 'c, 'd, 'e> UpdateIssueBuilder<'octo, 'a, 'b, 'c, 'd, 'e> {
 
         self.handler.crab.patch(route, Some(&self)).await
     }
+
+    fn validate_state_reason(&self) -> Result<()> {
+        use models::{issues::IssueStateReason, IssueState};
+
+        let valid = match (&self.state, &self.state_reason) {
+            (Some(IssueState::Open), Some(reason)) => *reason == IssueStateReason::Reopened,
+            (Some(IssueState::Closed), Some(reason)) => *reason != IssueStateReason::Reopened,
+            _ => true,
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(crate::Error::Other {
+                source: format!(
+                    "state_reason {:?} does not apply to state {:?}",
+                    self.state_reason, self.state
+                )
+                .into(),
+                backtrace: snafu::GenerateImplicitData::generate(),
+            })
+        }
+    }
 }
 
 #[cfg(test)]
@@ -129,4 +163,46 @@ mod tests {
             })
         )
     }
+
+    #[tokio::test]
+    async fn reject_state_reason_of_other_state() {
+        use crate::models::{issues::IssueStateReason, IssueState};
+
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.issues("rust-lang", "rust");
+
+        for (state, reason) in [
+            (IssueState::Open, IssueStateReason::NotPlanned),
+            (IssueState::Open, IssueStateReason::Completed),
+            (IssueState::Closed, IssueStateReason::Reopened),
+        ] {
+            let update = handler.update(1234).state(state).state_reason(reason);
+            assert!(matches!(
+                update.validate_state_reason(),
+                Err(crate::Error::Other { .. })
+            ));
+        }
+
+        let update = handler
+            .update(1234)
+            .state(IssueState::Closed)
+            .state_reason(IssueStateReason::NotPlanned);
+        assert!(update.validate_state_reason().is_ok());
+        let update = handler
+            .update(1234)
+            .state_reason(IssueStateReason::Reopened);
+        assert!(update.validate_state_reason().is_ok());
+    }
+
+    #[tokio::test]
+    async fn serialize_removed_milestone_as_null() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.issues("rust-lang", "rust");
+        let update = handler.update(1234).clear_milestone();
+
+        assert_eq!(
+            serde_json::to_value(update).unwrap(),
+            serde_json::json!({ "milestone": null })
+        )
+    }
 }
@@ -30,6 +30,27 @@ impl<'octo> OrgHandler<'octo> {
 
     /// Add or update organization membership
     ///
+    /// Deprecated in favor of [`OrgHandler::set_membership`], which always
+    /// sends the role.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let invitation = octocrab.orgs("owner").add_or_update_membership("ferris", None).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[deprecated(note = "use set_membership instead")]
+    pub async fn add_or_update_membership(
+        &self,
+        username: impl AsRef<str>,
+        role: Option<crate::params::orgs::Role>,
+    ) -> crate::Result<crate::models::orgs::MembershipInvitation> {
+        self.put_membership(username.as_ref(), role).await
+    }
+
+    /// Sets the role of `username` in the organization, inviting them if
+    /// they aren't a member yet.
+    ///
     /// **Note**
     /// - Only authenticated organization owners can add a member to the
     ///   organization or update the member's role.
@@ -37,36 +58,47 @@ impl<'octo> OrgHandler<'octo> {
     ///   invited user will receive an email inviting them to the organization.
     ///   The user's membership status will be pending until they accept
     ///   the invitation.
-    /// - Authenticated users can update a user's membership by passing the role
-    ///   parameter. If the authenticated user changes a member's role to admin,
-    ///   the affected user will receive an email notifying them that they've
-    ///   been made an organization owner. If the authenticated user changes an
+    /// - If the authenticated user changes a member's role to admin, the
+    ///   affected user will receive an email notifying them that they've been
+    ///   made an organization owner. If the authenticated user changes an
     ///   owner's role to member, no email will be sent.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
     /// # let octocrab = octocrab::Octocrab::default();
-    /// let invitation = octocrab.orgs("owner").add_or_update_membership("ferris", None).await?;
+    /// use octocrab::params::orgs::Role;
+    ///
+    /// let membership = octocrab.orgs("owner").set_membership("ferris", Role::Admin).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn add_or_update_membership(
+    pub async fn set_membership(
         &self,
         username: impl AsRef<str>,
+        role: crate::params::orgs::Role,
+    ) -> crate::Result<crate::models::orgs::MembershipInvitation> {
+        self.put_membership(username.as_ref(), Some(role)).await
+    }
+
+    async fn put_membership(
+        &self,
+        username: &str,
         role: Option<crate::params::orgs::Role>,
     ) -> crate::Result<crate::models::orgs::MembershipInvitation> {
-        let route = format!(
-            "/orgs/{org}/memberships/{username}",
-            org = self.owner,
-            username = username.as_ref(),
-        );
+        let route = format!("/orgs/{org}/memberships/{username}", org = self.owner);
 
         let body = role.map(|role| serde_json::json!({ "role": role }));
 
-        self.crab.post(route, body.as_ref()).await
+        self.crab.put(route, body.as_ref()).await
     }
 
     /// Check if a user is, publicly or privately, a member of the organization.
     ///
+    /// GitHub only answers this for requesters who are members of the
+    /// organization themselves. Otherwise it redirects to the public
+    /// membership check, which is surfaced as an
+    /// [`Error::NotOrganizationMember`] rather than as `false`.
+    ///
+    /// [`Error::NotOrganizationMember`]: crate::Error::NotOrganizationMember
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
     /// # let octocrab = octocrab::Octocrab::default();
@@ -82,17 +114,37 @@ impl<'octo> OrgHandler<'octo> {
         );
 
         let uri = Uri::builder()
-            .path_and_query(route)
+            .path_and_query(route.as_str())
             .build()
             .context(HttpSnafu)?;
 
         let response = self.crab._get(uri).await?;
 
-        match response.status() {
-            StatusCode::NO_CONTENT => Ok(true),
-            StatusCode::NOT_FOUND => Ok(false),
-            _ => Err(crate::map_github_error(response).await.unwrap_err()),
+        if response.status() == StatusCode::FOUND || was_redirected(&response, &route) {
+            return crate::error::NotOrganizationMemberSnafu.fail();
         }
+        crate::exists_via_status(response).await
+    }
+
+    /// Removes a user from the organization, along with their access to its
+    /// repositories.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// octocrab.orgs("owner").remove_member("ferris").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_member(&self, username: impl AsRef<str>) -> crate::Result<()> {
+        let route = format!(
+            "/orgs/{org}/members/{username}",
+            org = self.owner,
+            username = username.as_ref(),
+        );
+
+        crate::map_github_error(self.crab._delete(route, None::<&()>).await?)
+            .await
+            .map(drop)
     }
 
     /// Get an organization
@@ -117,6 +169,11 @@ impl<'octo> OrgHandler<'octo> {
 
     /// List repos for the specified organization.
     ///
+    /// Without [`ListReposBuilder::repo_type`], GitHub picks the type from who
+    /// is asking: `all` for members of the organization, but `public` for
+    /// anyone else, including unauthenticated clients. Set it explicitly when
+    /// the result must not depend on the credentials.
+    ///
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
     /// use octocrab::params;
@@ -141,6 +198,31 @@ impl<'octo> OrgHandler<'octo> {
         list_repos::ListReposBuilder::new(self)
     }
 
+    /// Creates a new repository named `name` in the organization. The
+    /// authenticated user must be a member of the organization.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let repo = octocrab::instance()
+    ///     .orgs("owner")
+    ///     .create_repo("octocrab")
+    ///     .description("A modern, extensible GitHub API client.")
+    ///     .license_template("mit")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_repo(
+        &self,
+        name: impl Into<String>,
+    ) -> crate::repos::CreateRepositoryBuilder<'octo> {
+        crate::repos::CreateRepositoryBuilder::new(
+            self.crab,
+            format!("/orgs/{org}/repos", org = self.owner),
+            name.into(),
+        )
+    }
+
     /// List events on this organization.
     ///
     /// Takes an optional etag which allows for efficient polling. Here is a quick example to poll a
@@ -218,7 +300,15 @@ impl<'octo> OrgHandler<'octo> {
     /// # Examples
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
-    /// let org_members = octocrab::instance().orgs("org").list_members().send().await?;
+    /// use octocrab::params::orgs::MemberRole;
+    ///
+    /// let org_members = octocrab::instance()
+    ///     .orgs("org")
+    ///     .list_members()
+    ///     // Optional Parameters
+    ///     .role(MemberRole::Admin)
+    ///     .send()
+    ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
@@ -233,3 +323,19 @@ impl<'octo> OrgHandler<'octo> {
         secrets::OrgSecretsHandler::new(self)
     }
 }
+
+/// Whether `response` is the result of following a redirect away from `route`.
+fn was_redirected<B>(response: &http::Response<B>, route: &str) -> bool {
+    #[cfg(feature = "follow-redirect")]
+    {
+        response
+            .extensions()
+            .get::<tower_http::follow_redirect::RequestUri>()
+            .is_some_and(|uri| !uri.0.path().ends_with(route))
+    }
+    #[cfg(not(feature = "follow-redirect"))]
+    {
+        let _ = (response, route);
+        false
+    }
+}
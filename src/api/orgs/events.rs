@@ -3,10 +3,8 @@ use crate::{
     etag::{EntityTag, Etagged},
     models::events,
     orgs::OrgHandler,
-    FromResponse, Page,
+    Page,
 };
-use http::request::Builder;
-use http::{header::HeaderMap, Method, StatusCode};
 
 pub struct ListOrgEventsBuilder<'octo, 'handler> {
     handler: &'handler OrgHandler<'octo>,
@@ -60,33 +58,9 @@ impl<'octo, 'handler> ListOrgEventsBuilder<'octo, 'handler> {
     pub async fn send(self) -> crate::Result<Etagged<Page<events::Event>>> {
         let route = format!("/orgs/{owner}/events", owner = self.handler.owner);
 
-        let uri = self
-            .handler
+        self.handler
             .crab
-            .parameterized_uri(route, Some(&self.params))?;
-
-        let mut headers = HeaderMap::new();
-        if let Some(etag) = self.headers.etag {
-            EntityTag::insert_if_none_match_header(&mut headers, etag)?;
-        }
-
-        let mut request = Builder::new().uri(uri).method(Method::GET);
-        for (key, value) in headers.iter() {
-            request = request.header(key, value);
-        }
-
-        let request = self.handler.crab.build_request(request, None::<&()>)?;
-        let response = self.handler.crab.execute(request).await?;
-        let etag = EntityTag::extract_from_response(&response);
-        if response.status() == StatusCode::NOT_MODIFIED {
-            Ok(Etagged { etag, value: None })
-        } else {
-            <Page<events::Event>>::from_response(crate::map_github_error(response).await?)
-                .await
-                .map(|page| Etagged {
-                    etag,
-                    value: Some(page),
-                })
-        }
+            .get_etagged(route, Some(&self.params), self.headers.etag)
+            .await
     }
 }
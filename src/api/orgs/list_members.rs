@@ -5,6 +5,10 @@ pub struct ListOrgMembersBuilder<'octo, 'r> {
     #[serde(skip)]
     handler: &'r OrgHandler<'octo>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    role: Option<crate::params::orgs::MemberRole>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter: Option<crate::params::orgs::MemberFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     per_page: Option<u8>,
     #[serde(skip_serializing_if = "Option::is_none")]
     page: Option<u32>,
@@ -14,11 +18,25 @@ impl<'octo, 'r> ListOrgMembersBuilder<'octo, 'r> {
     pub fn new(handler: &'r OrgHandler<'octo>) -> Self {
         Self {
             handler,
+            role: None,
+            filter: None,
             per_page: None,
             page: None,
         }
     }
 
+    /// Only list members with this role.
+    pub fn role(mut self, role: impl Into<crate::params::orgs::MemberRole>) -> Self {
+        self.role = Some(role.into());
+        self
+    }
+
+    /// Filter the members returned in the list.
+    pub fn filter(mut self, filter: impl Into<crate::params::orgs::MemberFilter>) -> Self {
+        self.filter = Some(filter.into());
+        self
+    }
+
     /// Results per page (max 100).
     pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
         self.per_page = Some(per_page.into());
@@ -36,3 +54,30 @@ impl<'octo, 'r> ListOrgMembersBuilder<'octo, 'r> {
         self.handler.crab.get(route, Some(&self)).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::params::orgs::{MemberFilter, MemberRole};
+
+    #[tokio::test]
+    async fn serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.orgs("rust-lang");
+        let list = handler
+            .list_members()
+            .role(MemberRole::Admin)
+            .filter(MemberFilter::TwoFactorDisabled)
+            .per_page(100)
+            .page(1u8);
+
+        assert_eq!(
+            serde_json::to_value(list).unwrap(),
+            serde_json::json!({
+                "role": "admin",
+                "filter": "2fa_disabled",
+                "per_page": 100,
+                "page": 1,
+            })
+        )
+    }
+}
@@ -28,7 +28,8 @@ impl<'octo, 'b> ListReposBuilder<'octo, 'b> {
         }
     }
 
-    /// Filter repostories by their type
+    /// Filter repositories by their type. Default: `all` for members of
+    /// the organization, `public` for anyone else.
     pub fn repo_type(mut self, r#type: impl Into<Option<crate::params::repos::Type>>) -> Self {
         self.r#type = r#type.into();
         self
@@ -72,13 +73,11 @@ mod tests {
     #[tokio::test]
     async fn serialize() {
         let octocrab = crate::Octocrab::default();
-        let handler = octocrab.pulls("rust-lang", "rust");
+        let handler = octocrab.orgs("rust-lang");
         let list = handler
-            .list()
-            .state(crate::params::State::Open)
-            .head("master")
-            .base("branch")
-            .sort(crate::params::pulls::Sort::Popularity)
+            .list_repos()
+            .repo_type(crate::params::repos::Type::Sources)
+            .sort(crate::params::repos::Sort::FullName)
             .direction(crate::params::Direction::Ascending)
             .per_page(100)
             .page(1u8);
@@ -86,14 +85,21 @@ mod tests {
         assert_eq!(
             serde_json::to_value(list).unwrap(),
             serde_json::json!({
-                "state": "open",
-                "head": "master",
-                "base": "branch",
-                "sort": "popularity",
+                "type": "sources",
+                "sort": "full_name",
                 "direction": "asc",
                 "per_page": 100,
                 "page": 1,
             })
         )
     }
+
+    #[tokio::test]
+    async fn serialize_without_type() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.orgs("rust-lang");
+        let list = handler.list_repos().repo_type(None);
+
+        assert_eq!(serde_json::to_value(list).unwrap(), serde_json::json!({}))
+    }
 }
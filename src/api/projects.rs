@@ -3,16 +3,27 @@
 //! # Notes
 //! Users need an account with sufficient privileges to interact with projects.
 
+mod cards;
 mod projects;
 
+pub use self::cards::{CreateCardBuilder, ListCardsBuilder};
 use self::projects::{
     CreateOrgProjectsBuilder, CreateRepositoryProjectsBuilder, CreateUserProjectBuilder,
     DeleteProjectBuilder, GetProjectBuilder, ListOrgProjectsBuilder, ListRepositoryProjectsBuilder,
     ListUserProjectsBuilder, NotNamed, UpdateProjectBuilder,
 };
-use crate::Octocrab;
+use crate::models::{CardId, ProjectColumnId};
+use crate::params::projects::CardPosition;
+use crate::{FromResponse, Octocrab};
+use bytes::Bytes;
+use http::{request::Builder, Method};
+use http_body_util::combinators::BoxBody;
 use serde::Serialize;
 
+/// The media type of the projects preview, which GitHub Enterprise Server
+/// requires for every projects endpoint.
+const INERTIA_PREVIEW: &str = "application/vnd.github.inertia-preview+json";
+
 /// A struct to access GitHub's projects API.
 ///
 /// Created with [`Octocrab::projects`].
@@ -25,6 +36,79 @@ impl<'octo> ProjectHandler<'octo> {
         Self { crab }
     }
 
+    /// Sends a request with the projects preview enabled.
+    async fn send_request<P, B>(
+        &self,
+        method: Method,
+        route: impl AsRef<str>,
+        parameters: Option<&P>,
+        body: Option<&B>,
+    ) -> crate::Result<http::Response<BoxBody<Bytes, crate::Error>>>
+    where
+        P: Serialize + ?Sized,
+        B: Serialize + ?Sized,
+    {
+        let uri = self.crab.parameterized_uri(route, parameters)?;
+        let request = Builder::new()
+            .method(method)
+            .uri(uri)
+            .header(http::header::ACCEPT, INERTIA_PREVIEW);
+        let request = self.crab.build_request(request, body)?;
+
+        crate::map_github_error(self.crab.execute(request).await?).await
+    }
+
+    pub(crate) async fn http_get<R, P>(
+        &self,
+        route: impl AsRef<str>,
+        parameters: Option<&P>,
+    ) -> crate::Result<R>
+    where
+        R: FromResponse,
+        P: Serialize + ?Sized,
+    {
+        let response = self
+            .send_request(Method::GET, route, parameters, None::<&()>)
+            .await?;
+        R::from_response(response).await
+    }
+
+    pub(crate) async fn http_post<R, B>(
+        &self,
+        route: impl AsRef<str>,
+        body: Option<&B>,
+    ) -> crate::Result<R>
+    where
+        R: FromResponse,
+        B: Serialize + ?Sized,
+    {
+        let response = self
+            .send_request(Method::POST, route, None::<&()>, body)
+            .await?;
+        R::from_response(response).await
+    }
+
+    pub(crate) async fn http_patch<R, B>(
+        &self,
+        route: impl AsRef<str>,
+        body: Option<&B>,
+    ) -> crate::Result<R>
+    where
+        R: FromResponse,
+        B: Serialize + ?Sized,
+    {
+        let response = self
+            .send_request(Method::PATCH, route, None::<&()>, body)
+            .await?;
+        R::from_response(response).await
+    }
+
+    pub(crate) async fn http_delete(&self, route: impl AsRef<str>) -> crate::Result<()> {
+        self.send_request(Method::DELETE, route, None::<&()>, None::<&()>)
+            .await
+            .map(drop)
+    }
+
     /// Get a project by its id.    
     ///
     /// # Arguments
@@ -249,4 +333,115 @@ impl<'octo> ProjectHandler<'octo> {
     ) -> ListRepositoryProjectsBuilder<'_, '_> {
         ListRepositoryProjectsBuilder::new(self, owner.into(), repo.into())
     }
+
+    /// Lists the columns of a project.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let columns = octocrab::instance()
+    ///     .projects()
+    ///     .list_columns(1002604u64)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_columns(
+        &self,
+        project_id: impl Into<crate::models::ProjectId>,
+    ) -> crate::Result<crate::Page<crate::models::ProjectColumn>> {
+        let route = format!("/projects/{}/columns", project_id.into());
+        self.http_get(route, None::<&()>).await
+    }
+
+    /// Creates a column called `name` in a project.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let column = octocrab::instance()
+    ///     .projects()
+    ///     .create_column(1002604u64, "To Do")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_column(
+        &self,
+        project_id: impl Into<crate::models::ProjectId>,
+        name: impl AsRef<str>,
+    ) -> crate::Result<crate::models::ProjectColumn> {
+        let route = format!("/projects/{}/columns", project_id.into());
+        self.http_post(route, Some(&serde_json::json!({ "name": name.as_ref() })))
+            .await
+    }
+
+    /// Creates a new [`ListCardsBuilder`] for listing the cards of a column.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let cards = octocrab::instance()
+    ///     .projects()
+    ///     .list_cards(367u64)
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_cards(&self, column_id: impl Into<ProjectColumnId>) -> ListCardsBuilder<'_, '_> {
+        ListCardsBuilder::new(self, column_id.into())
+    }
+
+    /// Creates a new [`CreateCardBuilder`] for adding a card to a column.
+    /// Set either a [`note`](CreateCardBuilder::note) or the
+    /// [`content`](CreateCardBuilder::content) of the card.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let card = octocrab::instance()
+    ///     .projects()
+    ///     .create_card(367u64)
+    ///     .note("Add payload for delete Project column")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_card(&self, column_id: impl Into<ProjectColumnId>) -> CreateCardBuilder<'_, '_> {
+        CreateCardBuilder::new(self, column_id.into())
+    }
+
+    /// Moves a card to `position` in its column, or in `column_id` if given.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::projects::CardPosition;
+    ///
+    /// octocrab::instance()
+    ///     .projects()
+    ///     .move_card(1478u64, CardPosition::Top, Some(368u64.into()))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn move_card(
+        &self,
+        card_id: impl Into<CardId>,
+        position: CardPosition,
+        column_id: Option<ProjectColumnId>,
+    ) -> crate::Result<()> {
+        #[derive(Serialize)]
+        struct MoveCard {
+            position: CardPosition,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            column_id: Option<ProjectColumnId>,
+        }
+
+        let route = format!("/projects/columns/cards/{}/moves", card_id.into());
+        self.send_request(
+            Method::POST,
+            route,
+            None::<&()>,
+            Some(&MoveCard {
+                position,
+                column_id,
+            }),
+        )
+        .await
+        .map(drop)
+    }
 }
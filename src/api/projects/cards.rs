@@ -0,0 +1,164 @@
+//! Helper builder structs to manage the cards of project columns.
+
+use super::*;
+use crate::models::{projects::Card, ProjectCardContentType};
+use crate::params::projects::ArchivedState;
+
+/// Helper builder struct to list the cards of a project column.
+///
+/// Used by [`Octocrab::projects`].
+#[derive(serde::Serialize)]
+pub struct ListCardsBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r ProjectHandler<'octo>,
+    #[serde(skip)]
+    column_id: ProjectColumnId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    archived_state: Option<ArchivedState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListCardsBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r ProjectHandler<'octo>, column_id: ProjectColumnId) -> Self {
+        Self {
+            handler,
+            column_id,
+            archived_state: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Filter cards by whether they are archived. Default: `not_archived`.
+    pub fn archived_state(mut self, archived_state: ArchivedState) -> Self {
+        self.archived_state = Some(archived_state);
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<crate::Page<Card>> {
+        let route = format!("/projects/columns/{}/cards", self.column_id);
+        self.handler.http_get(route, Some(&self)).await
+    }
+}
+
+/// Helper builder struct to add a card to a project column.
+///
+/// Used by [`Octocrab::projects`].
+#[derive(serde::Serialize)]
+pub struct CreateCardBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r ProjectHandler<'octo>,
+    #[serde(skip)]
+    column_id: ProjectColumnId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_type: Option<ProjectCardContentType>,
+}
+
+impl<'octo, 'r> CreateCardBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r ProjectHandler<'octo>, column_id: ProjectColumnId) -> Self {
+        Self {
+            handler,
+            column_id,
+            note: None,
+            content_id: None,
+            content_type: None,
+        }
+    }
+
+    /// The text of the card.
+    pub fn note(mut self, note: impl Into<String>) -> Self {
+        self.note = Some(note.into());
+        self
+    }
+
+    /// Links the card to an issue or pull request. `content_id` is the ID of
+    /// the issue or pull request, not its number.
+    pub fn content(
+        mut self,
+        content_id: impl Into<u64>,
+        content_type: ProjectCardContentType,
+    ) -> Self {
+        self.content_id = Some(content_id.into());
+        self.content_type = Some(content_type);
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Card> {
+        let route = format!("/projects/columns/{}/cards", self.column_id);
+        self.handler.http_post(route, Some(&self)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::ProjectCardContentType;
+    use crate::params::projects::{ArchivedState, CardPosition};
+
+    #[tokio::test]
+    async fn list_serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.projects();
+        let list = handler
+            .list_cards(367u64)
+            .archived_state(ArchivedState::All)
+            .per_page(100);
+
+        assert_eq!(
+            serde_json::to_value(list).unwrap(),
+            serde_json::json!({
+                "archived_state": "all",
+                "per_page": 100,
+            })
+        )
+    }
+
+    #[tokio::test]
+    async fn create_serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.projects();
+        let create = handler
+            .create_card(367u64)
+            .content(1347u64, ProjectCardContentType::PullRequest);
+
+        assert_eq!(
+            serde_json::to_value(create).unwrap(),
+            serde_json::json!({
+                "content_id": 1347,
+                "content_type": "PullRequest",
+            })
+        )
+    }
+
+    #[test]
+    fn card_position_serialize() {
+        assert_eq!(
+            serde_json::to_value(CardPosition::Top).unwrap(),
+            serde_json::json!("top")
+        );
+        assert_eq!(
+            serde_json::to_value(CardPosition::After(1478u64.into())).unwrap(),
+            serde_json::json!("after:1478")
+        );
+    }
+}
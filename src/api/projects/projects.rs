@@ -24,7 +24,7 @@ impl<'octo, 'r> GetProjectBuilder<'octo, 'r> {
 
     pub async fn send(self) -> crate::Result<crate::models::Project> {
         let route = format!("/projects/{project_id}", project_id = self.project_id);
-        self.handler.crab.get(route, None::<&()>).await
+        self.handler.http_get(route, None::<&()>).await
     }
 }
 
@@ -71,7 +71,7 @@ where
     pub async fn send(self) -> crate::Result<crate::models::Project> {
         let route = format!("/projects/{project_id}", project_id = self.project_id);
 
-        self.handler.crab.patch(route, Some(&self)).await
+        self.handler.http_patch(route, Some(&self)).await
     }
 }
 
@@ -96,9 +96,7 @@ impl<'octo, 'r> DeleteProjectBuilder<'octo, 'r> {
     pub async fn send(self) -> crate::Result<()> {
         let route = format!("/projects/{project_id}", project_id = self.project_id);
 
-        crate::map_github_error(self.handler.crab._delete(route, None::<&()>).await?)
-            .await
-            .map(drop)
+        self.handler.http_delete(route).await
     }
 }
 
@@ -131,7 +129,7 @@ impl<'octo, 'r> CreateUserProjectBuilder<'octo, 'r> {
     pub async fn send(self) -> crate::Result<crate::models::Project> {
         let route = "/user/projects";
 
-        self.handler.crab.post(route, Some(&self.body)).await
+        self.handler.http_post(route, Some(&self.body)).await
     }
 }
 
@@ -173,7 +171,7 @@ impl<'octo, 'r> ListUserProjectsBuilder<'octo, 'r> {
     pub async fn send(self) -> crate::Result<crate::Page<crate::models::Project>> {
         let route = format!("/users/{username}/projects", username = self.username);
 
-        self.handler.crab.get(route, None::<&()>).await
+        self.handler.http_get(route, None::<&()>).await
     }
 }
 
@@ -227,7 +225,7 @@ impl<'octo, 'r> ListOrgProjectsBuilder<'octo, 'r> {
 
     pub async fn send(self) -> crate::Result<crate::Page<crate::models::Project>> {
         let route = format!("/orgs/{org}/projects", org = self.org);
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler.http_get(route, Some(&self)).await
     }
 }
 
@@ -262,7 +260,7 @@ impl<'octo, 'r> CreateOrgProjectsBuilder<'octo, 'r> {
 
     pub async fn send(self) -> crate::Result<crate::models::Project> {
         let route = format!("/orgs/{org}/projects", org = self.org);
-        self.handler.crab.post(route, Some(&self)).await
+        self.handler.http_post(route, Some(&self)).await
     }
 }
 
@@ -311,7 +309,7 @@ impl<'octo, 'r> ListRepositoryProjectsBuilder<'octo, 'r> {
             owner = self.owner,
             repo = self.repo
         );
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler.http_get(route, Some(&self)).await
     }
 }
 
@@ -407,6 +405,6 @@ impl<'octo, 'r> CreateRepositoryProjectsBuilder<'octo, 'r, Named> {
             owner = self.owner,
             repo = self.repo
         );
-        self.handler.crab.post(route, Some(&self)).await
+        self.handler.http_post(route, Some(&self)).await
     }
 }
@@ -4,7 +4,9 @@ mod comment;
 mod create;
 mod list;
 mod merge;
+mod review;
 mod update;
+mod update_branch;
 
 use http::request::Builder;
 use http::{Method, Uri};
@@ -15,8 +17,12 @@ use crate::error::HttpSnafu;
 use crate::{Octocrab, Page};
 
 pub use self::{
-    create::CreatePullRequestBuilder, list::ListPullRequestsBuilder,
+    comment::{CreateReviewCommentBuilder, ListCommentReactionsBuilder},
+    create::CreatePullRequestBuilder,
+    list::ListPullRequestsBuilder,
+    review::CreateReviewBuilder,
     update::UpdatePullRequestBuilder,
+    update_branch::UpdateBranchBuilder,
 };
 
 /// A client to GitHub's pull request API.
@@ -79,29 +85,29 @@ impl<'octo> PullRequestHandler<'octo> {
         Ok(response.status() == 204)
     }
 
-    /// Update the branch of a pull request.
-    ///
+    /// Creates a new [`UpdateBranchBuilder`] for merging the latest changes
+    /// of the base branch into the branch of a pull request.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
     /// # let octocrab = octocrab::Octocrab::default();
-    /// octocrab.pulls("owner", "repo").update_branch(101).await?;
+    /// use octocrab::models::pulls::BranchUpdate;
+    ///
+    /// let update = octocrab
+    ///     .pulls("owner", "repo")
+    ///     .update_branch(101)
+    ///     // Optional Parameters
+    ///     .expected_head_sha("6dcb09b5b57875f334f61aebed695e2e4193db5e")
+    ///     // Send the request
+    ///     .send()
+    ///     .await?;
+    /// if update == BranchUpdate::AlreadyUpToDate {
+    ///     println!("Nothing to do");
+    /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn update_branch(&self, pr: u64) -> crate::Result<bool> {
-        let route = format!(
-            "/repos/{owner}/{repo}/pulls/{pr}/update-branch",
-            owner = self.owner,
-            repo = self.repo,
-            pr = pr
-        );
-        let uri = Uri::builder()
-            .path_and_query(route)
-            .build()
-            .context(crate::error::HttpSnafu)?;
-        let response = self.crab._put(uri, None::<&()>).await?;
-
-        Ok(response.status() == 202)
+    pub fn update_branch(&self, pr: u64) -> UpdateBranchBuilder<'_, '_> {
+        UpdateBranchBuilder::new(self, pr)
     }
 
     /// Get's a given pull request with by its `pr` number.
@@ -122,6 +128,45 @@ impl<'octo> PullRequestHandler<'octo> {
         self.http_get(route, None::<&()>).await
     }
 
+    /// Gets whether the pull request can be merged. GitHub computes this in
+    /// the background, leaving `mergeable` as `null` until it is done, so
+    /// the pull request is fetched up to `attempts` times, waiting `delay`
+    /// after each `null`. Returns `None` if it still wasn't known after the
+    /// last attempt. Requires the `poll` feature for its timer.
+    ///
+    /// Only a yes or no answer is returned; the pull request's
+    /// [`mergeable_state`](crate::models::pulls::PullRequest::mergeable_state)
+    /// tells why, e.g. whether it is `clean`, `dirty`, `blocked` or `behind`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use std::time::Duration;
+    ///
+    /// let mergeable = octocrab::instance()
+    ///     .pulls("owner", "repo")
+    ///     .mergeable(101, 5, Duration::from_secs(2))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "poll")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "poll")))]
+    pub async fn mergeable(
+        &self,
+        pr: u64,
+        attempts: u32,
+        delay: std::time::Duration,
+    ) -> crate::Result<Option<bool>> {
+        let mut attempt = 1;
+        loop {
+            let mergeable = self.get(pr).await?.mergeable;
+            if mergeable.is_some() || attempt >= attempts {
+                return Ok(mergeable);
+            }
+            attempt += 1;
+            tokio::time::sleep(delay).await;
+        }
+    }
+
     /// Get's a given pull request's `diff`.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
@@ -130,24 +175,7 @@ impl<'octo> PullRequestHandler<'octo> {
     /// # }
     /// ```
     pub async fn get_diff(&self, pr: u64) -> crate::Result<String> {
-        let route = format!(
-            "/repos/{owner}/{repo}/pulls/{pr}",
-            owner = self.owner,
-            repo = self.repo,
-            pr = pr
-        );
-
-        let uri = Uri::builder()
-            .path_and_query(route)
-            .build()
-            .context(crate::error::HttpSnafu)?;
-        let request = Builder::new()
-            .method(Method::GET)
-            .uri(uri)
-            .header(http::header::ACCEPT, crate::format_media_type("diff"));
-        let request = self.crab.build_request(request, None::<&()>)?;
-        let response = crate::map_github_error(self.crab.execute(request).await?).await?;
-        self.crab.body_to_string(response).await
+        self.get_as_text(pr, "diff").await
     }
 
     /// Get's a given pull request's patch.
@@ -158,6 +186,12 @@ impl<'octo> PullRequestHandler<'octo> {
     /// # }
     /// ```
     pub async fn get_patch(&self, pr: u64) -> crate::Result<String> {
+        self.get_as_text(pr, "patch").await
+    }
+
+    /// Gets a pull request in a non-JSON `media_type`, returning the
+    /// response body as is.
+    async fn get_as_text(&self, pr: u64, media_type: &str) -> crate::Result<String> {
         let route = format!(
             "/repos/{owner}/{repo}/pulls/{pr}",
             owner = self.owner,
@@ -170,9 +204,9 @@ impl<'octo> PullRequestHandler<'octo> {
             .build()
             .context(crate::error::HttpSnafu)?;
         let request = Builder::new()
-            .method("GET")
+            .method(Method::GET)
             .uri(uri)
-            .header(http::header::ACCEPT, crate::format_media_type("patch"));
+            .header(http::header::ACCEPT, crate::format_media_type(media_type));
         let request = self.crab.build_request(request, None::<&()>)?;
         let response = crate::map_github_error(self.crab.execute(request).await?).await?;
 
@@ -210,7 +244,7 @@ impl<'octo> PullRequestHandler<'octo> {
         create::CreatePullRequestBuilder::new(self, title, head, base)
     }
 
-    /// Update a new pull request.
+    /// Update an existing pull request.
     ///
     /// - `pull_number` — pull request number.
     /// ```no_run
@@ -272,10 +306,121 @@ impl<'octo> PullRequestHandler<'octo> {
         ListReviewsBuilder::new(self, pr_number)
     }
 
-    /// Request a review from users or teams.
+    /// Creates a new [`CreateReviewBuilder`] for reviewing a pull request.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::pulls::{ReviewComment, ReviewEvent};
+    ///
+    /// let review = octocrab::instance()
+    ///     .pulls("owner", "repo")
+    ///     .create_review(101)
+    ///     .body("Thanks! One small thing.")
+    ///     .event(ReviewEvent::RequestChanges)
+    ///     .comments([ReviewComment {
+    ///         path: "src/lib.rs".to_string(),
+    ///         line: Some(12),
+    ///         body: "This should return an error.".to_string(),
+    ///         ..Default::default()
+    ///     }])
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_review(&self, pr_number: u64) -> CreateReviewBuilder<'_, '_> {
+        CreateReviewBuilder::new(self, pr_number)
+    }
+
+    /// Submits a pending review with `event`. Fails without sending a request
+    /// if `event` is
+    /// [`ReviewEvent::Pending`](crate::params::pulls::ReviewEvent::Pending),
+    /// as a review can't be submitted as pending.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
-    /// let review = octocrab::instance().pulls("owner", "repo")
+    /// use octocrab::params::pulls::ReviewEvent;
+    ///
+    /// let review = octocrab::instance()
+    ///     .pulls("owner", "repo")
+    ///     .submit_review(101, 80u64, ReviewEvent::Approve, None)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn submit_review(
+        &self,
+        pr_number: u64,
+        review_id: impl Into<crate::models::ReviewId>,
+        event: crate::params::pulls::ReviewEvent,
+        body: impl Into<Option<String>>,
+    ) -> crate::Result<crate::models::pulls::Review> {
+        #[derive(serde::Serialize)]
+        struct SubmitReview {
+            event: crate::params::pulls::ReviewEvent,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            body: Option<String>,
+        }
+
+        if event == crate::params::pulls::ReviewEvent::Pending {
+            return Err(crate::Error::Other {
+                source: "a review can't be submitted as pending".into(),
+                backtrace: snafu::GenerateImplicitData::generate(),
+            });
+        }
+
+        let route = format!("{}/events", self.review_route(pr_number, review_id.into()));
+        self.http_post(
+            route,
+            Some(&SubmitReview {
+                event,
+                body: body.into(),
+            }),
+        )
+        .await
+    }
+
+    /// Dismisses a review, giving `message` as the reason. Only
+    /// administrators, or anyone when branch protection requires reviews,
+    /// can dismiss reviews.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let review = octocrab::instance()
+    ///     .pulls("owner", "repo")
+    ///     .dismiss_review(101, 80u64, "The requested changes were made.")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn dismiss_review(
+        &self,
+        pr_number: u64,
+        review_id: impl Into<crate::models::ReviewId>,
+        message: impl Into<String>,
+    ) -> crate::Result<crate::models::pulls::Review> {
+        let route = format!(
+            "{}/dismissals",
+            self.review_route(pr_number, review_id.into())
+        );
+        self.http_put(
+            route,
+            Some(&serde_json::json!({ "message": message.into() })),
+        )
+        .await
+    }
+
+    fn review_route(&self, pr_number: u64, review_id: crate::models::ReviewId) -> String {
+        format!(
+            "/repos/{owner}/{repo}/pulls/{pr_number}/reviews/{review_id}",
+            owner = self.owner,
+            repo = self.repo,
+        )
+    }
+
+    /// Request a review from users or teams, returning the updated pull
+    /// request. Empty lists are left out of the request, as GitHub rejects
+    /// them.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let pr = octocrab::instance().pulls("owner", "repo")
     ///    .request_reviews(101, ["user1".to_string(), "user2".to_string()], ["team1".to_string(), "team2".to_string()])
     ///  .await?;
     /// # Ok(())
@@ -286,24 +431,27 @@ impl<'octo> PullRequestHandler<'octo> {
         pr: u64,
         reviewers: impl Into<Vec<String>>,
         team_reviewers: impl Into<Vec<String>>,
-    ) -> crate::Result<crate::models::pulls::Review> {
+    ) -> crate::Result<crate::models::pulls::PullRequest> {
         let route = format!(
             "/repos/{owner}/{repo}/pulls/{pr}/requested_reviewers",
             owner = self.owner,
             repo = self.repo,
         );
 
-        let mut map = serde_json::Map::new();
-        map.insert("reviewers".to_string(), reviewers.into().into());
-        map.insert("team_reviewers".to_string(), team_reviewers.into().into());
+        let body = RequestedReviewers {
+            reviewers: reviewers.into(),
+            team_reviewers: team_reviewers.into(),
+        };
 
-        self.crab.post(route, Some(&map)).await
+        self.crab.post(route, Some(&body)).await
     }
 
-    /// Remove a requested reviewer from users or teams.
+    /// Remove a requested reviewer from users or teams, returning the updated
+    /// pull request. Empty lists are left out of the request, as GitHub
+    /// rejects them.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
-    /// let review = octocrab::instance().pulls("owner", "repo")
+    /// let pr = octocrab::instance().pulls("owner", "repo")
     ///    .remove_requested_reviewers(101, ["user1".to_string(), "user2".to_string()], ["team1".to_string(), "team2".to_string()])
     ///  .await?;
     /// # Ok(())
@@ -314,42 +462,65 @@ impl<'octo> PullRequestHandler<'octo> {
         pr: u64,
         reviewers: impl Into<Vec<String>>,
         team_reviewers: impl Into<Vec<String>>,
-    ) -> crate::Result<crate::models::pulls::Review> {
+    ) -> crate::Result<crate::models::pulls::PullRequest> {
         let route = format!(
             "/repos/{owner}/{repo}/pulls/{pr}/requested_reviewers",
             owner = self.owner,
             repo = self.repo,
         );
 
-        let mut map = serde_json::Map::new();
-        map.insert("reviewers".to_string(), reviewers.into().into());
-        map.insert("team_reviewers".to_string(), team_reviewers.into().into());
+        let body = RequestedReviewers {
+            reviewers: reviewers.into(),
+            team_reviewers: team_reviewers.into(),
+        };
 
-        self.crab.delete(route, Some(&map)).await
+        self.crab.delete(route, Some(&body)).await
     }
 
-    /// List all `DiffEntry`s associated with the pull request.
+    /// Lists all of the `DiffEntry`s associated with the pull request.
+    ///
+    /// The `patch` of a file is omitted for binary files.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
-    /// let files = octocrab::instance().pulls("owner", "repo").list_files(101).await?;
+    /// let files = octocrab::instance()
+    ///     .pulls("owner", "repo")
+    ///     .list_files(101)
+    ///     .per_page(100)
+    ///     .page(2u32)
+    ///     .send()
+    ///     .await?;
     /// # Ok(())
     /// # }
-    pub async fn list_files(
-        &self,
-        pr: u64,
-    ) -> crate::Result<Page<crate::models::repos::DiffEntry>> {
-        let route = format!(
-            "/repos/{owner}/{repo}/pulls/{pr}/files",
-            owner = self.owner,
-            repo = self.repo,
-        );
+    /// ```
+    pub fn list_files(&self, pr_number: u64) -> ListFilesBuilder<'_, '_> {
+        ListFilesBuilder::new(self, pr_number)
+    }
 
-        self.http_get(route, None::<&()>).await
+    /// Lists the commits of the pull request, oldest first. At most 250
+    /// commits are listed; use the repository's commits listing for larger
+    /// pull requests.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let commits = octocrab::instance()
+    ///     .pulls("owner", "repo")
+    ///     .commits(101)
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn commits(&self, pr_number: u64) -> ListCommitsBuilder<'_, '_> {
+        ListCommitsBuilder::new(self, pr_number)
     }
 
     /// Creates a new `ListCommentsBuilder` that can be configured to list and
     /// filter `Comments` for a particular pull request. If no pull request is
     /// specified, lists comments for the whole repo.
+    ///
+    /// These are review comments on the diff. Comments on a pull request's
+    /// conversation are issue comments, and are managed through
+    /// [`crate::issues::IssueHandler::create_comment`] and friends.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
     /// # let octocrab = octocrab::Octocrab::default();
@@ -372,6 +543,81 @@ impl<'octo> PullRequestHandler<'octo> {
         comment::ListCommentsBuilder::new(self, pr)
     }
 
+    /// Creates a new [`CreateReviewCommentBuilder`] for commenting on the
+    /// diff of a pull request, or replying to such a comment.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::pulls::comments::Side;
+    ///
+    /// let comment = octocrab::instance()
+    ///     .pulls("owner", "repo")
+    ///     .create_review_comment(101, "This could be a constant.")
+    ///     .commit_id("6dcb09b5b57875f334f61aebed695e2e4193db5e")
+    ///     .path("src/lib.rs")
+    ///     .start_line(10u64)
+    ///     .line(12u64)
+    ///     .side(Side::Right)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_review_comment(
+        &self,
+        pr: u64,
+        body: impl Into<String>,
+    ) -> CreateReviewCommentBuilder<'_, '_> {
+        CreateReviewCommentBuilder::new(self, pr, body.into())
+    }
+
+    /// Updates the body of a review comment.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let comment = octocrab::instance()
+    ///     .pulls("owner", "repo")
+    ///     .update_review_comment(1, "This could be a `const`.")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_review_comment(
+        &self,
+        comment_id: impl Into<crate::models::CommentId>,
+        body: impl Into<String>,
+    ) -> crate::Result<crate::models::pulls::Comment> {
+        let route = self.review_comment_route(comment_id.into());
+        self.http_patch(route, Some(&serde_json::json!({ "body": body.into() })))
+            .await
+    }
+
+    /// Deletes a review comment.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .pulls("owner", "repo")
+    ///     .delete_review_comment(1)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_review_comment(
+        &self,
+        comment_id: impl Into<crate::models::CommentId>,
+    ) -> crate::Result<()> {
+        let route = self.review_comment_route(comment_id.into());
+        crate::map_github_error(self.crab._delete(route, None::<&()>).await?)
+            .await
+            .map(drop)
+    }
+
+    fn review_comment_route(&self, comment_id: crate::models::CommentId) -> String {
+        format!(
+            "/repos/{owner}/{repo}/pulls/comments/{comment_id}",
+            owner = self.owner,
+            repo = self.repo,
+        )
+    }
+
     /// Creates a new `MergePullRequestsBuilder` that can be configured used to
     /// merge a pull request.
     /// ```no_run
@@ -379,11 +625,11 @@ impl<'octo> PullRequestHandler<'octo> {
     /// # let octocrab = octocrab::Octocrab::default();
     /// use octocrab::params;
     ///
-    /// let page = octocrab.pulls("owner", "repo").merge(20)
+    /// let merge = octocrab.pulls("owner", "repo").merge(20)
     ///     // Optional Parameters
     ///     .title("cool title")
     ///     .message("a message")
-    ///     // Won't merge of the HEAD commit of the PR branch is not the same
+    ///     // Won't merge if the HEAD commit of the PR branch is not the same
     ///     .sha("0123456")
     ///     // The method to use when merging, will default to `Merge`
     ///     .method(params::pulls::MergeMethod::Squash)
@@ -398,6 +644,75 @@ impl<'octo> PullRequestHandler<'octo> {
     }
 }
 
+/// # Reactions
+impl<'octo> PullRequestHandler<'octo> {
+    /// Lists the reactions to a review comment.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let reactions = octocrab::instance()
+    ///     .pulls("owner", "repo")
+    ///     .list_comment_reactions(1)
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_comment_reactions(
+        &self,
+        comment_id: impl Into<crate::models::CommentId>,
+    ) -> ListCommentReactionsBuilder<'_, '_> {
+        ListCommentReactionsBuilder::new(self, comment_id.into())
+    }
+
+    /// Creates a reaction to a review comment. If the authenticated user
+    /// already reacted with `content`, the existing reaction is returned.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .pulls("owner", "repo")
+    ///     .create_comment_reaction(1, octocrab::models::reactions::ReactionContent::Rocket)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_comment_reaction(
+        &self,
+        comment_id: impl Into<crate::models::CommentId>,
+        content: crate::models::reactions::ReactionContent,
+    ) -> crate::Result<crate::models::reactions::Reaction> {
+        let route = self.comment_reactions_route(comment_id.into());
+        super::reactions::create(self.crab, route, content).await
+    }
+
+    /// Deletes a reaction to a review comment.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .pulls("owner", "repo")
+    ///     .delete_comment_reaction(1, 1)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_comment_reaction(
+        &self,
+        comment_id: impl Into<crate::models::CommentId>,
+        reaction_id: impl Into<crate::models::ReactionId>,
+    ) -> crate::Result<()> {
+        let route = self.comment_reactions_route(comment_id.into());
+        super::reactions::delete(self.crab, route, reaction_id.into()).await
+    }
+
+    fn comment_reactions_route(&self, comment_id: crate::models::CommentId) -> String {
+        format!(
+            "/repos/{owner}/{repo}/pulls/comments/{comment_id}/reactions",
+            owner = self.owner,
+            repo = self.repo,
+        )
+    }
+}
+
 impl<'octo, 'r> ListReviewsBuilder<'octo, 'r> {
     pub(crate) fn new(handler: &'r PullRequestHandler<'octo>, pr_number: u64) -> Self {
         Self {
@@ -433,6 +748,109 @@ impl<'octo, 'r> ListReviewsBuilder<'octo, 'r> {
     }
 }
 
+impl<'octo, 'r> ListFilesBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r PullRequestHandler<'octo>, pr_number: u64) -> Self {
+        Self {
+            handler,
+            pr_number,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Send the actual request.
+    pub async fn send(self) -> crate::Result<Page<crate::models::repos::DiffEntry>> {
+        let route = format!(
+            "/repos/{owner}/{repo}/pulls/{pr}/files",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+            pr = self.pr_number,
+        );
+
+        self.handler.http_get(route, Some(&self)).await
+    }
+}
+
+impl<'octo, 'r> ListCommitsBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r PullRequestHandler<'octo>, pr_number: u64) -> Self {
+        Self {
+            handler,
+            pr_number,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Send the actual request.
+    pub async fn send(self) -> crate::Result<Page<crate::models::repos::RepoCommit>> {
+        let route = format!(
+            "/repos/{owner}/{repo}/pulls/{pr}/commits",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+            pr = self.pr_number,
+        );
+
+        self.handler.http_get(route, Some(&self)).await
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct ListCommitsBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r PullRequestHandler<'octo>,
+    #[serde(skip)]
+    pr_number: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+#[derive(serde::Serialize)]
+pub struct ListFilesBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r PullRequestHandler<'octo>,
+    #[serde(skip)]
+    pr_number: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+/// The body of a request to add or remove requested reviewers.
+#[derive(serde::Serialize)]
+struct RequestedReviewers {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    reviewers: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    team_reviewers: Vec<String>,
+}
+
 #[derive(serde::Serialize)]
 pub struct ListReviewsBuilder<'octo, 'r> {
     #[serde(skip)]
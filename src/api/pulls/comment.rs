@@ -1,4 +1,5 @@
 use super::*;
+use crate::params::pulls::comments::Side;
 
 /// A builder pattern struct for listing comments.
 ///
@@ -84,8 +85,218 @@ impl<'octo, 'b> ListCommentsBuilder<'octo, 'b> {
     }
 }
 
+/// A builder pattern struct for listing the reactions to a review comment.
+///
+/// created by [`PullRequestHandler::list_comment_reactions`]
+#[derive(serde::Serialize)]
+pub struct ListCommentReactionsBuilder<'octo, 'b> {
+    #[serde(skip)]
+    handler: &'b PullRequestHandler<'octo>,
+    #[serde(skip)]
+    comment_id: crate::models::CommentId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<crate::models::reactions::ReactionContent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'b> ListCommentReactionsBuilder<'octo, 'b> {
+    pub(crate) fn new(
+        handler: &'b PullRequestHandler<'octo>,
+        comment_id: crate::models::CommentId,
+    ) -> Self {
+        Self {
+            handler,
+            comment_id,
+            content: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Only list reactions of this kind.
+    pub fn content(mut self, content: crate::models::reactions::ReactionContent) -> Self {
+        self.content = Some(content);
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Page<crate::models::reactions::Reaction>> {
+        let route = self.handler.comment_reactions_route(self.comment_id);
+        self.handler.crab.get(route, Some(&self)).await
+    }
+}
+
+/// A builder pattern struct for commenting on the diff of a pull request.
+///
+/// Created by [`PullRequestHandler::create_review_comment`].
+#[derive(serde::Serialize)]
+pub struct CreateReviewCommentBuilder<'octo, 'b> {
+    #[serde(skip)]
+    handler: &'b PullRequestHandler<'octo>,
+    #[serde(skip)]
+    pr: u64,
+    body: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commit_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    position: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    side: Option<Side>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_line: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_side: Option<Side>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    in_reply_to: Option<crate::models::CommentId>,
+}
+
+impl<'octo, 'b> CreateReviewCommentBuilder<'octo, 'b> {
+    pub(crate) fn new(handler: &'b PullRequestHandler<'octo>, pr: u64, body: String) -> Self {
+        Self {
+            handler,
+            pr,
+            body,
+            commit_id: None,
+            path: None,
+            position: None,
+            line: None,
+            side: None,
+            start_line: None,
+            start_side: None,
+            in_reply_to: None,
+        }
+    }
+
+    /// The SHA of the commit to comment on.
+    pub fn commit_id(mut self, commit_id: impl Into<String>) -> Self {
+        self.commit_id = Some(commit_id.into());
+        self
+    }
+
+    /// The path of the file to comment on.
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// The position in the diff to comment on, counted from the first `@@`
+    /// hunk header. Deprecated by GitHub in favour of [`line`](Self::line),
+    /// and can't be combined with it.
+    pub fn position(mut self, position: impl Into<u64>) -> Self {
+        self.position = Some(position.into());
+        self
+    }
+
+    /// The line of the file to comment on, or the last line of the range
+    /// for a multi-line comment.
+    pub fn line(mut self, line: impl Into<u64>) -> Self {
+        self.line = Some(line.into());
+        self
+    }
+
+    /// The side of the diff `line` is on. Default: `RIGHT`.
+    pub fn side(mut self, side: Side) -> Self {
+        self.side = Some(side);
+        self
+    }
+
+    /// The first line of the range for a multi-line comment. Must come
+    /// before `line`.
+    pub fn start_line(mut self, start_line: impl Into<u64>) -> Self {
+        self.start_line = Some(start_line.into());
+        self
+    }
+
+    /// The side of the diff `start_line` is on.
+    pub fn start_side(mut self, start_side: Side) -> Self {
+        self.start_side = Some(start_side);
+        self
+    }
+
+    /// Replies to the top-level review comment `comment_id` instead. Only
+    /// the body is needed for a reply; GitHub ignores everything else.
+    pub fn in_reply_to(mut self, comment_id: impl Into<crate::models::CommentId>) -> Self {
+        self.in_reply_to = Some(comment_id.into());
+        self
+    }
+
+    /// Checks that the location of the comment is complete and that its
+    /// fields aren't combined in a way GitHub would reject.
+    fn validate(&self) -> crate::Result<()> {
+        let invalid = |message: &str| {
+            Err(crate::Error::Other {
+                source: format!("Invalid review comment: {message}").into(),
+                backtrace: snafu::GenerateImplicitData::generate(),
+            })
+        };
+
+        if self.in_reply_to.is_some() {
+            return Ok(());
+        }
+        if self.commit_id.is_none() || self.path.is_none() {
+            return invalid("`commit_id` and `path` are required unless replying");
+        }
+        if self.position.is_some() {
+            if self.line.is_some()
+                || self.side.is_some()
+                || self.start_line.is_some()
+                || self.start_side.is_some()
+            {
+                return invalid("`position` can't be combined with `line`, `side`, `start_line` or `start_side`");
+            }
+            return Ok(());
+        }
+        let line = match self.line {
+            Some(line) => line,
+            None => return invalid("either `line` or `position` is required"),
+        };
+        match self.start_line {
+            Some(start_line) if start_line >= line => {
+                invalid("`start_line` must come before `line`")
+            }
+            None if self.start_side.is_some() => invalid("`start_side` requires `start_line`"),
+            _ => Ok(()),
+        }
+    }
+
+    /// Sends the actual request. Invalid combinations of fields are
+    /// rejected with an [`Error::Other`](crate::Error::Other) before
+    /// anything is sent.
+    pub async fn send(self) -> crate::Result<crate::models::pulls::Comment> {
+        self.validate()?;
+        let route = format!(
+            "/repos/{owner}/{repo}/pulls/{pr}/comments",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+            pr = self.pr,
+        );
+        self.handler.http_post(route, Some(&self)).await
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::params::pulls::comments::Side;
+
     #[tokio::test]
     async fn serialize() {
         let octocrab = crate::Octocrab::default();
@@ -110,4 +321,84 @@ mod tests {
             })
         )
     }
+
+    #[tokio::test]
+    async fn serialize_multi_line_review_comment() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.pulls("rust-lang", "rust");
+        let create = handler
+            .create_review_comment(1, "Great stuff!")
+            .commit_id("6dcb09b5b57875f334f61aebed695e2e4193db5e")
+            .path("file1.txt")
+            .start_line(1u64)
+            .start_side(Side::Right)
+            .line(2u64)
+            .side(Side::Right);
+
+        assert!(create.validate().is_ok());
+        assert_eq!(
+            serde_json::to_value(create).unwrap(),
+            serde_json::json!({
+                "body": "Great stuff!",
+                "commit_id": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+                "path": "file1.txt",
+                "start_line": 1,
+                "start_side": "RIGHT",
+                "line": 2,
+                "side": "RIGHT",
+            })
+        )
+    }
+
+    #[tokio::test]
+    async fn validate_review_comment() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.pulls("rust-lang", "rust");
+        let comment = || {
+            handler
+                .create_review_comment(1, "Great stuff!")
+                .commit_id("6dcb09b5b57875f334f61aebed695e2e4193db5e")
+                .path("file1.txt")
+        };
+
+        assert!(comment().position(4u64).validate().is_ok());
+        assert!(comment().line(4u64).validate().is_ok());
+        assert!(handler
+            .create_review_comment(1, "Agreed")
+            .in_reply_to(42u64)
+            .validate()
+            .is_ok());
+
+        assert!(comment().validate().is_err());
+        assert!(handler
+            .create_review_comment(1, "Great stuff!")
+            .line(4u64)
+            .validate()
+            .is_err());
+        assert!(comment().position(4u64).line(4u64).validate().is_err());
+        assert!(comment().line(4u64).start_line(4u64).validate().is_err());
+        assert!(comment()
+            .line(4u64)
+            .start_side(Side::Left)
+            .validate()
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn serialize_reactions() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.pulls("rust-lang", "rust");
+        let list = handler
+            .list_comment_reactions(42)
+            .content(crate::models::reactions::ReactionContent::PlusOne)
+            .per_page(100);
+
+        assert_eq!(
+            serde_json::to_value(list).unwrap(),
+            serde_json::json!({
+                "content": "+1",
+                "per_page": 100,
+            })
+        )
+    }
 }
@@ -53,6 +53,12 @@ impl<'octo, 'b> ListPullRequestsBuilder<'octo, 'b> {
         self
     }
 
+    /// Filter pull requests by the `owner` and `branch` they come from,
+    /// formatted as [`Self::head`] expects.
+    pub fn head_branch(self, owner: impl AsRef<str>, branch: impl AsRef<str>) -> Self {
+        self.head(format!("{}:{}", owner.as_ref(), branch.as_ref()))
+    }
+
     /// Filter pulls by base branch name. Example: `gh-pages`.
     pub fn base(mut self, base: impl Into<String>) -> Self {
         self.base = Some(base.into());
@@ -127,4 +133,22 @@ mod tests {
             })
         )
     }
+
+    #[tokio::test]
+    async fn serialize_structured_head() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.pulls("rust-lang", "rust");
+        let list = handler
+            .list()
+            .head_branch("octocat", "new-feature")
+            .sort(crate::params::pulls::Sort::LongRunning);
+
+        assert_eq!(
+            serde_json::to_value(list).unwrap(),
+            serde_json::json!({
+                "head": "octocat:new-feature",
+                "sort": "long-running",
+            })
+        )
+    }
 }
@@ -58,6 +58,13 @@ impl<'octo, 'b> MergePullRequestsBuilder<'octo, 'b> {
     }
 
     /// Sends the actual request.
+    ///
+    /// If the pull request can't be merged GitHub responds with `405 Method
+    /// Not Allowed`, and if [`sha`](Self::sha) doesn't match the head of the
+    /// pull request it responds with `409 Conflict`. Both are returned as an
+    /// [`Error::GitHub`](crate::Error::GitHub) whose
+    /// [`GitHubError::status_code`](crate::GitHubError::status_code) can be
+    /// matched on.
     pub async fn send(self) -> crate::Result<crate::models::pulls::Merge> {
         let route = format!(
             "/repos/{owner}/{repo}/pulls/{pull_number}/merge",
@@ -0,0 +1,122 @@
+use crate::params::pulls::{ReviewComment, ReviewEvent};
+
+/// A builder pattern struct for creating a pull request review.
+///
+/// Created by [`super::PullRequestHandler::create_review`].
+#[derive(serde::Serialize)]
+pub struct CreateReviewBuilder<'octo, 'b> {
+    #[serde(skip)]
+    handler: &'b super::PullRequestHandler<'octo>,
+    #[serde(skip)]
+    pr_number: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commit_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    #[serde(skip_serializing_if = "is_pending")]
+    event: Option<ReviewEvent>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    comments: Vec<ReviewComment>,
+}
+
+fn is_pending(event: &Option<ReviewEvent>) -> bool {
+    matches!(event, None | Some(ReviewEvent::Pending))
+}
+
+impl<'octo, 'b> CreateReviewBuilder<'octo, 'b> {
+    pub(crate) fn new(handler: &'b super::PullRequestHandler<'octo>, pr_number: u64) -> Self {
+        Self {
+            handler,
+            pr_number,
+            commit_id: None,
+            body: None,
+            event: None,
+            comments: Vec::new(),
+        }
+    }
+
+    /// The SHA of the commit to review. Defaults to the most recent commit
+    /// of the pull request.
+    pub fn commit_id(mut self, commit_id: impl Into<String>) -> Self {
+        self.commit_id = Some(commit_id.into());
+        self
+    }
+
+    /// The text of the review. Required for `REQUEST_CHANGES` and `COMMENT`.
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// The action to take. Without one, the review is left pending.
+    pub fn event(mut self, event: ReviewEvent) -> Self {
+        self.event = Some(event);
+        self
+    }
+
+    /// Comments to leave on the diff as part of the review.
+    pub fn comments(mut self, comments: impl Into<Vec<ReviewComment>>) -> Self {
+        self.comments = comments.into();
+        self
+    }
+
+    /// Sends the actual request. GitHub refuses to let you approve or
+    /// request changes on your own pull request, which is returned as an
+    /// [`Error::GitHub`](crate::Error::GitHub) with a
+    /// `422 Unprocessable Entity` status.
+    pub async fn send(self) -> crate::Result<crate::models::pulls::Review> {
+        let route = format!(
+            "/repos/{owner}/{repo}/pulls/{pr}/reviews",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+            pr = self.pr_number,
+        );
+
+        self.handler.http_post(route, Some(&self)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::params::pulls::{ReviewComment, ReviewEvent};
+
+    #[tokio::test]
+    async fn serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.pulls("rust-lang", "rust");
+        let review = handler
+            .create_review(1)
+            .commit_id("ecdd80bb57125d7ba9641ffaa4d7d2c19d3f3091")
+            .body("Looks good, one nit.")
+            .event(ReviewEvent::RequestChanges)
+            .comments([ReviewComment {
+                path: "src/lib.rs".to_string(),
+                line: Some(12),
+                body: "Typo".to_string(),
+                ..Default::default()
+            }]);
+
+        assert_eq!(
+            serde_json::to_value(review).unwrap(),
+            serde_json::json!({
+                "commit_id": "ecdd80bb57125d7ba9641ffaa4d7d2c19d3f3091",
+                "body": "Looks good, one nit.",
+                "event": "REQUEST_CHANGES",
+                "comments": [{
+                    "path": "src/lib.rs",
+                    "line": 12,
+                    "body": "Typo",
+                }],
+            })
+        )
+    }
+
+    #[tokio::test]
+    async fn serialize_pending() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.pulls("rust-lang", "rust");
+        let review = handler.create_review(1).event(ReviewEvent::Pending);
+
+        assert_eq!(serde_json::to_value(review).unwrap(), serde_json::json!({}))
+    }
+}
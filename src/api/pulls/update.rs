@@ -4,6 +4,7 @@
 pub struct UpdatePullRequestBuilder<'octo, 'b> {
     #[serde(skip)]
     handler: &'b super::PullRequestHandler<'octo>,
+    #[serde(skip)]
     pull_number: u64,
     #[serde(skip_serializing_if = "Option::is_none")]
     title: Option<String>,
@@ -39,19 +40,19 @@ impl<'octo, 'b> UpdatePullRequestBuilder<'octo, 'b> {
         self
     }
 
-    /// The contents of the pull request.
+    /// The title of the pull request.
     pub fn title<A: Into<String>>(mut self, title: impl Into<Option<A>>) -> Self {
         self.title = title.into().map(A::into);
         self
     }
 
-    /// The contents of the pull request.
+    /// The name of the branch you want the changes pulled into.
     pub fn base<A: Into<String>>(mut self, base: impl Into<Option<A>>) -> Self {
         self.base = base.into().map(A::into);
         self
     }
 
-    /// The contents of the pull request.
+    /// Whether the pull request should be open or closed.
     pub fn state<A: Into<crate::params::pulls::State>>(
         mut self,
         state: impl Into<Option<A>>,
@@ -90,18 +91,33 @@ mod tests {
             .update(1)
             .title("title")
             .body(String::from("testing..."))
+            .base("main")
             .state(crate::params::pulls::State::Open)
             .maintainer_can_modify(true);
 
         assert_eq!(
             serde_json::to_value(list).unwrap(),
             serde_json::json!({
-                "pull_number": 1,
                 "title": "title",
+                "base": "main",
                 "body": "testing...",
                 "state": "open",
                 "maintainer_can_modify": true,
             })
         )
     }
+
+    #[tokio::test]
+    async fn serialize_omits_unset_fields() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.pulls("rust-lang", "rust");
+        let update = handler.update(1).title("title");
+
+        assert_eq!(
+            serde_json::to_value(update).unwrap(),
+            serde_json::json!({
+                "title": "title",
+            })
+        )
+    }
 }
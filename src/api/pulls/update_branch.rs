@@ -0,0 +1,85 @@
+use super::*;
+
+/// A builder pattern struct for updating the branch of a pull request with
+/// the latest changes of its base branch.
+///
+/// created by [`PullRequestHandler::update_branch`]
+#[derive(serde::Serialize)]
+pub struct UpdateBranchBuilder<'octo, 'b> {
+    #[serde(skip)]
+    handler: &'b PullRequestHandler<'octo>,
+    #[serde(skip)]
+    pr_number: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expected_head_sha: Option<String>,
+}
+
+impl<'octo, 'b> UpdateBranchBuilder<'octo, 'b> {
+    pub(crate) fn new(handler: &'b PullRequestHandler<'octo>, pr_number: u64) -> Self {
+        Self {
+            handler,
+            pr_number,
+            expected_head_sha: None,
+        }
+    }
+
+    /// SHA that the head of the pull request must match to allow the update.
+    /// If it doesn't, GitHub responds with `422 Unprocessable Entity`.
+    pub fn expected_head_sha(mut self, expected_head_sha: impl Into<String>) -> Self {
+        self.expected_head_sha = Some(expected_head_sha.into());
+        self
+    }
+
+    /// Sends the actual request.
+    ///
+    /// A branch that is already up to date is reported as
+    /// [`BranchUpdate::AlreadyUpToDate`](crate::models::pulls::BranchUpdate::AlreadyUpToDate)
+    /// rather than as the `422 Unprocessable Entity` GitHub responds with.
+    pub async fn send(self) -> crate::Result<crate::models::pulls::BranchUpdate> {
+        let route = format!(
+            "/repos/{owner}/{repo}/pulls/{pr}/update-branch",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+            pr = self.pr_number,
+        );
+
+        match self.handler.http_put(route, Some(&self)).await {
+            Ok(Scheduled { message, url }) => {
+                Ok(crate::models::pulls::BranchUpdate::Scheduled { message, url })
+            }
+            Err(crate::Error::GitHub { source, .. })
+                if source.status_code == http::StatusCode::UNPROCESSABLE_ENTITY
+                    && source.message.contains("no new commits") =>
+            {
+                Ok(crate::models::pulls::BranchUpdate::AlreadyUpToDate)
+            }
+            Err(error) => Err(error),
+        }
+    }
+}
+
+/// The body of a `202 Accepted` response to updating a branch.
+#[derive(serde::Deserialize)]
+struct Scheduled {
+    message: String,
+    url: url::Url,
+}
+
+#[cfg(test)]
+mod tests {
+    #[tokio::test]
+    async fn serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.pulls("rust-lang", "rust");
+        let update = handler
+            .update_branch(101)
+            .expected_head_sha("6dcb09b5b57875f334f61aebed695e2e4193db5e");
+
+        assert_eq!(
+            serde_json::to_value(update).unwrap(),
+            serde_json::json!({
+                "expected_head_sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+            })
+        )
+    }
+}
@@ -1,5 +1,7 @@
 //! Github RateLimit API
 
+use http::HeaderMap;
+
 use crate::{models, Octocrab, Result};
 
 /// Handler for GitHub's rate_limit API.
@@ -14,7 +16,9 @@ impl<'octo> RateLimitHandler<'octo> {
         Self { crab }
     }
 
-    /// Get the rate limit.
+    /// Get the rate limit. This request does not count against the rate
+    /// limit itself. To avoid the extra round trip, see
+    /// [`Octocrab::last_rate_limit`].
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
     /// let ratelimit = octocrab::instance()
@@ -28,3 +32,17 @@ impl<'octo> RateLimitHandler<'octo> {
         self.crab.get("/rate_limit", None::<&()>).await
     }
 }
+
+/// Reads the `x-ratelimit-*` headers GitHub sends with every API response.
+pub(crate) fn rate_from_headers(headers: &HeaderMap) -> Option<models::Rate> {
+    fn header<T: std::str::FromStr>(headers: &HeaderMap, name: &str) -> Option<T> {
+        headers.get(name)?.to_str().ok()?.parse().ok()
+    }
+
+    Some(models::Rate {
+        limit: header(headers, "x-ratelimit-limit")?,
+        used: header(headers, "x-ratelimit-used")?,
+        remaining: header(headers, "x-ratelimit-remaining")?,
+        reset: header(headers, "x-ratelimit-reset")?,
+    })
+}
@@ -0,0 +1,25 @@
+//! Helpers shared by everything that can be reacted to: issues, issue
+//! comments and pull request review comments. `route` is always the
+//! `.../reactions` route of the reacted to resource.
+
+use crate::models::{
+    reactions::{Reaction, ReactionContent},
+    ReactionId,
+};
+use crate::{Octocrab, Result};
+
+pub(crate) async fn create(
+    crab: &Octocrab,
+    route: String,
+    content: ReactionContent,
+) -> Result<Reaction> {
+    crab.post(route, Some(&serde_json::json!({ "content": content })))
+        .await
+}
+
+pub(crate) async fn delete(crab: &Octocrab, route: String, reaction_id: ReactionId) -> Result<()> {
+    let route = format!("{route}/{reaction_id}");
+    crate::map_github_error(crab._delete(route, None::<&()>).await?)
+        .await
+        .map(drop)
+}
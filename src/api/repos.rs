@@ -5,44 +5,67 @@ use http::header::ACCEPT;
 use http::request::Builder;
 use http::Uri;
 use http_body_util::combinators::BoxBody;
+use http_body_util::{BodyExt, Collected};
 use snafu::ResultExt;
 
+mod branch_protection;
 mod branches;
 mod collaborators;
 mod commits;
 mod contributors;
+mod create;
+pub mod deployments;
+pub mod environments;
 pub mod events;
 mod file;
 pub mod forks;
 mod generate;
+pub mod hooks;
 mod merges;
+pub mod milestones;
 mod pulls;
 pub mod releases;
 mod secrets;
+mod security_alerts;
 mod stargazers;
 mod status;
 mod tags;
 mod teams;
+pub mod traffic;
+mod transfer;
+mod trees;
 
 use crate::error::HttpSnafu;
 use crate::models::commits::GitCommitObject;
 use crate::models::repos;
 use crate::repos::file::GetReadmeBuilder;
 use crate::{models, params, Octocrab, Result};
+pub use branch_protection::UpdateBranchProtectionBuilder;
 pub use branches::ListBranchesBuilder;
-pub use collaborators::ListCollaboratorsBuilder;
+pub use collaborators::{
+    AddCollaboratorBuilder, ListCollaboratorsBuilder, ListRepoInvitationsBuilder,
+};
 pub use commits::ListCommitsBuilder;
 pub use contributors::ListContributorsBuilder;
+pub use create::CreateRepositoryBuilder;
+pub use deployments::DeploymentsHandler;
+pub use environments::EnvironmentsHandler;
 pub use file::{DeleteFileBuilder, GetContentBuilder, UpdateFileBuilder};
 pub use generate::GenerateRepositoryBuilder;
+pub use hooks::HooksHandler;
 pub use merges::MergeBranchBuilder;
+pub use milestones::MilestonesHandler;
 pub use pulls::ListPullsBuilder;
 pub use releases::ReleasesHandler;
 pub use secrets::RepoSecretsHandler;
+pub use security_alerts::{ListCodeScanningAlertsBuilder, ListDependabotAlertsBuilder};
 pub use stargazers::ListStarGazersBuilder;
 pub use status::{CreateStatusBuilder, ListStatusesBuilder};
 pub use tags::ListTagsBuilder;
 pub use teams::ListTeamsBuilder;
+pub use traffic::TrafficHandler;
+pub use transfer::TransferRepositoryBuilder;
+pub use trees::{CreateTreeBuilder, CreateTreeEntry, GetTreeBuilder};
 
 /// Handler for GitHub's repository API.
 ///
@@ -177,7 +200,102 @@ impl<'octo> RepoHandler<'octo> {
         self.crab.get(route, None::<&()>).await
     }
 
-    /// Creates a new reference for the repository.
+    /// Fetches the Git tree with the given `sha`, which can also be a branch
+    /// or tag name.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let tree = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .get_tree("main")
+    ///     .recursive(true)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_tree(&self, sha: impl Into<String>) -> GetTreeBuilder<'_, '_> {
+        GetTreeBuilder::new(self, sha.into())
+    }
+
+    /// Creates a new Git tree from `tree`, to be committed with
+    /// [`RepoHandler::create_git_commit_object`].
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let base_tree = "";
+    /// use octocrab::repos::CreateTreeEntry;
+    ///
+    /// let tree = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .create_tree(vec![CreateTreeEntry::file("crabs/ferris.txt", "🦀")])
+    ///     .base_tree(base_tree)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_tree(&self, tree: Vec<CreateTreeEntry>) -> CreateTreeBuilder<'_, '_> {
+        CreateTreeBuilder::new(self, tree)
+    }
+
+    /// Fetches the Git blob with the given `sha`. Use
+    /// [`GitBlob::decoded`](models::repos::GitBlob::decoded) to get its
+    /// contents.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let blob = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .get_blob("3a0f86fb8db8eea7ccbb9a95f325ddbedfb25e15")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_blob(&self, sha: impl Into<String>) -> Result<models::repos::GitBlob> {
+        let route = format!(
+            "/repos/{owner}/{repo}/git/blobs/{sha}",
+            owner = self.owner,
+            repo = self.repo,
+            sha = sha.into(),
+        );
+        self.crab.get(route, None::<&()>).await
+    }
+
+    /// Creates a new Git blob from `content`, which is either plain text or
+    /// base64 encoded as given by `encoding`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::repos::BlobEncoding;
+    ///
+    /// let blob = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .create_blob("🦀", BlobEncoding::Utf8)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_blob(
+        &self,
+        content: impl Into<String>,
+        encoding: params::repos::BlobEncoding,
+    ) -> Result<models::repos::ShortBlob> {
+        let route = format!(
+            "/repos/{owner}/{repo}/git/blobs",
+            owner = self.owner,
+            repo = self.repo,
+        );
+        self.crab
+            .post(
+                route,
+                Some(&serde_json::json!({
+                    "content": content.into(),
+                    "encoding": encoding,
+                })),
+            )
+            .await
+    }
+
+    /// Creates a new reference for the repository. Creating a reference that
+    /// already exists fails with an [`Error::GitHub`](crate::Error::GitHub)
+    /// whose status code is `422 Unprocessable Entity`.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
     /// # let master_sha = "";
@@ -212,6 +330,44 @@ impl<'octo> RepoHandler<'octo> {
             .await
     }
 
+    /// Points an existing reference at `sha`. Unless `force` is set, the
+    /// update must be a fast-forward.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let new_sha = "";
+    /// use octocrab::params::repos::Reference;
+    ///
+    /// // Moves the "heads/release" branch to `new_sha`.
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .update_ref(&Reference::Branch("release".to_string()), new_sha, false)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_ref(
+        &self,
+        reference: &params::repos::Reference,
+        sha: impl Into<String>,
+        force: bool,
+    ) -> Result<models::repos::Ref> {
+        let route = format!(
+            "/repos/{owner}/{repo}/git/refs/{ref}",
+            owner = self.owner,
+            repo = self.repo,
+            ref = reference.ref_url()
+        );
+        self.crab
+            .patch(
+                route,
+                Some(&serde_json::json!({
+                    "sha": sha.into(),
+                    "force": force,
+                })),
+            )
+            .await
+    }
+
     /// Deletes an existing reference from the repository.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
@@ -288,7 +444,7 @@ impl<'octo> RepoHandler<'octo> {
     ///         "Thought there’d never be a Rust Rap?\n"
     ///     )
     ///     .branch("master")
-    ///     .commiter(CommitAuthor {
+    ///     .committer(CommitAuthor {
     ///         name: "Octocat".to_string(),
     ///         email: "octocat@github.com".to_string(),
     ///         date: None,
@@ -345,7 +501,7 @@ impl<'octo> RepoHandler<'octo> {
     ///         blob_sha
     ///     )
     ///     .branch("master")
-    ///     .commiter(CommitAuthor {
+    ///     .committer(CommitAuthor {
     ///         name: "Octocat".to_string(),
     ///         email: "octocat@github.com".to_string(),
     ///         date: None,
@@ -392,7 +548,7 @@ impl<'octo> RepoHandler<'octo> {
     ///         blob_sha
     ///     )
     ///     .branch("master")
-    ///     .commiter(CommitAuthor {
+    ///     .committer(CommitAuthor {
     ///         name: "Octocat".to_string(),
     ///         email: "octocat@github.com".to_string(),
     ///         date: None,
@@ -417,6 +573,9 @@ impl<'octo> RepoHandler<'octo> {
     }
 
     /// List tags from a repository.
+    ///
+    /// GitHub doesn't sort tags semantically: they are listed by creation,
+    /// not by version, so `v1.10.0` may come before or after `v1.9.0`.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
     /// let tags = octocrab::instance().repos("owner", "repo").list_tags().send().await?;
@@ -427,6 +586,72 @@ impl<'octo> RepoHandler<'octo> {
         ListTagsBuilder::new(self)
     }
 
+    /// Lists the tag protection rules of the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let protections = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .list_tag_protections()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_tag_protections(&self) -> Result<Vec<models::repos::TagProtection>> {
+        self.crab
+            .get(self.tag_protection_route(), None::<&()>)
+            .await
+    }
+
+    /// Protects the tags matching `pattern`, e.g. `v*`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let protection = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .create_tag_protection("v*")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_tag_protection(
+        &self,
+        pattern: impl Into<String>,
+    ) -> Result<models::repos::TagProtection> {
+        self.crab
+            .post(
+                self.tag_protection_route(),
+                Some(&serde_json::json!({ "pattern": pattern.into() })),
+            )
+            .await
+    }
+
+    /// Deletes the tag protection rule with the given `id`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .delete_tag_protection(42u64)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_tag_protection(
+        &self,
+        id: impl Into<models::TagProtectionId>,
+    ) -> Result<()> {
+        let route = format!("{}/{}", self.tag_protection_route(), id.into());
+        crate::map_github_error(self.crab._delete(route, None::<&()>).await?)
+            .await
+            .map(drop)
+    }
+
+    fn tag_protection_route(&self) -> String {
+        format!(
+            "/repos/{owner}/{repo}/tags/protection",
+            owner = self.owner,
+            repo = self.repo,
+        )
+    }
+
     /// List branches from a repository.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
@@ -442,6 +667,81 @@ impl<'octo> RepoHandler<'octo> {
         ListBranchesBuilder::new(self)
     }
 
+    /// Fetches the protection settings of `branch`. On GitHub Enterprise
+    /// Server versions that still require it, enable the `luke-cage` preview
+    /// with [`OctocrabBuilder::add_preview`](crate::OctocrabBuilder::add_preview).
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let protection = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .get_branch_protection("main")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_branch_protection(
+        &self,
+        branch: impl AsRef<str>,
+    ) -> Result<models::repos::branch_protection::BranchProtection> {
+        self.crab
+            .get(self.branch_protection_route(branch.as_ref()), None::<&()>)
+            .await
+    }
+
+    /// Replaces the protection settings of `branch`, protecting it if it was
+    /// not protected yet.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::repos::branch_protection::RequiredPullRequestReviews;
+    ///
+    /// let protection = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .update_branch_protection("main")
+    ///     .enforce_admins(true)
+    ///     .required_pull_request_reviews(RequiredPullRequestReviews {
+    ///         required_approving_review_count: Some(1),
+    ///         ..Default::default()
+    ///     })
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update_branch_protection(
+        &self,
+        branch: impl Into<String>,
+    ) -> UpdateBranchProtectionBuilder<'_, '_> {
+        UpdateBranchProtectionBuilder::new(self, branch.into())
+    }
+
+    /// Removes the protection of `branch`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .delete_branch_protection("main")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_branch_protection(&self, branch: impl AsRef<str>) -> Result<()> {
+        crate::map_github_error(
+            self.crab
+                ._delete(self.branch_protection_route(branch.as_ref()), None::<&()>)
+                .await?,
+        )
+        .await
+        .map(drop)
+    }
+
+    fn branch_protection_route(&self, branch: &str) -> String {
+        format!(
+            "/repos/{owner}/{repo}/branches/{branch}/protection",
+            owner = self.owner,
+            repo = self.repo,
+        )
+    }
+
     /// List commits from a repository
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
@@ -475,6 +775,99 @@ impl<'octo> RepoHandler<'octo> {
         ListCollaboratorsBuilder::new(self)
     }
 
+    /// Creates a new [`AddCollaboratorBuilder`] for adding `username` as a
+    /// collaborator, or changing their permission if they already are one.
+    /// Users who aren't members of the owning organization are sent an
+    /// invitation instead, which is returned.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::teams::Permission;
+    ///
+    /// let invitation = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .add_collaborator("octocat")
+    ///     // Optional Parameters
+    ///     .permission(Permission::Maintain)
+    ///     // Send the request
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_collaborator(&self, username: impl Into<String>) -> AddCollaboratorBuilder<'_, '_> {
+        AddCollaboratorBuilder::new(self, username.into())
+    }
+
+    /// Removes `username` as a collaborator.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .remove_collaborator("octocat")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_collaborator(&self, username: impl AsRef<str>) -> Result<()> {
+        let route = self.collaborator_route(username.as_ref());
+        crate::map_github_error(self.crab._delete(route, None::<&()>).await?)
+            .await
+            .map(drop)
+    }
+
+    /// Lists the open invitations to collaborate on the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let invitations = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .list_invitations()
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_invitations(&self) -> ListRepoInvitationsBuilder<'_, '_> {
+        ListRepoInvitationsBuilder::new(self)
+    }
+
+    /// Deletes an invitation to collaborate on the repository, so it can no
+    /// longer be accepted.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .delete_invitation(1u64)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_invitation(
+        &self,
+        invitation_id: impl Into<models::RepoInvitationId>,
+    ) -> Result<()> {
+        let route = format!("{}/{}", self.invitations_route(), invitation_id.into());
+        crate::map_github_error(self.crab._delete(route, None::<&()>).await?)
+            .await
+            .map(drop)
+    }
+
+    fn collaborator_route(&self, username: &str) -> String {
+        format!(
+            "/repos/{owner}/{repo}/collaborators/{username}",
+            owner = self.owner,
+            repo = self.repo,
+        )
+    }
+
+    fn invitations_route(&self) -> String {
+        format!(
+            "/repos/{owner}/{repo}/invitations",
+            owner = self.owner,
+            repo = self.repo,
+        )
+    }
+
     /// List contributors from a repository.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
@@ -497,6 +890,99 @@ impl<'octo> RepoHandler<'octo> {
         ListStarGazersBuilder::new(self)
     }
 
+    /// Creates a new [`ListDependabotAlertsBuilder`] that can be configured
+    /// to filter the Dependabot alerts of the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::repos::dependabot::{Severity, State};
+    ///
+    /// let alerts = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .list_dependabot_alerts()
+    ///     // Optional Parameters
+    ///     .state(State::Open)
+    ///     .severity(Severity::Critical)
+    ///     .per_page(100)
+    ///     // Send the request
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_dependabot_alerts(&self) -> ListDependabotAlertsBuilder<'_, '_> {
+        ListDependabotAlertsBuilder::new(self)
+    }
+
+    /// Creates a new [`ListCodeScanningAlertsBuilder`] that can be configured
+    /// to filter the code scanning alerts of the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::repos::code_scanning::{Severity, State};
+    ///
+    /// let alerts = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .list_code_scanning_alerts()
+    ///     // Optional Parameters
+    ///     .tool_name("CodeQL")
+    ///     .state(State::Open)
+    ///     .severity(Severity::High)
+    ///     .per_page(100)
+    ///     // Send the request
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_code_scanning_alerts(&self) -> ListCodeScanningAlertsBuilder<'_, '_> {
+        ListCodeScanningAlertsBuilder::new(self)
+    }
+
+    /// Watches or ignores the repository for the authenticated user.
+    /// Setting `ignored` blocks all notifications from it.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let subscription = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .set_subscription(true, false)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_subscription(
+        &self,
+        subscribed: bool,
+        ignored: bool,
+    ) -> Result<models::activity::RepositorySubscription> {
+        let route = format!(
+            "/repos/{owner}/{repo}/subscription",
+            owner = self.owner,
+            repo = self.repo,
+        );
+        let body = serde_json::json!({ "subscribed": subscribed, "ignored": ignored });
+        self.crab.put(route, Some(&body)).await
+    }
+
+    /// Stops watching the repository for the authenticated user.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .delete_subscription()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_subscription(&self) -> Result<()> {
+        let route = format!(
+            "/repos/{owner}/{repo}/subscription",
+            owner = self.owner,
+            repo = self.repo,
+        );
+        crate::map_github_error(self.crab._delete(route, None::<&()>).await?)
+            .await
+            .map(drop)
+    }
+
     /// Lists languages for the specified repository.
     /// The value shown for each language is the number of bytes of code written in that language.
     ///
@@ -520,11 +1006,36 @@ impl<'octo> RepoHandler<'octo> {
         self.crab.get(route, None::<&()>).await
     }
 
+    /// Creates a `DeploymentsHandler` for the specified repository.
+    pub fn deployments(&self) -> deployments::DeploymentsHandler<'_, '_> {
+        deployments::DeploymentsHandler::new(self)
+    }
+
+    /// Creates an `EnvironmentsHandler` for the specified repository.
+    pub fn environments(&self) -> environments::EnvironmentsHandler<'_, '_> {
+        environments::EnvironmentsHandler::new(self)
+    }
+
+    /// Creates a `HooksHandler` for the specified repository.
+    pub fn hooks(&self) -> hooks::HooksHandler<'_, '_> {
+        hooks::HooksHandler::new(self)
+    }
+
+    /// Creates a `MilestonesHandler` for the specified repository.
+    pub fn milestones(&self) -> milestones::MilestonesHandler<'_, '_> {
+        milestones::MilestonesHandler::new(self)
+    }
+
     /// Creates a `ReleasesHandler` for the specified repository.
     pub fn releases(&self) -> releases::ReleasesHandler<'_, '_> {
         releases::ReleasesHandler::new(self)
     }
 
+    /// Creates a `TrafficHandler` for the specified repository.
+    pub fn traffic(&self) -> traffic::TrafficHandler<'_, '_> {
+        traffic::TrafficHandler::new(self)
+    }
+
     /// Create a status for a specified commit in the specified repository.
     pub fn create_status(&self, sha: String, state: models::StatusState) -> CreateStatusBuilder {
         CreateStatusBuilder::new(self, sha, state)
@@ -615,6 +1126,34 @@ impl<'octo> RepoHandler<'octo> {
         GenerateRepositoryBuilder::new(self, name)
     }
 
+    /// Creates a new [`TransferRepositoryBuilder`] that transfers the
+    /// repository to `new_owner`, a user or an organization.
+    ///
+    /// GitHub accepts the transfer with `202 Accepted` and completes it
+    /// asynchronously, so the returned repository may still show the old
+    /// owner. Transfers to a user only complete once they accept them.
+    /// Afterwards the old path answers with `301 Moved Permanently`, which
+    /// the client follows to the new location when the `follow-redirect`
+    /// feature is enabled (the default).
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::TeamId;
+    ///
+    /// let repo = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .transfer("new-owner")
+    ///     // Optional Parameters
+    ///     .team_ids(vec![TeamId(12), TeamId(345)])
+    ///     // Send the request
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn transfer(&self, new_owner: impl Into<String>) -> TransferRepositoryBuilder<'_, '_> {
+        TransferRepositoryBuilder::new(self, new_owner.into())
+    }
+
     /// Retrieve the contents of a file in raw format
     pub async fn raw_file(
         self,
@@ -661,24 +1200,69 @@ impl<'octo> RepoHandler<'octo> {
             .map(drop)
     }
 
-    /// Stream the repository contents as a .tar.gz
+    /// Downloads an archive of the repository at `reference` as a `.tar.gz`.
+    /// GitHub redirects to `codeload.github.com`, which is sent the request
+    /// without the `Authorization` header.
+    ///
+    /// The archive holds a single top-level directory named
+    /// `{owner}-{repo}-{short sha}`, whatever `reference` was, so strip it
+    /// when extracting rather than expecting the name of the branch or tag.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let tarball = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .download_tarball("main".to_string())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub async fn download_tarball(
         &self,
         reference: impl Into<params::repos::Commitish>,
-    ) -> Result<http::Response<BoxBody<Bytes, crate::Error>>> {
+    ) -> Result<Bytes> {
+        self.download_archive("tarball", reference.into()).await
+    }
+
+    /// Downloads an archive of the repository at `reference` as a `.zip`.
+    /// Like [`Self::download_tarball`], the redirect is followed without the
+    /// `Authorization` header and the archive holds a single top-level
+    /// directory named `{owner}-{repo}-{short sha}`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let zipball = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .download_zipball("v1.0.0".to_string())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_zipball(
+        &self,
+        reference: impl Into<params::repos::Commitish>,
+    ) -> Result<Bytes> {
+        self.download_archive("zipball", reference.into()).await
+    }
+
+    async fn download_archive(
+        &self,
+        format: &str,
+        reference: params::repos::Commitish,
+    ) -> Result<Bytes> {
         let route = format!(
-            "/repos/{owner}/{repo}/tarball/{reference}",
+            "/repos/{owner}/{repo}/{format}/{reference}",
             owner = self.owner,
             repo = self.repo,
-            reference = reference.into(),
         );
         let uri = Uri::builder()
             .path_and_query(route)
             .build()
             .context(HttpSnafu)?;
-        self.crab
+        let response = self
+            .crab
             .follow_location_to_data(self.crab._get(uri).await?)
-            .await
+            .await?;
+        let body = crate::map_github_error(response).await?.into_body();
+        body.collect().await.map(Collected::to_bytes)
     }
 
     /// Check if a user is a repository collaborator
@@ -695,7 +1279,7 @@ impl<'octo> RepoHandler<'octo> {
             .context(HttpSnafu)?;
 
         let response = self.crab._get(uri).await?;
-        Ok(response.status().is_success())
+        crate::exists_via_status(response).await
     }
 
     /// Merges `head` into the `base` branch.
@@ -764,7 +1348,9 @@ impl<'octo> RepoHandler<'octo> {
 pub struct CreateGitCommitObjectBuilder<'octo, 'req> {
     #[serde(skip)]
     handler: &'octo RepoHandler<'req>,
+    #[serde(skip)]
     owner: String,
+    #[serde(skip)]
     repo: String,
     message: String,
     tree: String,
@@ -0,0 +1,140 @@
+use super::*;
+use crate::models::repos::branch_protection::{
+    BranchProtection, PushRestrictions, RequiredPullRequestReviews, RequiredStatusChecks,
+};
+
+/// A builder pattern struct for replacing the protection of a branch.
+///
+/// GitHub requires every setting to be sent, so settings that are not set
+/// are sent as `null`, which turns them off.
+///
+/// Created by [`RepoHandler::update_branch_protection`].
+#[derive(serde::Serialize)]
+pub struct UpdateBranchProtectionBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    #[serde(skip)]
+    branch: String,
+    required_status_checks: Option<RequiredStatusChecks>,
+    enforce_admins: Option<bool>,
+    required_pull_request_reviews: Option<RequiredPullRequestReviews>,
+    restrictions: Option<PushRestrictions>,
+}
+
+impl<'octo, 'r> UpdateBranchProtectionBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>, branch: String) -> Self {
+        Self {
+            handler,
+            branch,
+            required_status_checks: None,
+            enforce_admins: None,
+            required_pull_request_reviews: None,
+            restrictions: None,
+        }
+    }
+
+    /// The status checks that must pass before merging, or `None` to not
+    /// require any.
+    pub fn required_status_checks(
+        mut self,
+        required_status_checks: impl Into<Option<RequiredStatusChecks>>,
+    ) -> Self {
+        self.required_status_checks = required_status_checks.into();
+        self
+    }
+
+    /// Whether the protection also applies to administrators. `None` turns
+    /// it off like `false` does.
+    pub fn enforce_admins(mut self, enforce_admins: impl Into<Option<bool>>) -> Self {
+        self.enforce_admins = enforce_admins.into();
+        self
+    }
+
+    /// The reviews a pull request needs before merging, or `None` to not
+    /// require pull requests.
+    pub fn required_pull_request_reviews(
+        mut self,
+        required_pull_request_reviews: impl Into<Option<RequiredPullRequestReviews>>,
+    ) -> Self {
+        self.required_pull_request_reviews = required_pull_request_reviews.into();
+        self
+    }
+
+    /// Who may push to the branch, or `None` to not restrict pushing. Only
+    /// available for organization-owned repositories.
+    pub fn restrictions(mut self, restrictions: impl Into<Option<PushRestrictions>>) -> Self {
+        self.restrictions = restrictions.into();
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<BranchProtection> {
+        let route = self.handler.branch_protection_route(&self.branch);
+        self.handler.crab.put(route, Some(&self)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::repos::branch_protection::{
+        RequiredPullRequestReviews, RequiredStatusChecks, StatusCheck,
+    };
+
+    #[tokio::test]
+    async fn serialize_unset_settings_as_null() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.repos("rust-lang", "rust");
+        let update = handler.update_branch_protection("main");
+
+        assert_eq!(
+            serde_json::to_value(update).unwrap(),
+            serde_json::json!({
+                "required_status_checks": null,
+                "enforce_admins": null,
+                "required_pull_request_reviews": null,
+                "restrictions": null,
+            })
+        )
+    }
+
+    #[tokio::test]
+    async fn serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.repos("rust-lang", "rust");
+        let update = handler
+            .update_branch_protection("main")
+            .required_status_checks(RequiredStatusChecks {
+                strict: true,
+                checks: vec![StatusCheck {
+                    context: "ci".to_string(),
+                    app_id: None,
+                }],
+                ..Default::default()
+            })
+            .enforce_admins(true)
+            .required_pull_request_reviews(RequiredPullRequestReviews {
+                dismiss_stale_reviews: true,
+                required_approving_review_count: Some(2),
+                ..Default::default()
+            })
+            .enforce_admins(None);
+
+        assert_eq!(
+            serde_json::to_value(update).unwrap(),
+            serde_json::json!({
+                "required_status_checks": {
+                    "strict": true,
+                    "contexts": [],
+                    "checks": [{ "context": "ci" }],
+                },
+                "enforce_admins": null,
+                "required_pull_request_reviews": {
+                    "dismiss_stale_reviews": true,
+                    "require_code_owner_reviews": false,
+                    "required_approving_review_count": 2,
+                },
+                "restrictions": null,
+            })
+        )
+    }
+}
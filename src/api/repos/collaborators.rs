@@ -5,6 +5,8 @@ pub struct ListCollaboratorsBuilder<'octo, 'r> {
     #[serde(skip)]
     handler: &'r RepoHandler<'octo>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    affiliation: Option<params::repos::collaborators::Affiliation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     per_page: Option<u8>,
     #[serde(skip_serializing_if = "Option::is_none")]
     page: Option<u32>,
@@ -14,11 +16,19 @@ impl<'octo, 'r> ListCollaboratorsBuilder<'octo, 'r> {
     pub fn new(handler: &'r RepoHandler<'octo>) -> Self {
         Self {
             handler,
+            affiliation: None,
             per_page: None,
             page: None,
         }
     }
 
+    /// Filter collaborators by how they are affiliated with the repository.
+    /// Default: `all`.
+    pub fn affiliation(mut self, affiliation: params::repos::collaborators::Affiliation) -> Self {
+        self.affiliation = Some(affiliation);
+        self
+    }
+
     /// Results per page (max 100).
     pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
         self.per_page = Some(per_page.into());
@@ -41,3 +51,121 @@ impl<'octo, 'r> ListCollaboratorsBuilder<'octo, 'r> {
         self.handler.crab.get(route, Some(&self)).await
     }
 }
+
+/// A builder pattern struct for adding a collaborator to a repository.
+///
+/// Created by [`RepoHandler::add_collaborator`].
+#[derive(serde::Serialize)]
+pub struct AddCollaboratorBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    #[serde(skip)]
+    username: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    permission: Option<params::teams::Permission>,
+}
+
+impl<'octo, 'r> AddCollaboratorBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>, username: String) -> Self {
+        Self {
+            handler,
+            username,
+            permission: None,
+        }
+    }
+
+    /// The permission to grant the collaborator. Default: `push`.
+    pub fn permission(mut self, permission: params::teams::Permission) -> Self {
+        self.permission = Some(permission);
+        self
+    }
+
+    /// Sends the actual request.
+    ///
+    /// Returns the invitation sent to the user, or `None` if they didn't
+    /// need one, e.g. because they are a member of the organization owning
+    /// the repository or already a collaborator.
+    pub async fn send(self) -> crate::Result<Option<models::repos::RepoInvitation>> {
+        let route = self.handler.collaborator_route(&self.username);
+        self.handler.crab.put(route, Some(&self)).await
+    }
+}
+
+/// A builder pattern struct for listing the open invitations to collaborate
+/// on a repository.
+///
+/// Created by [`RepoHandler::list_invitations`].
+#[derive(serde::Serialize)]
+pub struct ListRepoInvitationsBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListRepoInvitationsBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>) -> Self {
+        Self {
+            handler,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<crate::Page<models::repos::RepoInvitation>> {
+        let route = self.handler.invitations_route();
+        self.handler.crab.get(route, Some(&self)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::params;
+
+    #[tokio::test]
+    async fn list_serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.repos("rust-lang", "rust");
+        let list = handler
+            .list_collaborators()
+            .affiliation(params::repos::collaborators::Affiliation::Outside)
+            .per_page(100);
+
+        assert_eq!(
+            serde_json::to_value(list).unwrap(),
+            serde_json::json!({
+                "affiliation": "outside",
+                "per_page": 100,
+            })
+        )
+    }
+
+    #[tokio::test]
+    async fn add_serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.repos("rust-lang", "rust");
+        let add = handler
+            .add_collaborator("octocat")
+            .permission(params::teams::Permission::Triage);
+
+        assert_eq!(
+            serde_json::to_value(add).unwrap(),
+            serde_json::json!({ "permission": "triage" })
+        )
+    }
+}
@@ -67,13 +67,14 @@ impl<'octo, 'r> ListCommitsBuilder<'octo, 'r> {
         self
     }
 
-    /// Only show notifications updated after the given time.
+    /// Only commits after this date will be returned.
     pub fn since(mut self, since: DateTime<Utc>) -> Self {
         self.since = Some(since);
         self
     }
 
-    /// Only commits before this date will be returned.
+    /// Only commits before this date will be returned. Dates are sent as
+    /// RFC 3339 timestamps, e.g. `2024-01-01T00:00:00Z`.
     pub fn until(mut self, until: DateTime<Utc>) -> Self {
         self.until = Some(until);
         self
@@ -101,3 +102,36 @@ impl<'octo, 'r> ListCommitsBuilder<'octo, 'r> {
         self.handler.crab.get(route, Some(&self)).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    #[tokio::test]
+    async fn serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.repos("rust-lang", "rust");
+        let list = handler
+            .list_commits()
+            .branch("main")
+            .path("src/lib.rs")
+            .author("ferris")
+            .since(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap())
+            .until(Utc.with_ymd_and_hms(2024, 6, 30, 12, 30, 0).unwrap())
+            .per_page(100)
+            .page(2u32);
+
+        assert_eq!(
+            serde_json::to_value(list).unwrap(),
+            serde_json::json!({
+                "sha": "main",
+                "path": "src/lib.rs",
+                "author": "ferris",
+                "since": "2024-01-01T00:00:00Z",
+                "until": "2024-06-30T12:30:00Z",
+                "per_page": 100,
+                "page": 2,
+            })
+        )
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{models, Octocrab, Result};
+
+/// A builder pattern struct for creating a repository, either for the
+/// authenticated user or for an organization.
+///
+/// Created by [`CurrentAuthHandler::create_repo`] and [`OrgHandler::create_repo`].
+///
+/// [`CurrentAuthHandler::create_repo`]: crate::current::CurrentAuthHandler::create_repo
+/// [`OrgHandler::create_repo`]: crate::orgs::OrgHandler::create_repo
+#[derive(serde::Serialize)]
+pub struct CreateRepositoryBuilder<'octo> {
+    #[serde(skip)]
+    crab: &'octo Octocrab,
+    #[serde(skip)]
+    route: String,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    homepage: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    private: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    auto_init: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    gitignore_template: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    license_template: Option<String>,
+}
+
+impl<'octo> CreateRepositoryBuilder<'octo> {
+    pub(crate) fn new(crab: &'octo Octocrab, route: String, name: String) -> Self {
+        Self {
+            crab,
+            route,
+            name,
+            description: None,
+            homepage: None,
+            private: None,
+            auto_init: None,
+            gitignore_template: None,
+            license_template: None,
+        }
+    }
+
+    /// A short description of the repository.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// A URL with more information about the repository.
+    pub fn homepage(mut self, homepage: impl Into<String>) -> Self {
+        self.homepage = Some(homepage.into());
+        self
+    }
+
+    /// Whether the repository is private.
+    pub fn private(mut self, private: impl Into<bool>) -> Self {
+        self.private = Some(private.into());
+        self
+    }
+
+    /// Whether to create an initial commit with an empty README, so that the
+    /// repository has a default branch.
+    pub fn auto_init(mut self, auto_init: impl Into<bool>) -> Self {
+        self.auto_init = Some(auto_init.into());
+        self
+    }
+
+    /// The name of a [gitignore template](crate::gitignore) to apply, e.g. `Rust`.
+    pub fn gitignore_template(mut self, gitignore_template: impl Into<String>) -> Self {
+        self.gitignore_template = Some(gitignore_template.into());
+        self
+    }
+
+    /// The keyword of an open source license to apply, e.g. `mit` or `mpl-2.0`.
+    pub fn license_template(mut self, license_template: impl Into<String>) -> Self {
+        self.license_template = Some(license_template.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<models::Repository> {
+        self.crab.post(&self.route, Some(&self)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[tokio::test]
+    async fn serialize() {
+        let octocrab = crate::Octocrab::default();
+        let create = octocrab
+            .orgs("rust-lang")
+            .create_repo("octocrab")
+            .description("A modern, extensible GitHub API client.")
+            .private(true)
+            .auto_init(true)
+            .gitignore_template("Rust")
+            .license_template("mit");
+
+        assert_eq!(
+            serde_json::to_value(create).unwrap(),
+            serde_json::json!({
+                "name": "octocrab",
+                "description": "A modern, extensible GitHub API client.",
+                "private": true,
+                "auto_init": true,
+                "gitignore_template": "Rust",
+                "license_template": "mit",
+            })
+        )
+    }
+}
@@ -0,0 +1,470 @@
+use super::*;
+use crate::models::repos::deployments::{
+    CreateDeploymentResponse, Deployment, DeploymentState, DeploymentStatus,
+};
+use crate::models::DeploymentId;
+
+/// Handler for GitHub's deployments API.
+///
+/// Created with [`RepoHandler::deployments`].
+pub struct DeploymentsHandler<'octo, 'r> {
+    parent: &'r RepoHandler<'octo>,
+}
+
+impl<'octo, 'r> DeploymentsHandler<'octo, 'r> {
+    pub(crate) fn new(parent: &'r RepoHandler<'octo>) -> Self {
+        Self { parent }
+    }
+
+    /// Creates a new [`ListDeploymentsBuilder`] that can be configured to
+    /// filter listing deployments.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .deployments()
+    ///     .list()
+    ///     // Optional Parameters
+    ///     .environment("production")
+    ///     .per_page(100)
+    ///     // Send the request
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListDeploymentsBuilder<'_, '_, '_> {
+        ListDeploymentsBuilder::new(self)
+    }
+
+    /// Creates a new [`CreateDeploymentBuilder`] deploying `ref`, which can
+    /// be a branch, tag or SHA.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::repos::deployments::CreateDeploymentResponse;
+    ///
+    /// let response = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .deployments()
+    ///     .create("main")
+    ///     // Optional Parameters
+    ///     .environment("production")
+    ///     .required_contexts(vec![])
+    ///     // Send the request
+    ///     .send()
+    ///     .await?;
+    ///
+    /// match response {
+    ///     CreateDeploymentResponse::Created(deployment) => println!("Created {}", deployment.id),
+    ///     CreateDeploymentResponse::MergedBranch { message } => println!("{message}"),
+    ///     _ => {}
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create(&self, r#ref: impl Into<String>) -> CreateDeploymentBuilder<'_, '_, '_> {
+        CreateDeploymentBuilder::new(self, r#ref.into())
+    }
+
+    /// Gets a deployment by its ID.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let deployment = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .deployments()
+    ///     .get(42u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, deployment_id: DeploymentId) -> Result<Deployment> {
+        let route = format!("{}/{deployment_id}", self.collection_route());
+        self.parent.crab.get(route, None::<&()>).await
+    }
+
+    /// Creates a new [`CreateDeploymentStatusBuilder`] that sets the `state`
+    /// of a deployment.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::repos::deployments::DeploymentState;
+    ///
+    /// let status = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .deployments()
+    ///     .create_status(42u64.into(), DeploymentState::Success)
+    ///     // Optional Parameters
+    ///     .log_url("https://ci.example.com/builds/42")
+    ///     .environment_url("https://staging.example.com")
+    ///     // Send the request
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_status(
+        &self,
+        deployment_id: DeploymentId,
+        state: DeploymentState,
+    ) -> CreateDeploymentStatusBuilder<'_, '_, '_> {
+        CreateDeploymentStatusBuilder::new(self, deployment_id, state)
+    }
+
+    /// Lists the statuses of a deployment, newest first.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let statuses = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .deployments()
+    ///     .list_statuses(42u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_statuses(
+        &self,
+        deployment_id: DeploymentId,
+    ) -> Result<crate::Page<DeploymentStatus>> {
+        let route = self.statuses_route(deployment_id);
+        self.parent.crab.get(route, None::<&()>).await
+    }
+
+    fn collection_route(&self) -> String {
+        format!(
+            "/repos/{owner}/{repo}/deployments",
+            owner = self.parent.owner,
+            repo = self.parent.repo,
+        )
+    }
+
+    fn statuses_route(&self, deployment_id: DeploymentId) -> String {
+        format!("{}/{deployment_id}/statuses", self.collection_route())
+    }
+}
+
+/// A builder pattern struct for listing deployments.
+///
+/// Created by [`DeploymentsHandler::list`].
+#[derive(serde::Serialize)]
+pub struct ListDeploymentsBuilder<'octo, 'r1, 'r2> {
+    #[serde(skip)]
+    handler: &'r2 DeploymentsHandler<'octo, 'r1>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sha: Option<String>,
+    #[serde(rename = "ref", skip_serializing_if = "Option::is_none")]
+    r#ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    task: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    environment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r1, 'r2> ListDeploymentsBuilder<'octo, 'r1, 'r2> {
+    pub(crate) fn new(handler: &'r2 DeploymentsHandler<'octo, 'r1>) -> Self {
+        Self {
+            handler,
+            sha: None,
+            r#ref: None,
+            task: None,
+            environment: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Only list deployments of this commit SHA.
+    pub fn sha(mut self, sha: impl Into<String>) -> Self {
+        self.sha = Some(sha.into());
+        self
+    }
+
+    /// Only list deployments of this branch, tag or SHA.
+    pub fn r#ref(mut self, r#ref: impl Into<String>) -> Self {
+        self.r#ref = Some(r#ref.into());
+        self
+    }
+
+    /// Only list deployments with this task, e.g. `deploy`.
+    pub fn task(mut self, task: impl Into<String>) -> Self {
+        self.task = Some(task.into());
+        self
+    }
+
+    /// Only list deployments to this environment, e.g. `production`.
+    pub fn environment(mut self, environment: impl Into<String>) -> Self {
+        self.environment = Some(environment.into());
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<crate::Page<Deployment>> {
+        let route = self.handler.collection_route();
+        self.handler.parent.crab.get(route, Some(&self)).await
+    }
+}
+
+/// A builder pattern struct for creating a deployment.
+///
+/// Created by [`DeploymentsHandler::create`].
+#[derive(serde::Serialize)]
+pub struct CreateDeploymentBuilder<'octo, 'r1, 'r2> {
+    #[serde(skip)]
+    handler: &'r2 DeploymentsHandler<'octo, 'r1>,
+    #[serde(rename = "ref")]
+    r#ref: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    task: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    auto_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    required_contexts: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    payload: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    environment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    transient_environment: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    production_environment: Option<bool>,
+}
+
+impl<'octo, 'r1, 'r2> CreateDeploymentBuilder<'octo, 'r1, 'r2> {
+    pub(crate) fn new(handler: &'r2 DeploymentsHandler<'octo, 'r1>, r#ref: String) -> Self {
+        Self {
+            handler,
+            r#ref,
+            task: None,
+            auto_merge: None,
+            required_contexts: None,
+            payload: None,
+            environment: None,
+            description: None,
+            transient_environment: None,
+            production_environment: None,
+        }
+    }
+
+    /// The task to execute, e.g. `deploy` or `deploy:migrations`.
+    /// Default: `deploy`.
+    pub fn task(mut self, task: impl Into<String>) -> Self {
+        self.task = Some(task.into());
+        self
+    }
+
+    /// Whether to merge the default branch into the ref first if it is
+    /// behind. Default: `true`. See [`CreateDeploymentResponse::MergedBranch`].
+    pub fn auto_merge(mut self, auto_merge: impl Into<bool>) -> Self {
+        self.auto_merge = Some(auto_merge.into());
+        self
+    }
+
+    /// The status contexts that must be successful before deploying. Pass an
+    /// empty list to skip the checks. Default: all unique contexts.
+    pub fn required_contexts(mut self, required_contexts: impl Into<Vec<String>>) -> Self {
+        self.required_contexts = Some(required_contexts.into());
+        self
+    }
+
+    /// Extra information for the deployment system.
+    pub fn payload(mut self, payload: impl Into<serde_json::Value>) -> Self {
+        self.payload = Some(payload.into());
+        self
+    }
+
+    /// The name of the environment to deploy to. Default: `production`.
+    pub fn environment(mut self, environment: impl Into<String>) -> Self {
+        self.environment = Some(environment.into());
+        self
+    }
+
+    /// A short description of the deployment.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Whether the environment is specific to the deployment and will no
+    /// longer exist at some point in the future.
+    pub fn transient_environment(mut self, transient_environment: impl Into<bool>) -> Self {
+        self.transient_environment = Some(transient_environment.into());
+        self
+    }
+
+    /// Whether the environment is one that end-users directly interact with.
+    pub fn production_environment(mut self, production_environment: impl Into<bool>) -> Self {
+        self.production_environment = Some(production_environment.into());
+        self
+    }
+
+    /// Sends the actual request.
+    ///
+    /// A ref that can't be merged with the default branch fails with an
+    /// [`Error::GitHub`](crate::Error::GitHub) whose status code is
+    /// `409 Conflict`.
+    pub async fn send(self) -> Result<CreateDeploymentResponse> {
+        #[derive(serde::Deserialize)]
+        struct MergedBranch {
+            message: String,
+        }
+
+        let route = self.handler.collection_route();
+        let response = self.handler.parent.crab._post(route, Some(&self)).await?;
+        let response = crate::map_github_error(response).await?;
+
+        if response.status() == http::StatusCode::ACCEPTED {
+            let MergedBranch { message } = crate::FromResponse::from_response(response).await?;
+            Ok(CreateDeploymentResponse::MergedBranch { message })
+        } else {
+            let deployment: Deployment = crate::FromResponse::from_response(response).await?;
+            Ok(CreateDeploymentResponse::Created(Box::new(deployment)))
+        }
+    }
+}
+
+/// A builder pattern struct for creating a deployment status.
+///
+/// Created by [`DeploymentsHandler::create_status`].
+#[derive(serde::Serialize)]
+pub struct CreateDeploymentStatusBuilder<'octo, 'r1, 'r2> {
+    #[serde(skip)]
+    handler: &'r2 DeploymentsHandler<'octo, 'r1>,
+    #[serde(skip)]
+    deployment_id: DeploymentId,
+    state: DeploymentState,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    log_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    environment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    environment_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    auto_inactive: Option<bool>,
+}
+
+impl<'octo, 'r1, 'r2> CreateDeploymentStatusBuilder<'octo, 'r1, 'r2> {
+    pub(crate) fn new(
+        handler: &'r2 DeploymentsHandler<'octo, 'r1>,
+        deployment_id: DeploymentId,
+        state: DeploymentState,
+    ) -> Self {
+        Self {
+            handler,
+            deployment_id,
+            state,
+            log_url: None,
+            description: None,
+            environment: None,
+            environment_url: None,
+            auto_inactive: None,
+        }
+    }
+
+    /// The URL of the deployment's output.
+    pub fn log_url(mut self, log_url: impl Into<String>) -> Self {
+        self.log_url = Some(log_url.into());
+        self
+    }
+
+    /// A short description of the status.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// The name of the environment that was deployed to, if it changed.
+    pub fn environment(mut self, environment: impl Into<String>) -> Self {
+        self.environment = Some(environment.into());
+        self
+    }
+
+    /// The URL at which the deployed environment can be accessed.
+    pub fn environment_url(mut self, environment_url: impl Into<String>) -> Self {
+        self.environment_url = Some(environment_url.into());
+        self
+    }
+
+    /// Whether a `success` status marks the previous deployments to the same
+    /// environment as `inactive`. Default: `true`.
+    pub fn auto_inactive(mut self, auto_inactive: impl Into<bool>) -> Self {
+        self.auto_inactive = Some(auto_inactive.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<DeploymentStatus> {
+        let route = self.handler.statuses_route(self.deployment_id);
+        self.handler.parent.crab.post(route, Some(&self)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::repos::deployments::DeploymentState;
+
+    #[tokio::test]
+    async fn create_serialize() {
+        let octocrab = crate::Octocrab::default();
+        let repo = octocrab.repos("rust-lang", "rust");
+        let deployments = repo.deployments();
+        let create = deployments
+            .create("main")
+            .task("deploy:migrations")
+            .auto_merge(false)
+            .required_contexts(vec![])
+            .payload(serde_json::json!({ "deploy": "migrate" }))
+            .environment("staging")
+            .description("Deploy request from octocrab");
+
+        assert_eq!(
+            serde_json::to_value(create).unwrap(),
+            serde_json::json!({
+                "ref": "main",
+                "task": "deploy:migrations",
+                "auto_merge": false,
+                "required_contexts": [],
+                "payload": { "deploy": "migrate" },
+                "environment": "staging",
+                "description": "Deploy request from octocrab",
+            })
+        )
+    }
+
+    #[tokio::test]
+    async fn create_status_serialize() {
+        let octocrab = crate::Octocrab::default();
+        let repo = octocrab.repos("rust-lang", "rust");
+        let deployments = repo.deployments();
+        let create = deployments
+            .create_status(42u64.into(), DeploymentState::InProgress)
+            .log_url("https://ci.example.com/builds/42")
+            .environment_url("https://staging.example.com");
+
+        assert_eq!(
+            serde_json::to_value(create).unwrap(),
+            serde_json::json!({
+                "state": "in_progress",
+                "log_url": "https://ci.example.com/builds/42",
+                "environment_url": "https://staging.example.com",
+            })
+        )
+    }
+}
@@ -0,0 +1,106 @@
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
+use super::*;
+use crate::models::repos::deployments::{Environment, Environments};
+
+/// Handler for GitHub's deployment environments API.
+///
+/// Created with [`RepoHandler::environments`].
+pub struct EnvironmentsHandler<'octo, 'r> {
+    parent: &'r RepoHandler<'octo>,
+}
+
+impl<'octo, 'r> EnvironmentsHandler<'octo, 'r> {
+    pub(crate) fn new(parent: &'r RepoHandler<'octo>) -> Self {
+        Self { parent }
+    }
+
+    /// Creates a new [`ListEnvironmentsBuilder`] for listing the deployment
+    /// environments of the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let environments = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .environments()
+    ///     .list()
+    ///     // Optional Parameters
+    ///     .per_page(100)
+    ///     // Send the request
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListEnvironmentsBuilder<'_, '_, '_> {
+        ListEnvironmentsBuilder::new(self)
+    }
+
+    /// Gets a deployment environment by its name.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let environment = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .environments()
+    ///     .get("production")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, name: impl AsRef<str>) -> Result<Environment> {
+        let route = format!(
+            "{}/{name}",
+            self.collection_route(),
+            name = utf8_percent_encode(name.as_ref(), NON_ALPHANUMERIC),
+        );
+        self.parent.crab.get(route, None::<&()>).await
+    }
+
+    fn collection_route(&self) -> String {
+        format!(
+            "/repos/{owner}/{repo}/environments",
+            owner = self.parent.owner,
+            repo = self.parent.repo,
+        )
+    }
+}
+
+/// A builder pattern struct for listing deployment environments.
+///
+/// Created by [`EnvironmentsHandler::list`].
+#[derive(serde::Serialize)]
+pub struct ListEnvironmentsBuilder<'octo, 'r1, 'r2> {
+    #[serde(skip)]
+    handler: &'r2 EnvironmentsHandler<'octo, 'r1>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r1, 'r2> ListEnvironmentsBuilder<'octo, 'r1, 'r2> {
+    pub(crate) fn new(handler: &'r2 EnvironmentsHandler<'octo, 'r1>) -> Self {
+        Self {
+            handler,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<Environments> {
+        let route = self.handler.collection_route();
+        self.handler.parent.crab.get(route, Some(&self)).await
+    }
+}
@@ -104,7 +104,7 @@ pub struct UpdateFileBuilder<'octo, 'r> {
     #[serde(skip_serializing_if = "Option::is_none")]
     branch: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    commiter: Option<models::repos::CommitAuthor>,
+    committer: Option<models::repos::CommitAuthor>,
     #[serde(skip_serializing_if = "Option::is_none")]
     author: Option<models::repos::CommitAuthor>,
 }
@@ -124,7 +124,7 @@ impl<'octo, 'r> UpdateFileBuilder<'octo, 'r> {
             content,
             sha,
             branch: None,
-            commiter: None,
+            committer: None,
             author: None,
         }
     }
@@ -136,11 +136,17 @@ impl<'octo, 'r> UpdateFileBuilder<'octo, 'r> {
     }
 
     /// The person that committed the file.
-    pub fn commiter(mut self, commiter: impl Into<models::repos::CommitAuthor>) -> Self {
-        self.commiter = Some(commiter.into());
+    pub fn committer(mut self, committer: impl Into<models::repos::CommitAuthor>) -> Self {
+        self.committer = Some(committer.into());
         self
     }
 
+    /// The person that committed the file.
+    #[deprecated(note = "use committer instead")]
+    pub fn commiter(self, commiter: impl Into<models::repos::CommitAuthor>) -> Self {
+        self.committer(commiter)
+    }
+
     /// The author of the file.
     pub fn author(mut self, author: impl Into<models::repos::CommitAuthor>) -> Self {
         self.author = Some(author.into());
@@ -170,7 +176,7 @@ pub struct DeleteFileBuilder<'octo, 'r> {
     #[serde(skip_serializing_if = "Option::is_none")]
     branch: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    commiter: Option<models::repos::CommitAuthor>,
+    committer: Option<models::repos::CommitAuthor>,
     #[serde(skip_serializing_if = "Option::is_none")]
     author: Option<models::repos::CommitAuthor>,
 }
@@ -188,7 +194,7 @@ impl<'octo, 'r> DeleteFileBuilder<'octo, 'r> {
             message,
             sha,
             branch: None,
-            commiter: None,
+            committer: None,
             author: None,
         }
     }
@@ -200,11 +206,17 @@ impl<'octo, 'r> DeleteFileBuilder<'octo, 'r> {
     }
 
     /// The person that committed the file.
-    pub fn commiter(mut self, commiter: impl Into<models::repos::CommitAuthor>) -> Self {
-        self.commiter = Some(commiter.into());
+    pub fn committer(mut self, committer: impl Into<models::repos::CommitAuthor>) -> Self {
+        self.committer = Some(committer.into());
         self
     }
 
+    /// The person that committed the file.
+    #[deprecated(note = "use committer instead")]
+    pub fn commiter(self, commiter: impl Into<models::repos::CommitAuthor>) -> Self {
+        self.committer(commiter)
+    }
+
     /// The author of the file.
     pub fn author(mut self, author: impl Into<models::repos::CommitAuthor>) -> Self {
         self.author = Some(author.into());
@@ -239,7 +251,7 @@ mod tests {
                 "testsha",
             )
             .branch("not-master")
-            .commiter(CommitAuthor {
+            .committer(CommitAuthor {
                 name: "Octocat".to_string(),
                 email: "octocat@github.com".to_string(),
                 date: None,
@@ -259,7 +271,7 @@ mod tests {
                 "content": general_purpose::STANDARD.encode("This is a test."),
                 "sha": "testsha",
                 "branch": "not-master",
-                "commiter": {
+                "committer": {
                     "name": "Octocat",
                     "email": "octocat@github.com"
                 },
@@ -271,6 +283,23 @@ mod tests {
         )
     }
 
+    #[tokio::test]
+    async fn serialize_create_omits_sha() {
+        let octocrab = crate::instance();
+        let repo = octocrab.repos("owner", "repo");
+        let builder = repo.create_file("tests/test.txt", "Create test.txt", "This is a test.");
+
+        use base64::{engine::general_purpose, Engine as _};
+
+        assert_eq!(
+            serde_json::to_value(builder).unwrap(),
+            serde_json::json!({
+                "message": "Create test.txt",
+                "content": general_purpose::STANDARD.encode("This is a test."),
+            })
+        )
+    }
+
     #[tokio::test]
     async fn serialize_delete() {
         let octocrab = crate::instance();
@@ -278,7 +307,7 @@ mod tests {
         let builder = repo
             .delete_file("tests/test.txt", "Update test.txt", "testsha")
             .branch("not-master")
-            .commiter(CommitAuthor {
+            .committer(CommitAuthor {
                 name: "Octocat".to_string(),
                 email: "octocat@github.com".to_string(),
                 date: None,
@@ -295,7 +324,7 @@ mod tests {
                 "message": "Update test.txt",
                 "sha": "testsha",
                 "branch": "not-master",
-                "commiter": {
+                "committer": {
                     "name": "Octocat",
                     "email": "octocat@github.com"
                 },
@@ -57,12 +57,18 @@ pub struct CreateForkBuilder<'octo, 'r> {
     handler: &'r RepoHandler<'octo>,
     #[serde(skip_serializing_if = "Option::is_none")]
     organization: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_branch_only: Option<bool>,
 }
 impl<'octo, 'r> CreateForkBuilder<'octo, 'r> {
     pub(crate) fn new(handler: &'r RepoHandler<'octo>) -> Self {
         Self {
             handler,
             organization: None,
+            name: None,
+            default_branch_only: None,
         }
     }
 
@@ -72,7 +78,24 @@ impl<'octo, 'r> CreateForkBuilder<'octo, 'r> {
         self
     }
 
+    /// A new name for the fork.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Whether to only fork the default branch.
+    pub fn default_branch_only(mut self, default_branch_only: impl Into<bool>) -> Self {
+        self.default_branch_only = Some(default_branch_only.into());
+        self
+    }
+
     /// Sends the actual request.
+    ///
+    /// GitHub creates the fork asynchronously, so the returned repository may
+    /// not be accessible yet. Forking large repositories can take several
+    /// minutes; with the `poll` feature, use `RepoHandler::wait_until_ready`
+    /// on the fork to wait for it.
     pub async fn send(self) -> crate::Result<crate::models::Repository> {
         let route = format!(
             "/repos/{owner}/{repo}/forks",
@@ -125,4 +148,43 @@ impl<'octo> RepoHandler<'octo> {
     pub fn create_fork(&self) -> CreateForkBuilder<'_, '_> {
         CreateForkBuilder::new(self)
     }
+
+    /// Polls the repository until it can be fetched, e.g. after creating it
+    /// as a fork with [`RepoHandler::create_fork`]. The repository is fetched
+    /// up to `attempts` times, waiting `delay` after each `404 Not Found`;
+    /// the last error is returned if it is still not found. Requires the
+    /// `poll` feature for its timer.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::instance();
+    /// use std::time::Duration;
+    ///
+    /// let fork = octocrab.repos("owner", "repo").create_fork().send().await?;
+    /// let fork = octocrab
+    ///     .repos(fork.owner.unwrap().login, fork.name)
+    ///     .wait_until_ready(10, Duration::from_secs(3))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "poll")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "poll")))]
+    pub async fn wait_until_ready(
+        &self,
+        attempts: u32,
+        delay: std::time::Duration,
+    ) -> crate::Result<crate::models::Repository> {
+        let mut attempt = 1;
+        loop {
+            match self.get().await {
+                Err(crate::Error::GitHub { source, .. })
+                    if source.status_code == http::StatusCode::NOT_FOUND && attempt < attempts =>
+                {
+                    attempt += 1;
+                    tokio::time::sleep(delay).await;
+                }
+                result => return result,
+            }
+        }
+    }
 }
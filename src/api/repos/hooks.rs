@@ -0,0 +1,369 @@
+use crate::models::hooks::{Config, Hook};
+use crate::models::webhook_events::WebhookEventType;
+
+use super::*;
+
+/// Handler for GitHub's repository webhooks API.
+///
+/// Created with [`RepoHandler::hooks`].
+pub struct HooksHandler<'octo, 'r> {
+    parent: &'r RepoHandler<'octo>,
+}
+
+impl<'octo, 'r> HooksHandler<'octo, 'r> {
+    pub(crate) fn new(parent: &'r RepoHandler<'octo>) -> Self {
+        Self { parent }
+    }
+
+    /// Creates a new [`ListHooksBuilder`] that lists the webhooks of the
+    /// repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let hooks = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .hooks()
+    ///     .list()
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListHooksBuilder<'_, '_, '_> {
+        ListHooksBuilder::new(self)
+    }
+
+    /// Gets a single webhook.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let hook = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .hooks()
+    ///     .get(1)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, hook_id: u64) -> crate::Result<Hook> {
+        self.parent.crab.get(self.route(hook_id), None::<&()>).await
+    }
+
+    /// Creates a new [`CreateHookBuilder`] for a webhook delivering to
+    /// `config`. The `secret` of the config is never returned by GitHub.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::hooks::{Config, ContentType};
+    /// use octocrab::models::webhook_events::WebhookEventType;
+    ///
+    /// let config = Config {
+    ///     url: "https://example.com/webhook".to_string(),
+    ///     content_type: Some(ContentType::Json),
+    ///     insecure_ssl: None,
+    ///     secret: Some("shh".to_string()),
+    /// };
+    /// let hook = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .hooks()
+    ///     .create(config)
+    ///     // Optional Parameters
+    ///     .events(vec![WebhookEventType::Push, WebhookEventType::PullRequest])
+    ///     .active(true)
+    ///     // Send the request
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create(&self, config: Config) -> CreateHookBuilder<'_, '_, '_> {
+        CreateHookBuilder::new(self, config)
+    }
+
+    /// Creates a new [`UpdateHookBuilder`] for the webhook with `hook_id`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::webhook_events::WebhookEventType;
+    ///
+    /// let hook = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .hooks()
+    ///     .update(1)
+    ///     .add_events(vec![WebhookEventType::Release])
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&self, hook_id: u64) -> UpdateHookBuilder<'_, '_, '_> {
+        UpdateHookBuilder::new(self, hook_id)
+    }
+
+    /// Deletes a webhook.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .hooks()
+    ///     .delete(1)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(&self, hook_id: u64) -> crate::Result<()> {
+        crate::map_github_error(
+            self.parent
+                .crab
+                ._delete(self.route(hook_id), None::<&()>)
+                .await?,
+        )
+        .await
+        .map(drop)
+    }
+
+    /// Triggers a ping event to be sent to the webhook.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .hooks()
+    ///     .ping(1)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn ping(&self, hook_id: u64) -> crate::Result<()> {
+        let route = format!("{}/pings", self.route(hook_id));
+        crate::map_github_error(self.parent.crab._post(route, None::<&()>).await?)
+            .await
+            .map(drop)
+    }
+
+    fn route(&self, hook_id: u64) -> String {
+        format!(
+            "/repos/{owner}/{repo}/hooks/{hook_id}",
+            owner = self.parent.owner,
+            repo = self.parent.repo,
+        )
+    }
+}
+
+/// A builder pattern struct for listing webhooks.
+///
+/// created by [`HooksHandler::list`].
+#[derive(serde::Serialize)]
+pub struct ListHooksBuilder<'octo, 'repos, 'handler> {
+    #[serde(skip)]
+    handler: &'handler HooksHandler<'octo, 'repos>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'repos, 'handler> ListHooksBuilder<'octo, 'repos, 'handler> {
+    pub(crate) fn new(handler: &'handler HooksHandler<'octo, 'repos>) -> Self {
+        Self {
+            handler,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<crate::Page<Hook>> {
+        let route = format!(
+            "/repos/{owner}/{repo}/hooks",
+            owner = self.handler.parent.owner,
+            repo = self.handler.parent.repo,
+        );
+        self.handler.parent.crab.get(route, Some(&self)).await
+    }
+}
+
+/// A builder pattern struct for creating webhooks.
+///
+/// created by [`HooksHandler::create`].
+#[derive(serde::Serialize)]
+pub struct CreateHookBuilder<'octo, 'repos, 'handler> {
+    #[serde(skip)]
+    handler: &'handler HooksHandler<'octo, 'repos>,
+    name: &'static str,
+    config: Config,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    events: Vec<WebhookEventType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    active: Option<bool>,
+}
+
+impl<'octo, 'repos, 'handler> CreateHookBuilder<'octo, 'repos, 'handler> {
+    pub(crate) fn new(handler: &'handler HooksHandler<'octo, 'repos>, config: Config) -> Self {
+        Self {
+            handler,
+            name: "web",
+            config,
+            events: Vec::new(),
+            active: None,
+        }
+    }
+
+    /// The events the webhook is triggered for. Default: `push`.
+    pub fn events(mut self, events: impl Into<Vec<WebhookEventType>>) -> Self {
+        self.events = events.into();
+        self
+    }
+
+    /// Whether notifications are sent when the webhook is triggered.
+    /// Default: `true`.
+    pub fn active(mut self, active: impl Into<bool>) -> Self {
+        self.active = Some(active.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Hook> {
+        let route = format!(
+            "/repos/{owner}/{repo}/hooks",
+            owner = self.handler.parent.owner,
+            repo = self.handler.parent.repo,
+        );
+        self.handler.parent.crab.post(route, Some(&self)).await
+    }
+}
+
+/// A builder pattern struct for updating webhooks.
+///
+/// created by [`HooksHandler::update`].
+#[derive(serde::Serialize)]
+pub struct UpdateHookBuilder<'octo, 'repos, 'handler> {
+    #[serde(skip)]
+    handler: &'handler HooksHandler<'octo, 'repos>,
+    #[serde(skip)]
+    hook_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    config: Option<Config>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    events: Option<Vec<WebhookEventType>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    add_events: Vec<WebhookEventType>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    remove_events: Vec<WebhookEventType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    active: Option<bool>,
+}
+
+impl<'octo, 'repos, 'handler> UpdateHookBuilder<'octo, 'repos, 'handler> {
+    pub(crate) fn new(handler: &'handler HooksHandler<'octo, 'repos>, hook_id: u64) -> Self {
+        Self {
+            handler,
+            hook_id,
+            config: None,
+            events: None,
+            add_events: Vec::new(),
+            remove_events: Vec::new(),
+            active: None,
+        }
+    }
+
+    /// Replaces the configuration of the webhook.
+    pub fn config(mut self, config: Config) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    /// Replaces the events the webhook is triggered for.
+    pub fn events(mut self, events: impl Into<Vec<WebhookEventType>>) -> Self {
+        self.events = Some(events.into());
+        self
+    }
+
+    /// Events to add to those the webhook is triggered for.
+    pub fn add_events(mut self, add_events: impl Into<Vec<WebhookEventType>>) -> Self {
+        self.add_events = add_events.into();
+        self
+    }
+
+    /// Events to remove from those the webhook is triggered for.
+    pub fn remove_events(mut self, remove_events: impl Into<Vec<WebhookEventType>>) -> Self {
+        self.remove_events = remove_events.into();
+        self
+    }
+
+    /// Whether notifications are sent when the webhook is triggered.
+    pub fn active(mut self, active: impl Into<bool>) -> Self {
+        self.active = Some(active.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Hook> {
+        let route = self.handler.route(self.hook_id);
+        self.handler.parent.crab.patch(route, Some(&self)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::hooks::{Config, ContentType};
+    use crate::models::webhook_events::WebhookEventType;
+
+    #[tokio::test]
+    async fn serialize_create() {
+        let octocrab = crate::Octocrab::default();
+        let repo = octocrab.repos("owner", "repo");
+        let hooks = repo.hooks();
+        let create = hooks
+            .create(Config {
+                url: "https://example.com/webhook".to_string(),
+                content_type: Some(ContentType::Json),
+                insecure_ssl: Some("0".to_string()),
+                secret: Some("shh".to_string()),
+            })
+            .events(vec![WebhookEventType::Push, WebhookEventType::PullRequest])
+            .active(true);
+
+        assert_eq!(
+            serde_json::to_value(create).unwrap(),
+            serde_json::json!({
+                "name": "web",
+                "config": {
+                    "url": "https://example.com/webhook",
+                    "content_type": "json",
+                    "insecure_ssl": "0",
+                    "secret": "shh",
+                },
+                "events": ["push", "pull_request"],
+                "active": true,
+            })
+        )
+    }
+
+    #[tokio::test]
+    async fn serialize_update() {
+        let octocrab = crate::Octocrab::default();
+        let repo = octocrab.repos("owner", "repo");
+        let hooks = repo.hooks();
+        let update = hooks
+            .update(1)
+            .add_events(vec![WebhookEventType::Release])
+            .active(false);
+
+        assert_eq!(
+            serde_json::to_value(update).unwrap(),
+            serde_json::json!({
+                "add_events": ["release"],
+                "active": false,
+            })
+        )
+    }
+}
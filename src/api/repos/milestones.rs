@@ -0,0 +1,364 @@
+use super::*;
+
+/// Handler for GitHub's milestones API.
+///
+/// Created with [`RepoHandler::milestones`].
+pub struct MilestonesHandler<'octo, 'r> {
+    parent: &'r RepoHandler<'octo>,
+}
+
+impl<'octo, 'r> MilestonesHandler<'octo, 'r> {
+    pub(crate) fn new(parent: &'r RepoHandler<'octo>) -> Self {
+        Self { parent }
+    }
+
+    /// Creates a new [`ListMilestonesBuilder`] that can be configured to
+    /// filter listing milestones.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params;
+    ///
+    /// let page = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .milestones()
+    ///     .list()
+    ///     // Optional Parameters
+    ///     .state(params::State::All)
+    ///     .sort(params::milestones::Sort::Completeness)
+    ///     .direction(params::Direction::Descending)
+    ///     .per_page(100)
+    ///     .page(2u32)
+    ///     // Send the request
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListMilestonesBuilder<'_, '_, '_> {
+        ListMilestonesBuilder::new(self)
+    }
+
+    /// Creates a new [`CreateMilestoneBuilder`] with `title`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let milestone = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .milestones()
+    ///     .create("v1.0")
+    ///     // Optional Parameters
+    ///     .description("Tracking milestone for version 1.0")
+    ///     .due_on(chrono::Utc::now())
+    ///     // Send the request
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create(&self, title: impl Into<String>) -> CreateMilestoneBuilder<'_, '_, '_> {
+        CreateMilestoneBuilder::new(self, title.into())
+    }
+
+    /// Gets a milestone by its number.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let milestone = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .milestones()
+    ///     .get(1)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, number: u64) -> Result<models::Milestone> {
+        self.parent.crab.get(self.route(number), None::<&()>).await
+    }
+
+    /// Creates a new [`UpdateMilestoneBuilder`] for the milestone `number`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params;
+    ///
+    /// let milestone = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .milestones()
+    ///     .update(1)
+    ///     // Optional Parameters
+    ///     .state(params::milestones::State::Closed)
+    ///     // Send the request
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&self, number: u64) -> UpdateMilestoneBuilder<'_, '_, '_> {
+        UpdateMilestoneBuilder::new(self, number)
+    }
+
+    /// Deletes a milestone. Its issues and pull requests are kept, without
+    /// a milestone.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .milestones()
+    ///     .delete(1)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(&self, number: u64) -> Result<()> {
+        crate::map_github_error(
+            self.parent
+                .crab
+                ._delete(self.route(number), None::<&()>)
+                .await?,
+        )
+        .await
+        .map(drop)
+    }
+
+    fn collection_route(&self) -> String {
+        format!(
+            "/repos/{owner}/{repo}/milestones",
+            owner = self.parent.owner,
+            repo = self.parent.repo,
+        )
+    }
+
+    fn route(&self, number: u64) -> String {
+        format!("{}/{number}", self.collection_route())
+    }
+}
+
+/// A builder pattern struct for listing milestones.
+///
+/// Created by [`MilestonesHandler::list`].
+#[derive(serde::Serialize)]
+pub struct ListMilestonesBuilder<'octo, 'r1, 'r2> {
+    #[serde(skip)]
+    handler: &'r2 MilestonesHandler<'octo, 'r1>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<crate::params::State>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort: Option<crate::params::milestones::Sort>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    direction: Option<crate::params::Direction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r1, 'r2> ListMilestonesBuilder<'octo, 'r1, 'r2> {
+    pub(crate) fn new(handler: &'r2 MilestonesHandler<'octo, 'r1>) -> Self {
+        Self {
+            handler,
+            state: None,
+            sort: None,
+            direction: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Filter milestones by their state. Default: `open`.
+    pub fn state(mut self, state: crate::params::State) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// What to sort the results by. Default: `due_on`.
+    pub fn sort(mut self, sort: crate::params::milestones::Sort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// The direction of the sort. Default: `asc`.
+    pub fn direction(mut self, direction: crate::params::Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<crate::Page<models::Milestone>> {
+        let route = self.handler.collection_route();
+        self.handler.parent.crab.get(route, Some(&self)).await
+    }
+}
+
+/// A builder pattern struct for creating a milestone.
+///
+/// Created by [`MilestonesHandler::create`].
+#[derive(serde::Serialize)]
+pub struct CreateMilestoneBuilder<'octo, 'r1, 'r2> {
+    #[serde(skip)]
+    handler: &'r2 MilestonesHandler<'octo, 'r1>,
+    title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<crate::params::milestones::State>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    due_on: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl<'octo, 'r1, 'r2> CreateMilestoneBuilder<'octo, 'r1, 'r2> {
+    pub(crate) fn new(handler: &'r2 MilestonesHandler<'octo, 'r1>, title: String) -> Self {
+        Self {
+            handler,
+            title,
+            state: None,
+            description: None,
+            due_on: None,
+        }
+    }
+
+    /// The state of the milestone. Default: `open`.
+    pub fn state(mut self, state: crate::params::milestones::State) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// A description of the milestone.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// When the milestone is due.
+    pub fn due_on(mut self, due_on: impl Into<chrono::DateTime<chrono::Utc>>) -> Self {
+        self.due_on = Some(due_on.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<models::Milestone> {
+        let route = self.handler.collection_route();
+        self.handler.parent.crab.post(route, Some(&self)).await
+    }
+}
+
+/// A builder pattern struct for updating a milestone.
+///
+/// Created by [`MilestonesHandler::update`].
+#[derive(serde::Serialize)]
+pub struct UpdateMilestoneBuilder<'octo, 'r1, 'r2> {
+    #[serde(skip)]
+    handler: &'r2 MilestonesHandler<'octo, 'r1>,
+    #[serde(skip)]
+    number: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<crate::params::milestones::State>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    due_on: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl<'octo, 'r1, 'r2> UpdateMilestoneBuilder<'octo, 'r1, 'r2> {
+    pub(crate) fn new(handler: &'r2 MilestonesHandler<'octo, 'r1>, number: u64) -> Self {
+        Self {
+            handler,
+            number,
+            title: None,
+            state: None,
+            description: None,
+            due_on: None,
+        }
+    }
+
+    /// The title of the milestone.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// The state of the milestone.
+    pub fn state(mut self, state: crate::params::milestones::State) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// A description of the milestone.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// When the milestone is due.
+    pub fn due_on(mut self, due_on: impl Into<chrono::DateTime<chrono::Utc>>) -> Self {
+        self.due_on = Some(due_on.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<models::Milestone> {
+        let route = self.handler.route(self.number);
+        self.handler.parent.crab.patch(route, Some(&self)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::params;
+
+    #[tokio::test]
+    async fn list_serialize() {
+        let octocrab = crate::Octocrab::default();
+        let repo = octocrab.repos("rust-lang", "rust");
+        let milestones = repo.milestones();
+        let list = milestones
+            .list()
+            .state(params::State::All)
+            .sort(params::milestones::Sort::DueOn)
+            .direction(params::Direction::Descending)
+            .per_page(100);
+
+        assert_eq!(
+            serde_json::to_value(list).unwrap(),
+            serde_json::json!({
+                "state": "all",
+                "sort": "due_on",
+                "direction": "desc",
+                "per_page": 100,
+            })
+        )
+    }
+
+    #[tokio::test]
+    async fn create_serialize() {
+        let octocrab = crate::Octocrab::default();
+        let repo = octocrab.repos("rust-lang", "rust");
+        let milestones = repo.milestones();
+        let due_on = chrono::DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z").unwrap();
+        let create = milestones
+            .create("v1.0")
+            .state(params::milestones::State::Open)
+            .description("The first release")
+            .due_on(due_on);
+
+        assert_eq!(
+            serde_json::to_value(create).unwrap(),
+            serde_json::json!({
+                "title": "v1.0",
+                "state": "open",
+                "description": "The first release",
+                "due_on": "2024-01-01T00:00:00Z",
+            })
+        )
+    }
+}
@@ -85,6 +85,117 @@ impl<'octo, 'r> ReleasesHandler<'octo, 'r> {
         UpdateReleaseBuilder::new(self, release_id)
     }
 
+    /// Creates a new [`UploadAssetBuilder`] that uploads `data` as an asset
+    /// called `name` to the release with `release_id`. Assets go to the
+    /// upload host rather than the API host, see
+    /// [`OctocrabBuilder::upload_uri`](crate::OctocrabBuilder::upload_uri).
+    ///
+    /// Uploading an asset with the same name as an existing one fails with
+    /// an [`Error::GitHub`](crate::Error::GitHub) whose status code is
+    /// `422 Unprocessable Entity`.
+    ///
+    /// `data` is held in memory until the upload finishes, which lets the
+    /// request be retried. Use [`ReleasesHandler::upload_asset_stream`] for
+    /// files too large for that.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let asset = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .releases()
+    ///     .upload_asset(1, "octocrab.tar.gz", "application/gzip", std::fs::read("octocrab.tar.gz").unwrap())
+    ///     // Optional Parameters
+    ///     .label("Source code (tar.gz)")
+    ///     // Send the request
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn upload_asset(
+        &self,
+        release_id: u64,
+        name: impl Into<String>,
+        content_type: impl Into<String>,
+        data: impl Into<bytes::Bytes>,
+    ) -> UploadAssetBuilder<'_, '_, '_> {
+        UploadAssetBuilder::new(
+            self,
+            release_id,
+            name.into(),
+            content_type.into(),
+            data.into().into(),
+        )
+    }
+
+    /// Creates a new [`UploadAssetBuilder`] that uploads the chunks of
+    /// `stream` as they are produced, so the asset never has to fit in
+    /// memory. Unlike [`ReleasesHandler::upload_asset`], the upload is not
+    /// retried, as a stream can only be sent once.
+    ///
+    /// Without [`UploadAssetBuilder::content_length`] the body is sent with
+    /// chunked transfer encoding.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let chunks = futures::stream::iter(vec![
+    ///     Ok::<_, std::io::Error>(bytes::Bytes::from("first chunk, ")),
+    ///     Ok(bytes::Bytes::from("second chunk")),
+    /// ]);
+    /// let asset = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .releases()
+    ///     .upload_asset_stream(1, "notes.txt", "text/plain", chunks)
+    ///     // Optional Parameters
+    ///     .content_length(25)
+    ///     // Send the request
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn upload_asset_stream<S, E>(
+        &self,
+        release_id: u64,
+        name: impl Into<String>,
+        content_type: impl Into<String>,
+        stream: S,
+    ) -> UploadAssetBuilder<'_, '_, '_>
+    where
+        S: futures::Stream<Item = Result<bytes::Bytes, E>> + Send + 'static,
+        E: Into<tower::BoxError> + 'static,
+    {
+        UploadAssetBuilder::new(
+            self,
+            release_id,
+            name.into(),
+            content_type.into(),
+            crate::OctoBody::wrap_stream(stream),
+        )
+    }
+
+    /// Deletes a release.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .releases()
+    ///     .delete(1)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(&self, release_id: u64) -> crate::Result<()> {
+        let route = format!(
+            "/repos/{owner}/{repo}/releases/{release_id}",
+            owner = self.parent.owner,
+            repo = self.parent.repo,
+            release_id = release_id,
+        );
+
+        crate::map_github_error(self.parent.crab._delete(route, None::<&()>).await?)
+            .await
+            .map(drop)
+    }
+
     /// Fetches a single asset by its ID.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
@@ -285,6 +396,8 @@ pub struct CreateReleaseBuilder<'octo, 'repos, 'handler, 'tag_name, 'target_comm
     prerelease: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     make_latest: Option<MakeLatest>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    generate_release_notes: Option<bool>,
 }
 
 #[derive(Debug, Clone, Copy, serde::Serialize)]
@@ -323,6 +436,7 @@ impl<'octo, 'repos, 'handler, 'tag_name, 'target_commitish, 'name, 'body>
             draft: None,
             prerelease: None,
             make_latest: None,
+            generate_release_notes: None,
         }
     }
 
@@ -371,6 +485,14 @@ impl<'octo, 'repos, 'handler, 'tag_name, 'target_commitish, 'name, 'body>
         self
     }
 
+    /// Whether to automatically generate the name and body for this release.
+    /// If [`Self::name`] is set, it is used as the release name; if
+    /// [`Self::body`] is set, it is prepended to the generated notes.
+    pub fn generate_release_notes(mut self, generate_release_notes: impl Into<bool>) -> Self {
+        self.generate_release_notes = Some(generate_release_notes.into());
+        self
+    }
+
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<crate::models::repos::Release> {
         let route = format!(
@@ -382,6 +504,81 @@ impl<'octo, 'repos, 'handler, 'tag_name, 'target_commitish, 'name, 'body>
     }
 }
 
+/// A builder pattern struct for uploading release assets.
+///
+/// created by [`ReleasesHandler::upload_asset`].
+#[derive(serde::Serialize)]
+pub struct UploadAssetBuilder<'octo, 'repos, 'handler> {
+    #[serde(skip)]
+    handler: &'handler ReleasesHandler<'octo, 'repos>,
+    #[serde(skip)]
+    release_id: u64,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    #[serde(skip)]
+    content_type: String,
+    #[serde(skip)]
+    content_length: Option<u64>,
+    #[serde(skip)]
+    data: crate::OctoBody,
+}
+
+impl<'octo, 'repos, 'handler> UploadAssetBuilder<'octo, 'repos, 'handler> {
+    pub(crate) fn new(
+        handler: &'handler ReleasesHandler<'octo, 'repos>,
+        release_id: u64,
+        name: String,
+        content_type: String,
+        data: crate::OctoBody,
+    ) -> Self {
+        Self {
+            handler,
+            release_id,
+            name,
+            label: None,
+            content_type,
+            content_length: None,
+            data,
+        }
+    }
+
+    /// A short description of the asset, shown in place of its name.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// The size of the asset in bytes, sent as the `Content-Length` header of
+    /// a streamed upload. Buffered uploads always know their size.
+    pub fn content_length(mut self, content_length: u64) -> Self {
+        self.content_length = Some(content_length);
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(mut self) -> crate::Result<crate::models::repos::Asset> {
+        let route = format!(
+            "/repos/{owner}/{repo}/releases/{release_id}/assets",
+            owner = self.handler.parent.owner,
+            repo = self.handler.parent.repo,
+            release_id = self.release_id,
+        );
+        let data = std::mem::take(&mut self.data);
+        self.handler
+            .parent
+            .crab
+            .upload_body(
+                route,
+                Some(&self),
+                &self.content_type,
+                self.content_length,
+                data,
+            )
+            .await
+    }
+}
+
 /// A builder pattern struct for updating releases.
 ///
 /// created by [`ReleasesHandler::update`].
@@ -0,0 +1,275 @@
+use super::*;
+use crate::models::repos::{code_scanning::CodeScanningAlert, dependabot::DependabotAlert};
+
+/// A builder pattern struct for listing the Dependabot alerts of a
+/// repository.
+///
+/// Created by [`RepoHandler::list_dependabot_alerts`].
+#[derive(serde::Serialize)]
+pub struct ListDependabotAlertsBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<params::repos::dependabot::State>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    severity: Option<params::repos::dependabot::Severity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ecosystem: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    package: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    direction: Option<params::Direction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    before: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    after: Option<String>,
+}
+
+impl<'octo, 'r> ListDependabotAlertsBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>) -> Self {
+        Self {
+            handler,
+            state: None,
+            severity: None,
+            ecosystem: None,
+            package: None,
+            direction: None,
+            per_page: None,
+            before: None,
+            after: None,
+        }
+    }
+
+    /// Filter alerts by their state.
+    pub fn state(mut self, state: params::repos::dependabot::State) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Filter alerts by the severity of their advisory.
+    pub fn severity(mut self, severity: params::repos::dependabot::Severity) -> Self {
+        self.severity = Some(severity);
+        self
+    }
+
+    /// Filter alerts by package ecosystem, e.g. `rust` or `npm`.
+    pub fn ecosystem(mut self, ecosystem: impl Into<String>) -> Self {
+        self.ecosystem = Some(ecosystem.into());
+        self
+    }
+
+    /// Filter alerts by package name.
+    pub fn package(mut self, package: impl Into<String>) -> Self {
+        self.package = Some(package.into());
+        self
+    }
+
+    /// The direction of the sort by creation date. Default: `desc`.
+    pub fn direction(mut self, direction: params::Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Only return alerts before this cursor. Dependabot alerts are paged
+    /// with cursors rather than page numbers; the links of the returned
+    /// [`Page`](crate::Page) already carry them.
+    pub fn before(mut self, before: impl Into<String>) -> Self {
+        self.before = Some(before.into());
+        self
+    }
+
+    /// Only return alerts after this cursor.
+    pub fn after(mut self, after: impl Into<String>) -> Self {
+        self.after = Some(after.into());
+        self
+    }
+
+    /// Sends the actual request. Fails with
+    /// [`Error::FeatureNotEnabled`](crate::Error::FeatureNotEnabled) if
+    /// Dependabot alerts are disabled for the repository.
+    pub async fn send(self) -> Result<crate::Page<DependabotAlert>> {
+        let route = format!(
+            "/repos/{owner}/{repo}/dependabot/alerts",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+        );
+        map_feature_not_enabled(self.handler.crab.get(route, Some(&self)).await)
+    }
+}
+
+/// A builder pattern struct for listing the code scanning alerts of a
+/// repository.
+///
+/// Created by [`RepoHandler::list_code_scanning_alerts`].
+#[derive(serde::Serialize)]
+pub struct ListCodeScanningAlertsBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tool_name: Option<String>,
+    #[serde(rename = "ref", skip_serializing_if = "Option::is_none")]
+    r#ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<params::repos::code_scanning::State>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    severity: Option<params::repos::code_scanning::Severity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    direction: Option<params::Direction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListCodeScanningAlertsBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>) -> Self {
+        Self {
+            handler,
+            tool_name: None,
+            r#ref: None,
+            state: None,
+            severity: None,
+            direction: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Filter alerts by the name of the tool that raised them, e.g. `CodeQL`.
+    pub fn tool_name(mut self, tool_name: impl Into<String>) -> Self {
+        self.tool_name = Some(tool_name.into());
+        self
+    }
+
+    /// Only return alerts found on this ref, e.g. `refs/heads/main`.
+    /// Default: the default branch.
+    pub fn r#ref(mut self, r#ref: impl Into<String>) -> Self {
+        self.r#ref = Some(r#ref.into());
+        self
+    }
+
+    /// Filter alerts by their state.
+    pub fn state(mut self, state: params::repos::code_scanning::State) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Filter alerts by their severity.
+    pub fn severity(mut self, severity: params::repos::code_scanning::Severity) -> Self {
+        self.severity = Some(severity);
+        self
+    }
+
+    /// The direction of the sort by creation date. Default: `desc`.
+    pub fn direction(mut self, direction: params::Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request. Fails with
+    /// [`Error::FeatureNotEnabled`](crate::Error::FeatureNotEnabled) if code
+    /// scanning is not enabled for the repository.
+    pub async fn send(self) -> Result<crate::Page<CodeScanningAlert>> {
+        let route = format!(
+            "/repos/{owner}/{repo}/code-scanning/alerts",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+        );
+        map_feature_not_enabled(self.handler.crab.get(route, Some(&self)).await)
+    }
+}
+
+/// GitHub answers with `403 Forbidden` both when the token lacks permission
+/// and when the feature is turned off for the repository, so only the
+/// message tells them apart.
+fn map_feature_not_enabled<T>(result: Result<T>) -> Result<T> {
+    match result {
+        Err(crate::Error::GitHub { source, backtrace })
+            if source.status_code == http::StatusCode::FORBIDDEN
+                && is_feature_not_enabled(&source.message) =>
+        {
+            Err(crate::Error::FeatureNotEnabled { source, backtrace })
+        }
+        result => result,
+    }
+}
+
+fn is_feature_not_enabled(message: &str) -> bool {
+    let message = message.to_lowercase();
+    ["disabled", "not enabled", "must be enabled"]
+        .iter()
+        .any(|phrase| message.contains(phrase))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::params;
+
+    #[tokio::test]
+    async fn list_dependabot_alerts_serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.repos("rust-lang", "rust");
+        let list = handler
+            .list_dependabot_alerts()
+            .state(params::repos::dependabot::State::AutoDismissed)
+            .severity(params::repos::dependabot::Severity::Critical)
+            .ecosystem("rust")
+            .per_page(100)
+            .after("Y3Vyc29y");
+
+        assert_eq!(
+            serde_json::to_value(list).unwrap(),
+            serde_json::json!({
+                "state": "auto_dismissed",
+                "severity": "critical",
+                "ecosystem": "rust",
+                "per_page": 100,
+                "after": "Y3Vyc29y",
+            })
+        )
+    }
+
+    #[tokio::test]
+    async fn list_code_scanning_alerts_serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.repos("rust-lang", "rust");
+        let list = handler
+            .list_code_scanning_alerts()
+            .tool_name("CodeQL")
+            .r#ref("refs/heads/main")
+            .state(params::repos::code_scanning::State::Open)
+            .severity(params::repos::code_scanning::Severity::Warning)
+            .page(2u32);
+
+        assert_eq!(
+            serde_json::to_value(list).unwrap(),
+            serde_json::json!({
+                "tool_name": "CodeQL",
+                "ref": "refs/heads/main",
+                "state": "open",
+                "severity": "warning",
+                "page": 2,
+            })
+        )
+    }
+}
@@ -41,6 +41,9 @@ impl<'octo, 'r> CreateStatusBuilder<'octo, 'r> {
     }
 
     /// A short description of the status.
+    ///
+    /// GitHub rejects descriptions longer than 140 characters; they are sent
+    /// as given rather than truncated.
     pub fn description(mut self, description: String) -> Self {
         self.description = Some(description);
         self
@@ -117,3 +120,32 @@ impl<'octo, 'r> ListStatusesBuilder<'octo, 'r> {
         self.handler.crab.get(route, Some(&self)).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::models::StatusState;
+
+    #[tokio::test]
+    async fn serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.repos("rust-lang", "rust");
+        let status = handler
+            .create_status(
+                "6dcb09b5b57875f334f61aebed695e2e4193db5e".to_string(),
+                StatusState::Pending,
+            )
+            .context("continuous-integration/jenkins".to_string())
+            .description("The build is running".to_string())
+            .target("https://ci.example.com/1000/output".to_string());
+
+        assert_eq!(
+            serde_json::to_value(status).unwrap(),
+            serde_json::json!({
+                "state": "pending",
+                "context": "continuous-integration/jenkins",
+                "description": "The build is running",
+                "target_url": "https://ci.example.com/1000/output",
+            })
+        )
+    }
+}
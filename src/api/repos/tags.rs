@@ -1,5 +1,9 @@
 use super::*;
 
+/// A builder pattern struct for listing the tags of a repository, in the
+/// order they were created rather than by version.
+///
+/// Created by [`RepoHandler::list_tags`].
 #[derive(serde::Serialize)]
 pub struct ListTagsBuilder<'octo, 'r> {
     #[serde(skip)]
@@ -0,0 +1,110 @@
+use super::*;
+use crate::models::repos::traffic::{Clones, PopularPath, Referrer, Views};
+use crate::params::repos::traffic::Per;
+
+/// Handler for GitHub's repository traffic API, which covers the last 14
+/// days.
+///
+/// Traffic is only visible to users with push access to the repository,
+/// anyone else gets an [`Error::GitHub`](crate::Error::GitHub) whose status
+/// code is `403 Forbidden`.
+///
+/// Created with [`RepoHandler::traffic`].
+pub struct TrafficHandler<'octo, 'r> {
+    parent: &'r RepoHandler<'octo>,
+}
+
+#[derive(serde::Serialize)]
+struct PerParams {
+    per: Per,
+}
+
+impl<'octo, 'r> TrafficHandler<'octo, 'r> {
+    pub(crate) fn new(parent: &'r RepoHandler<'octo>) -> Self {
+        Self { parent }
+    }
+
+    /// Gets the views of the repository, broken down `per` day or week.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::repos::traffic::Per;
+    ///
+    /// let views = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .traffic()
+    ///     .views(Per::Week)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn views(&self, per: Per) -> Result<Views> {
+        self.parent
+            .crab
+            .get(self.route("views"), Some(&PerParams { per }))
+            .await
+    }
+
+    /// Gets the clones of the repository, broken down `per` day or week.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::repos::traffic::Per;
+    ///
+    /// let clones = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .traffic()
+    ///     .clones(Per::Day)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn clones(&self, per: Per) -> Result<Clones> {
+        self.parent
+            .crab
+            .get(self.route("clones"), Some(&PerParams { per }))
+            .await
+    }
+
+    /// Gets the top 10 sites that referred people to the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let referrers = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .traffic()
+    ///     .top_referrers()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn top_referrers(&self) -> Result<Vec<Referrer>> {
+        self.parent
+            .crab
+            .get(self.route("popular/referrers"), None::<&()>)
+            .await
+    }
+
+    /// Gets the top 10 most viewed pages of the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let paths = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .traffic()
+    ///     .top_paths()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn top_paths(&self) -> Result<Vec<PopularPath>> {
+        self.parent
+            .crab
+            .get(self.route("popular/paths"), None::<&()>)
+            .await
+    }
+
+    fn route(&self, path: &str) -> String {
+        format!(
+            "/repos/{owner}/{repo}/traffic/{path}",
+            owner = self.parent.owner,
+            repo = self.parent.repo,
+        )
+    }
+}
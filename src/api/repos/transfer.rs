@@ -0,0 +1,74 @@
+use super::*;
+
+/// A builder pattern struct for transferring a repository to another owner.
+///
+/// Created by [`RepoHandler::transfer`].
+#[derive(serde::Serialize)]
+pub struct TransferRepositoryBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    new_owner: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    new_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    team_ids: Option<Vec<models::TeamId>>,
+}
+
+impl<'octo, 'r> TransferRepositoryBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>, new_owner: String) -> Self {
+        Self {
+            handler,
+            new_owner,
+            new_name: None,
+            team_ids: None,
+        }
+    }
+
+    /// Renames the repository as part of the transfer.
+    pub fn new_name(mut self, new_name: impl Into<String>) -> Self {
+        self.new_name = Some(new_name.into());
+        self
+    }
+
+    /// The teams of the new owner, an organization, to give access to the
+    /// repository.
+    pub fn team_ids(mut self, team_ids: impl Into<Vec<models::TeamId>>) -> Self {
+        self.team_ids = Some(team_ids.into());
+        self
+    }
+
+    /// Sends the actual request, returning the repository as it is while the
+    /// transfer is still in progress.
+    pub async fn send(self) -> Result<models::Repository> {
+        let route = format!(
+            "/repos/{owner}/{repo}/transfer",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+        );
+        self.handler.crab.post(route, Some(&self)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::TeamId;
+
+    #[tokio::test]
+    async fn serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.repos("rust-lang", "rust");
+        let transfer = handler
+            .transfer("rust-lang-nursery")
+            .new_name("rustc")
+            .team_ids(vec![TeamId(1), TeamId(2)]);
+
+        assert_eq!(
+            serde_json::to_value(transfer).unwrap(),
+            serde_json::json!({
+                "new_owner": "rust-lang-nursery",
+                "new_name": "rustc",
+                "team_ids": [1, 2],
+            })
+        )
+    }
+}
@@ -0,0 +1,206 @@
+use super::*;
+
+/// A builder pattern struct for fetching a Git tree.
+///
+/// Created by [`RepoHandler::get_tree`].
+#[derive(serde::Serialize)]
+pub struct GetTreeBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    #[serde(skip)]
+    sha: String,
+    // GitHub recurses for any value of `recursive`, so it is left out unless set.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    recursive: bool,
+}
+
+impl<'octo, 'r> GetTreeBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>, sha: String) -> Self {
+        Self {
+            handler,
+            sha,
+            recursive: false,
+        }
+    }
+
+    /// Also return the entries of all subtrees. Check [`GitTree::truncated`]
+    /// as large trees are cut off.
+    ///
+    /// [`GitTree::truncated`]: crate::models::repos::GitTree::truncated
+    pub fn recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<models::repos::GitTree> {
+        let route = format!(
+            "/repos/{owner}/{repo}/git/trees/{sha}",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+            sha = self.sha,
+        );
+        self.handler.crab.get(route, Some(&self)).await
+    }
+}
+
+/// An entry of a tree to create with [`RepoHandler::create_tree`].
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct CreateTreeEntry {
+    path: String,
+    mode: String,
+    #[serde(rename = "type")]
+    r#type: models::repos::TreeEntryType,
+    // `Some(None)` is sent as `null`, which deletes the entry from the base tree.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sha: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
+}
+
+impl CreateTreeEntry {
+    /// An entry at `path` pointing to the existing object `sha`. `mode` is
+    /// `100644` for a file, `100755` for an executable, `040000` for a
+    /// subdirectory, `160000` for a submodule or `120000` for a symlink.
+    pub fn object(
+        path: impl Into<String>,
+        mode: impl Into<String>,
+        r#type: models::repos::TreeEntryType,
+        sha: impl Into<String>,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            mode: mode.into(),
+            r#type,
+            sha: Some(Some(sha.into())),
+            content: None,
+        }
+    }
+
+    /// A regular file at `path` whose blob GitHub creates from `content`.
+    pub fn file(path: impl Into<String>, content: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            mode: "100644".to_string(),
+            r#type: models::repos::TreeEntryType::Blob,
+            sha: None,
+            content: Some(content.into()),
+        }
+    }
+
+    /// Removes the file at `path` from the base tree.
+    pub fn delete(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            mode: "100644".to_string(),
+            r#type: models::repos::TreeEntryType::Blob,
+            sha: Some(None),
+            content: None,
+        }
+    }
+}
+
+/// A builder pattern struct for creating a Git tree.
+///
+/// Created by [`RepoHandler::create_tree`].
+#[derive(serde::Serialize)]
+pub struct CreateTreeBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    tree: Vec<CreateTreeEntry>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    base_tree: Option<String>,
+}
+
+impl<'octo, 'r> CreateTreeBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>, tree: Vec<CreateTreeEntry>) -> Self {
+        Self {
+            handler,
+            tree,
+            base_tree: None,
+        }
+    }
+
+    /// The SHA of the tree to apply the entries to. Without it, the new tree
+    /// only contains the given entries.
+    pub fn base_tree(mut self, base_tree: impl Into<String>) -> Self {
+        self.base_tree = Some(base_tree.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<models::repos::GitTree> {
+        let route = format!(
+            "/repos/{owner}/{repo}/git/trees",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+        );
+        self.handler.crab.post(route, Some(&self)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CreateTreeEntry;
+    use crate::models::repos::TreeEntryType;
+
+    #[tokio::test]
+    async fn get_serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.repos("rust-lang", "rust");
+
+        assert_eq!(
+            serde_json::to_value(handler.get_tree("main")).unwrap(),
+            serde_json::json!({})
+        );
+        assert_eq!(
+            serde_json::to_value(handler.get_tree("main").recursive(true)).unwrap(),
+            serde_json::json!({ "recursive": true })
+        );
+    }
+
+    #[tokio::test]
+    async fn create_serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.repos("rust-lang", "rust");
+        let create = handler
+            .create_tree(vec![
+                CreateTreeEntry::object(
+                    "src",
+                    "040000",
+                    TreeEntryType::Tree,
+                    "f484d249c660418515fb01c2b9662073663c242e",
+                ),
+                CreateTreeEntry::file("README.md", "# Hello"),
+                CreateTreeEntry::delete("CHANGELOG.md"),
+            ])
+            .base_tree("9fb037999f264ba9a7fc6274d15fa3ae2ab98312");
+
+        assert_eq!(
+            serde_json::to_value(create).unwrap(),
+            serde_json::json!({
+                "tree": [
+                    {
+                        "path": "src",
+                        "mode": "040000",
+                        "type": "tree",
+                        "sha": "f484d249c660418515fb01c2b9662073663c242e",
+                    },
+                    {
+                        "path": "README.md",
+                        "mode": "100644",
+                        "type": "blob",
+                        "content": "# Hello",
+                    },
+                    {
+                        "path": "CHANGELOG.md",
+                        "mode": "100644",
+                        "type": "blob",
+                        "sha": null,
+                    },
+                ],
+                "base_tree": "9fb037999f264ba9a7fc6274d15fa3ae2ab98312",
+            })
+        )
+    }
+}
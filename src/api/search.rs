@@ -14,14 +14,17 @@ impl<'octo> SearchHandler<'octo> {
         Self { crab }
     }
 
-    /// Searches for all the repositories matching the search query.
+    /// Searches for all the repositories matching the search query. The
+    /// page's `total_count` is set from the search response.
     /// ```no_run
     ///# async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params;
+    ///
     /// let page = octocrab::instance()
     ///     .search()
     ///     .repositories("tetris language:rust")
-    ///     .sort("stars")
-    ///     .order("desc")
+    ///     .sort(params::search::RepositorySort::Stars)
+    ///     .order(params::Direction::Descending)
     ///     .send()
     ///     .await?;
     ///# Ok(())
@@ -54,14 +57,17 @@ impl<'octo> SearchHandler<'octo> {
         QueryHandler::new(self.crab, "commits", query.as_ref())
     }
 
-    /// Searches for all users matching the search query.
+    /// Searches for all users matching the search query. The page's
+    /// `total_count` is set from the search response.
     /// ```no_run
     ///# async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params;
+    ///
     /// let page = octocrab::instance()
     ///     .search()
     ///     .users("bors type:user")
-    ///     .sort("followers")
-    ///     .order("desc")
+    ///     .sort(params::search::UserSort::Followers)
+    ///     .order(params::Direction::Descending)
     ///     .send()
     ///     .await?;
     ///# Ok(())
@@ -74,14 +80,19 @@ impl<'octo> SearchHandler<'octo> {
         QueryHandler::new(self.crab, "users", query.as_ref())
     }
 
-    /// Searches for all the issues matching the search query.
+    /// Searches for all the issues and pull requests matching the search query.
+    /// The page's `total_count` and `incomplete_results` are set from the
+    /// search response.
     /// ```no_run
     ///# async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params;
+    ///
     /// let page = octocrab::instance()
     ///     .search()
-    ///     .issues_and_pull_requests("GitHub Octocrab in:readme user:ferris")
+    ///     .issues_and_pull_requests("is:open is:pr author:ferris")
     ///     .sort("comments")
-    ///     .order("asc")
+    ///     .order(params::Direction::Ascending)
+    ///     .per_page(100)
     ///     .send()
     ///     .await?;
     ///# Ok(())
@@ -102,6 +113,7 @@ impl<'octo> SearchHandler<'octo> {
     ///     .code("println! language:rust repo:rust-lang/rust")
     ///     .sort("indexed")
     ///     .order("asc")
+    ///     .text_match(true)
     ///     .send()
     ///     .await?;
     ///# Ok(())
@@ -126,12 +138,16 @@ pub struct QueryHandler<'octo, 'query, T> {
     route: &'static str,
     #[serde(rename = "q")]
     query: &'query str,
+    #[serde(skip_serializing_if = "Option::is_none")]
     per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     page: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     sort: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     order: Option<String>,
+    #[serde(skip)]
+    text_match: bool,
 }
 
 impl<'octo, 'query, T> QueryHandler<'octo, 'query, T> {
@@ -145,6 +161,7 @@ impl<'octo, 'query, T> QueryHandler<'octo, 'query, T> {
             return_type: std::marker::PhantomData,
             route,
             sort: None,
+            text_match: false,
         }
     }
 
@@ -155,13 +172,21 @@ impl<'octo, 'query, T> QueryHandler<'octo, 'query, T> {
         self
     }
 
-    /// Sets the `order` parameter for the query. The exact parameters for this
-    /// method will vary based on what is being searched.
+    /// Sets the `order` parameter for the query, either `"asc"` or `"desc"`
+    /// (or a [`crate::params::Direction`]). Only used when `sort` is set.
     pub fn order<S: Into<String>>(mut self, order: impl Into<Option<S>>) -> Self {
         self.order = order.into().map(S::into);
         self
     }
 
+    /// Requests text match metadata, which highlights the search terms
+    /// within each result. For code search this populates
+    /// [`models::Code::text_matches`].
+    pub fn text_match(mut self, text_match: bool) -> Self {
+        self.text_match = text_match;
+        self
+    }
+
     /// Results per page (max 100).
     pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
         self.per_page = Some(per_page.into());
@@ -178,8 +203,17 @@ impl<'octo, 'query, T> QueryHandler<'octo, 'query, T> {
 impl<'octo, 'query, T: serde::de::DeserializeOwned> QueryHandler<'octo, 'query, T> {
     /// Send the actual request.
     pub async fn send(self) -> crate::Result<crate::Page<T>> {
+        let headers = self.text_match.then(|| {
+            let mut headers = http::header::HeaderMap::new();
+            headers.insert(
+                http::header::ACCEPT,
+                http::HeaderValue::from_static("application/vnd.github.text-match+json"),
+            );
+            headers
+        });
+
         self.crab
-            .get(&format!("/search/{}", self.route), Some(&self))
+            .get_with_headers(&format!("/search/{}", self.route), Some(&self), headers)
             .await
     }
 }
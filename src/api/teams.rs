@@ -17,7 +17,7 @@ use http::Uri;
 use snafu::ResultExt;
 
 use crate::error::HttpSnafu;
-use crate::{models, Octocrab, Result};
+use crate::{models, params, Octocrab, Result};
 
 /// Handler for GitHub's teams API.
 ///
@@ -174,6 +174,63 @@ impl<'octo> TeamHandler<'octo> {
         ListTeamMembersBuilder::new(self, team_slug.into())
     }
 
+    /// Adds a user to a team, or updates their role in it. If the user isn't
+    /// a member of the organization yet, they are invited and the membership
+    /// stays `pending` until they accept.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params;
+    ///
+    /// let membership = octocrab::instance()
+    ///     .teams("owner")
+    ///     .add_or_update_membership("team", "ferris", params::teams::Role::Maintainer)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_or_update_membership(
+        &self,
+        team_slug: impl Into<String>,
+        username: impl AsRef<str>,
+        role: params::teams::Role,
+    ) -> Result<models::teams::TeamMembership> {
+        let route = format!(
+            "/orgs/{org}/teams/{team}/memberships/{username}",
+            org = self.owner,
+            team = team_slug.into(),
+            username = username.as_ref(),
+        );
+        self.crab
+            .put(route, Some(&serde_json::json!({ "role": role })))
+            .await
+    }
+
+    /// Grants a team `permission` on a repository, adding the repository to
+    /// the team if needed. Shorthand for
+    /// [`TeamRepoHandler::add_or_update`].
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params;
+    ///
+    /// octocrab::instance()
+    ///     .teams("owner")
+    ///     .add_or_update_repo_permissions("team", "owner", "repo", params::teams::Permission::Push)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_or_update_repo_permissions(
+        &self,
+        team_slug: impl Into<String>,
+        repo_owner: impl Into<String>,
+        repo_name: impl Into<String>,
+        permission: params::teams::Permission,
+    ) -> Result<()> {
+        self.repos(team_slug)
+            .add_or_update(repo_owner, repo_name, permission)
+            .await
+    }
+
     /// List the pending invitations for a team in an organization.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
@@ -132,12 +132,8 @@ impl<'octo> TeamRepoHandler<'octo> {
             owner = repo_owner.into(),
             repo = repo_name.into(),
         );
-        self.crab
-            ._delete(
-                self.crab.parameterized_uri(route, None::<&()>)?,
-                None::<&()>,
-            )
-            .await?;
-        Ok(())
+        crate::map_github_error(self.crab._delete(route, None::<&()>).await?)
+            .await
+            .map(drop)
     }
 }
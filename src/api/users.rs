@@ -35,6 +35,50 @@ impl<'octo> UserHandler<'octo> {
         ListUserFollowingBuilder::new(self)
     }
 
+    /// Follows this user as the authenticated user.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance().users("ferris").follow().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn follow(&self) -> crate::Result<()> {
+        let route = self.following_route();
+        crate::map_github_error(self.crab._put(route, None::<&()>).await?)
+            .await
+            .map(drop)
+    }
+
+    /// Unfollows this user as the authenticated user.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance().users("ferris").unfollow().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn unfollow(&self) -> crate::Result<()> {
+        let route = self.following_route();
+        crate::map_github_error(self.crab._delete(route, None::<&()>).await?)
+            .await
+            .map(drop)
+    }
+
+    /// Checks whether the authenticated user follows this user.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let is_following = octocrab::instance().users("ferris").is_following().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn is_following(&self) -> crate::Result<bool> {
+        let route = self.following_route();
+        crate::exists_via_status(self.crab._get(route).await?).await
+    }
+
+    fn following_route(&self) -> String {
+        format!("/user/following/{}", self.user)
+    }
+
     pub fn repos(&self) -> ListUserReposBuilder<'_, '_> {
         ListUserReposBuilder::new(self)
     }
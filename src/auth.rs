@@ -7,7 +7,7 @@ use jsonwebtoken::{Algorithm, EncodingKey, Header};
 use secrecy::{ExposeSecret, SecretString};
 use serde::{Deserialize, Serialize};
 use std::fmt;
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 
 use snafu::*;
 
@@ -129,7 +129,107 @@ impl From<OAuthWire> for OAuth {
     }
 }
 
+/// Returns the url to redirect users to in order to request access to their
+/// account for the OAuth app with `client_id`, the first step of GitHub's web
+/// application flow. `state` is passed back to `redirect_uri` and should be an
+/// unguessable random string. Without a `redirect_uri` the callback url from
+/// the app's settings is used.
+///
+/// See https://docs.github.com/en/apps/oauth-apps/building-oauth-apps/authorizing-oauth-apps#web-application-flow for details.
+///
+/// ```
+/// # fn run() -> octocrab::Result<()> {
+/// let client_id = secrecy::SecretString::from("Iv1.8a61f9b3a7aba766".to_string());
+/// let url = octocrab::auth::authorize_url(&client_id, ["repo", "read:org"], "a1b2c3", None)?;
+/// assert_eq!(
+///     url,
+///     "https://github.com/login/oauth/authorize?client_id=Iv1.8a61f9b3a7aba766&scope=repo+read%3Aorg&state=a1b2c3"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn authorize_url<I, S>(
+    client_id: &SecretString,
+    scope: I,
+    state: &str,
+    redirect_uri: Option<&str>,
+) -> Result<String>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let scope = scope
+        .into_iter()
+        .map(|s| s.as_ref().to_string())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let query = serde_urlencoded::to_string(AuthorizeParams {
+        client_id: client_id.expose_secret(),
+        redirect_uri,
+        scope: &scope,
+        state,
+    })
+    .context(crate::error::SerdeUrlEncodedSnafu)?;
+
+    Ok(format!("https://github.com/login/oauth/authorize?{query}"))
+}
+
 impl crate::Octocrab {
+    /// Exchange the `code` passed to the redirect url of the web application
+    /// flow for an access token, see [`authorize_url`].
+    ///
+    /// Like [`Octocrab::authenticate_as_device`](crate::Octocrab::authenticate_as_device),
+    /// the `Octocrab` that calls this method *must* be constructed with
+    /// `base_uri: "https://github.com"`, as tokens are not issued by the API
+    /// host. If GitHub rejects the code, e.g. because it expired, an
+    /// [`Error::OAuth`](crate::Error::OAuth) is returned.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let client_id = secrecy::SecretString::from(String::new());
+    /// # let client_secret = secrecy::SecretString::from(String::new());
+    /// let crab = octocrab::Octocrab::builder()
+    ///     .base_uri("https://github.com")?
+    ///     .build()?;
+    /// let oauth = crab
+    ///     .exchange_oauth_code(&client_id, &client_secret, "0123456789abcdef", None)
+    ///     .await?;
+    /// let user_crab = octocrab::Octocrab::builder()
+    ///     .oauth(oauth)
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn exchange_oauth_code(
+        &self,
+        client_id: &SecretString,
+        client_secret: &SecretString,
+        code: &str,
+        redirect_uri: Option<&str>,
+    ) -> Result<OAuth> {
+        let mut headers = http::HeaderMap::new();
+        headers.insert(
+            http::header::ACCEPT,
+            http::HeaderValue::from_static("application/json"),
+        );
+        let response: CodeExchangeResponse = self
+            .post_with_headers(
+                "/login/oauth/access_token",
+                Some(&ExchangeCode {
+                    client_id: client_id.expose_secret(),
+                    client_secret: client_secret.expose_secret(),
+                    code,
+                    redirect_uri,
+                }),
+                Some(headers),
+            )
+            .await?;
+        match response {
+            CodeExchangeResponse::Ok(oauth) => Ok(oauth),
+            CodeExchangeResponse::Err(error) => Err(error).context(crate::error::OAuthSnafu),
+        }
+    }
+
     /// Authenticate with Github's device flow. This starts the process to obtain a new `OAuth`.
     ///
     /// See https://docs.github.com/en/developers/apps/building-oauth-apps/authorizing-oauth-apps#device-flow for details.
@@ -204,7 +304,33 @@ pub struct DeviceCodes {
 impl DeviceCodes {
     /// Poll Github to see if authentication codes are available.
     ///
+    /// Returns [`Continue`] while the user has not authorized the device yet,
+    /// in which case the caller should wait for the interval returned by
+    /// [`Continue::next_interval`] before polling again. Once the codes have
+    /// expired or the user denied access, an [`Error::OAuth`](crate::Error::OAuth)
+    /// is returned instead.
+    ///
     /// See `https://docs.github.com/en/developers/apps/building-oauth-apps/authorizing-oauth-apps#response-parameters` for details.
+    ///
+    /// ```no_run
+    /// # async fn run(crab: octocrab::Octocrab, client_id: secrecy::SecretString) -> octocrab::Result<()> {
+    /// use either::Either;
+    /// use std::time::Duration;
+    ///
+    /// let codes = crab.authenticate_as_device(&client_id, ["repo"]).await?;
+    /// println!("Go to {} and enter code {}", codes.verification_uri, codes.user_code);
+    ///
+    /// let mut interval = Duration::from_secs(codes.interval);
+    /// let oauth = loop {
+    ///     tokio::time::sleep(interval).await;
+    ///     match codes.poll_once(&crab, &client_id).await? {
+    ///         Either::Left(oauth) => break oauth,
+    ///         Either::Right(cont) => interval = cont.next_interval(interval),
+    ///     }
+    /// };
+    /// # Ok(())
+    /// # }
+    /// ```
     pub async fn poll_once(
         &self,
         crab: &crate::Octocrab,
@@ -220,10 +346,11 @@ impl DeviceCodes {
                 }),
             )
             .await?;
-        Ok(match poll {
-            TokenResponse::Ok(k) => Either::Left(k),
-            TokenResponse::Continue { error } => Either::Right(error),
-        })
+        match poll {
+            TokenResponse::Ok(k) => Ok(Either::Left(k)),
+            TokenResponse::Continue { error } => Ok(Either::Right(error)),
+            TokenResponse::Err(error) => Err(error).context(crate::error::OAuthSnafu),
+        }
     }
 }
 
@@ -234,6 +361,33 @@ struct DeviceFlow<'a> {
     scope: &'a str,
 }
 
+/// See https://docs.github.com/en/apps/oauth-apps/building-oauth-apps/authorizing-oauth-apps#1-request-a-users-github-identity
+#[derive(Serialize)]
+struct AuthorizeParams<'a> {
+    client_id: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    redirect_uri: Option<&'a str>,
+    scope: &'a str,
+    state: &'a str,
+}
+
+/// See https://docs.github.com/en/apps/oauth-apps/building-oauth-apps/authorizing-oauth-apps#2-users-are-redirected-back-to-your-site-by-github
+#[derive(Serialize)]
+struct ExchangeCode<'a> {
+    client_id: &'a str,
+    client_secret: &'a str,
+    code: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    redirect_uri: Option<&'a str>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum CodeExchangeResponse {
+    Ok(OAuth),
+    Err(crate::error::OAuthError),
+}
+
 #[derive(Deserialize)]
 #[serde(untagged)]
 enum TokenResponse {
@@ -241,6 +395,8 @@ enum TokenResponse {
     Ok(OAuth),
     // We got an error that allows us to continue polling.
     Continue { error: Continue },
+    // We got an error that ends the flow, e.g. the codes expired.
+    Err(crate::error::OAuthError),
 }
 
 /// Control flow when polling the device flow authorization.
@@ -261,6 +417,17 @@ pub enum Continue {
     AuthorizationPending,
 }
 
+impl Continue {
+    /// Returns how long to wait before polling again, given the `interval`
+    /// waited before the last poll.
+    pub fn next_interval(self, interval: Duration) -> Duration {
+        match self {
+            Continue::SlowDown => interval + Duration::from_secs(5),
+            Continue::AuthorizationPending => interval,
+        }
+    }
+}
+
 #[derive(Serialize)]
 struct PollForDevice<'a> {
     /// Required. The client ID you received from GitHub for your OAuth App.
@@ -270,3 +437,23 @@ struct PollForDevice<'a> {
     /// Required. The grant type must be urn:ietf:params:oauth:grant-type:device_code.
     grant_type: &'static str,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Continue;
+    use std::time::Duration;
+
+    #[test]
+    fn slow_down_adds_five_seconds_to_interval() {
+        let interval = Duration::from_secs(5);
+
+        assert_eq!(
+            Continue::SlowDown.next_interval(interval),
+            Duration::from_secs(10)
+        );
+        assert_eq!(
+            Continue::AuthorizationPending.next_interval(interval),
+            interval
+        );
+    }
+}
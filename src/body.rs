@@ -0,0 +1,148 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use futures::{Stream, TryStreamExt};
+use http_body::{Body, Frame, SizeHint};
+use http_body_util::{combinators::UnsyncBoxBody, Full, StreamBody};
+use tower::BoxError;
+
+/// The body of requests sent through the [`Service`](tower::Service) stack.
+///
+/// Most bodies are buffered, so they can be cloned and resent by middleware
+/// such as retries. Bodies created with [`OctoBody::wrap_stream`] are sent as
+/// they are produced instead, which keeps large uploads out of memory at the
+/// cost of never being retried.
+///
+/// How the body is represented is private, so that more kinds of bodies can
+/// be supported without changing the type again.
+#[derive(Debug, Default)]
+pub struct OctoBody(Kind);
+
+#[derive(Debug)]
+enum Kind {
+    Buffered(Full<Bytes>),
+    Streaming(UnsyncBoxBody<Bytes, BoxError>),
+}
+
+impl Default for Kind {
+    fn default() -> Self {
+        Kind::Buffered(Full::default())
+    }
+}
+
+impl OctoBody {
+    /// Creates a body that sends each chunk of `stream` as it is produced.
+    ///
+    /// Unless the request sets a `Content-Length` header, the body is sent
+    /// with chunked transfer encoding.
+    pub fn wrap_stream<S, E>(stream: S) -> Self
+    where
+        S: Stream<Item = Result<Bytes, E>> + Send + 'static,
+        E: Into<BoxError> + 'static,
+    {
+        let body = StreamBody::new(stream.map_ok(Frame::data).map_err(Into::into));
+        Self(Kind::Streaming(UnsyncBoxBody::new(body)))
+    }
+
+    /// Returns a copy of the body, or `None` if it is streamed and so can
+    /// only be sent once.
+    pub fn try_clone(&self) -> Option<Self> {
+        match &self.0 {
+            Kind::Buffered(body) => Some(Self(Kind::Buffered(body.clone()))),
+            Kind::Streaming(_) => None,
+        }
+    }
+}
+
+impl Body for OctoBody {
+    type Data = Bytes;
+    type Error = BoxError;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        match &mut self.get_mut().0 {
+            Kind::Buffered(body) => Pin::new(body).poll_frame(cx).map_err(|e| match e {}),
+            Kind::Streaming(body) => Pin::new(body).poll_frame(cx),
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        match &self.0 {
+            Kind::Buffered(body) => body.is_end_stream(),
+            Kind::Streaming(body) => body.is_end_stream(),
+        }
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        match &self.0 {
+            Kind::Buffered(body) => body.size_hint(),
+            Kind::Streaming(body) => body.size_hint(),
+        }
+    }
+}
+
+impl From<Full<Bytes>> for OctoBody {
+    fn from(body: Full<Bytes>) -> Self {
+        Self(Kind::Buffered(body))
+    }
+}
+
+impl From<Bytes> for OctoBody {
+    fn from(bytes: Bytes) -> Self {
+        Self(Kind::Buffered(Full::new(bytes)))
+    }
+}
+
+impl From<Vec<u8>> for OctoBody {
+    fn from(bytes: Vec<u8>) -> Self {
+        Bytes::from(bytes).into()
+    }
+}
+
+impl From<String> for OctoBody {
+    fn from(string: String) -> Self {
+        Bytes::from(string).into()
+    }
+}
+
+impl From<&'static [u8]> for OctoBody {
+    fn from(bytes: &'static [u8]) -> Self {
+        Bytes::from_static(bytes).into()
+    }
+}
+
+impl From<&'static str> for OctoBody {
+    fn from(string: &'static str) -> Self {
+        Bytes::from_static(string.as_bytes()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http_body_util::BodyExt;
+
+    #[tokio::test]
+    async fn buffered_bodies_can_be_cloned() {
+        let body = OctoBody::from("hello");
+        let copy = body.try_clone().unwrap();
+
+        assert_eq!(copy.collect().await.unwrap().to_bytes(), "hello");
+        assert_eq!(body.collect().await.unwrap().to_bytes(), "hello");
+    }
+
+    #[tokio::test]
+    async fn streamed_bodies_are_sent_once() {
+        let chunks = vec![
+            Ok::<_, std::io::Error>(Bytes::from("hel")),
+            Ok(Bytes::from("lo")),
+        ];
+        let body = OctoBody::wrap_stream(futures::stream::iter(chunks));
+
+        assert!(body.try_clone().is_none());
+        assert_eq!(body.collect().await.unwrap().to_bytes(), "hello");
+    }
+}
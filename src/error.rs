@@ -1,6 +1,7 @@
+use bytes::Bytes;
 use http::uri::InvalidUri;
 
-use snafu::{Backtrace, Snafu};
+use snafu::{Backtrace, IntoError, Snafu};
 
 use std::fmt;
 use std::fmt::{Display, Formatter};
@@ -24,7 +25,7 @@ impl std::error::Error for UriParseError {}
 #[snafu(visibility(pub))]
 pub enum Error {
     GitHub {
-        source: GitHubError,
+        source: Box<GitHubError>,
         backtrace: Backtrace,
     },
     UriParse {
@@ -63,6 +64,14 @@ pub enum Error {
         backtrace: Backtrace,
     },
 
+    /// The request, or connecting for it, took longer than the timeout set
+    /// on the [`crate::OctocrabBuilder`].
+    #[snafu(display("Timeout Error: {}\n\nFound at {}", source, backtrace))]
+    Timeout {
+        source: BoxError,
+        backtrace: Backtrace,
+    },
+
     #[snafu(display("Hyper Error: {}\n\nFound at {}", source, backtrace))]
     Hyper {
         source: hyper::Error,
@@ -80,9 +89,63 @@ pub enum Error {
         source: serde_json::Error,
         backtrace: Backtrace,
     },
+    /// A response body didn't match the type it was read into. `body` holds
+    /// the raw body, to debug changes of GitHub's schema.
     #[snafu(display("JSON Error in {}: {}\nFound at {}", source.path(), source.inner(), backtrace))]
     Json {
         source: serde_path_to_error::Error<serde_json::Error>,
+        body: Bytes,
+        backtrace: Backtrace,
+    },
+    #[snafu(display(
+        "GraphQL Error: {}\nFound at {}",
+        errors.iter().map(|error| error.message.as_str()).collect::<Vec<_>>().join(", "),
+        backtrace
+    ))]
+    GraphQL {
+        errors: Vec<GraphQLError>,
+        backtrace: Backtrace,
+    },
+    /// GitHub's secondary rate limits (abuse detection) refused the request.
+    /// Wait for `retry_after` before trying again if it is given, and for at
+    /// least a minute otherwise.
+    #[snafu(display("Secondary rate limit exceeded: {}\nFound at {}", source, backtrace))]
+    SecondaryRateLimit {
+        source: Box<GitHubError>,
+        retry_after: Option<std::time::Duration>,
+        backtrace: Backtrace,
+    },
+    /// GitHub refused the request because the feature it uses, such as
+    /// Dependabot alerts or code scanning, is not enabled for the repository.
+    #[snafu(display("Feature not enabled: {}\nFound at {}", source, backtrace))]
+    FeatureNotEnabled {
+        source: Box<GitHubError>,
+        backtrace: Backtrace,
+    },
+    #[snafu(display(
+        "The request requires authentication, but the client has no credentials\nFound at {}",
+        backtrace
+    ))]
+    MissingAuth { backtrace: Backtrace },
+    /// Only members of an organization may check whether someone else is a
+    /// member; GitHub redirects anyone else to the public membership check.
+    #[snafu(display(
+        "The requester is not a member of the organization\nFound at {}",
+        backtrace
+    ))]
+    NotOrganizationMember { backtrace: Backtrace },
+    #[snafu(display("OAuth Error: {}\nFound at {}", source, backtrace))]
+    OAuth {
+        source: OAuthError,
+        backtrace: Backtrace,
+    },
+    #[snafu(display(
+        "Installation token expiry could not be parsed: {}\nFound at {}",
+        source,
+        backtrace
+    ))]
+    InstallationTokenExpiry {
+        source: chrono::ParseError,
         backtrace: Backtrace,
     },
     #[snafu(display("JWT Error in {}\nFound at {}", source, backtrace))]
@@ -96,6 +159,136 @@ pub enum Error {
     },
 }
 
+/// The kind of an [`Error`], returned by [`Error::kind`] to tell failures
+/// apart without matching every variant.
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum ErrorKind<'a> {
+    /// Sending the request or receiving the response failed, e.g. because
+    /// the connection was refused or reset or timed out.
+    Transport,
+    /// GitHub answered with an error status other than `401 Unauthorized`.
+    Http {
+        status: http::StatusCode,
+        github_error: &'a GitHubError,
+    },
+    /// A response couldn't be decoded. `body` is the raw body, when the
+    /// error came from reading it.
+    Decode {
+        source: &'a (dyn std::error::Error + 'static),
+        body: Option<&'a Bytes>,
+    },
+    /// The client couldn't authenticate, e.g. because it has no credentials,
+    /// GitHub rejected them with `401 Unauthorized` or creating a GitHub App
+    /// token failed.
+    Auth,
+    /// Any other error, such as failing to build a request.
+    Other,
+}
+
+impl Error {
+    /// Returns the kind of this error.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::ErrorKind;
+    ///
+    /// match octocrab::instance().repos("owner", "repo").get().await {
+    ///     Ok(repo) => println!("{}", repo.name),
+    ///     Err(error) => match error.kind() {
+    ///         ErrorKind::Http { status, .. } if status == 404 => println!("not found"),
+    ///         ErrorKind::Decode { body: Some(body), .. } => {
+    ///             println!("unexpected body: {}", String::from_utf8_lossy(body))
+    ///         }
+    ///         _ => return Err(error),
+    ///     },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn kind(&self) -> ErrorKind<'_> {
+        match self {
+            Self::Service { .. } | Self::Timeout { .. } | Self::Hyper { .. } => {
+                ErrorKind::Transport
+            }
+            Self::GitHub { source, .. } if source.status_code == http::StatusCode::UNAUTHORIZED => {
+                ErrorKind::Auth
+            }
+            Self::GitHub { source, .. }
+            | Self::SecondaryRateLimit { source, .. }
+            | Self::FeatureNotEnabled { source, .. } => ErrorKind::Http {
+                status: source.status_code,
+                github_error: source,
+            },
+            Self::Json { source, body, .. } => ErrorKind::Decode {
+                source,
+                body: Some(body),
+            },
+            Self::Serde { source, .. } => ErrorKind::Decode { source, body: None },
+            Self::InvalidUtf8 { source, .. } => ErrorKind::Decode { source, body: None },
+            Self::MissingAuth { .. }
+            | Self::OAuth { .. }
+            | Self::InstallationTokenExpiry { .. }
+            | Self::JWT { .. } => ErrorKind::Auth,
+            Self::UriParse { .. }
+            | Self::Uri { .. }
+            | Self::InvalidHeaderValue { .. }
+            | Self::Http { .. }
+            | Self::Encoder { .. }
+            | Self::SerdeUrlEncoded { .. }
+            | Self::GraphQL { .. }
+            | Self::NotOrganizationMember { .. }
+            | Self::Other { .. } => ErrorKind::Other,
+        }
+    }
+
+    /// Whether sending the request again may succeed, as it may for
+    /// transport errors, server errors, `429 Too Many Requests` and
+    /// secondary rate limits.
+    ///
+    /// This is broader than what
+    /// [`RetryConfig`](crate::service::middleware::retry::RetryConfig)
+    /// retries on its own: `Simple` never retries secondary rate limits sent
+    /// as `403 Forbidden`, and `Backoff` only does when they come with a
+    /// `Retry-After` header.
+    pub fn is_retriable(&self) -> bool {
+        match self.kind() {
+            ErrorKind::Transport => true,
+            ErrorKind::Http { status, .. } => {
+                status.is_server_error()
+                    || status == http::StatusCode::TOO_MANY_REQUESTS
+                    || matches!(self, Self::SecondaryRateLimit { .. })
+            }
+            _ => false,
+        }
+    }
+
+    /// Wraps an error returned by the service stack, telling timeouts apart
+    /// from other failures.
+    pub(crate) fn from_service(source: BoxError) -> Self {
+        let timed_out = std::iter::successors(
+            Some(&*source as &(dyn std::error::Error + 'static)),
+            |error| error.source(),
+        )
+        .any(is_timeout);
+
+        if timed_out {
+            TimeoutSnafu.into_error(source)
+        } else {
+            ServiceSnafu.into_error(source)
+        }
+    }
+}
+
+fn is_timeout(error: &(dyn std::error::Error + 'static)) -> bool {
+    #[cfg(feature = "timeout")]
+    if error.is::<tower::timeout::error::Elapsed>() {
+        return true;
+    }
+    error
+        .downcast_ref::<std::io::Error>()
+        .is_some_and(|error| error.kind() == std::io::ErrorKind::TimedOut)
+}
+
 /// An error returned from GitHub's API.
 #[derive(Debug, Clone)]
 #[non_exhaustive]
@@ -104,6 +297,13 @@ pub struct GitHubError {
     pub errors: Option<Vec<serde_json::Value>>,
     pub message: String,
     pub status_code: http::StatusCode,
+    /// The permissions the request needs, as GitHub names them in the
+    /// `X-Accepted-GitHub-Permissions` header of responses to fine-grained
+    /// personal access tokens and GitHub Apps, e.g. `contents=read`. A
+    /// `403 Forbidden` with the message "Resource not accessible by personal
+    /// access token" means the token lacks these. Alternative sets of
+    /// permissions are separated by `;`.
+    pub required_permission: Option<String>,
 }
 
 impl fmt::Display for GitHubError {
@@ -114,6 +314,10 @@ impl fmt::Display for GitHubError {
             write!(f, "\nDocumentation URL: {documentation_url}")?;
         }
 
+        if let Some(required_permission) = &self.required_permission {
+            write!(f, "\nRequired permission: {required_permission}")?;
+        }
+
         if let Some(errors) = &self.errors.as_ref().filter(|errors| !errors.is_empty()) {
             write!(f, "\nErrors:")?;
             for error in errors.iter() {
@@ -126,3 +330,93 @@ impl fmt::Display for GitHubError {
 }
 
 impl std::error::Error for GitHubError {}
+
+impl GitHubError {
+    /// Returns the field-level errors of a `422 Unprocessable Entity`
+    /// response, skipping entries of [`GitHubError::errors`] that are not
+    /// objects with a `code`.
+    pub fn validation_errors(&self) -> Vec<ValidationError> {
+        self.errors
+            .iter()
+            .flatten()
+            .filter_map(|error| serde_json::from_value(error.clone()).ok())
+            .collect()
+    }
+}
+
+/// A validation error returned in the `errors` array of a GitHub error
+/// response.
+///
+/// See https://docs.github.com/en/rest/overview/resources-in-the-rest-api#client-errors
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[non_exhaustive]
+pub struct ValidationError {
+    /// The resource that failed validation, e.g. `PullRequest`.
+    pub resource: Option<String>,
+    /// The field that failed validation, e.g. `head`.
+    pub field: Option<String>,
+    /// What was wrong with the field, e.g. `missing_field`, `invalid`,
+    /// `already_exists` or `custom`.
+    pub code: String,
+    /// A description of the error, usually set when `code` is `custom`.
+    pub message: Option<String>,
+}
+
+/// An error returned in the body of a GitHub OAuth token request, which is
+/// sent with a `200 OK` status.
+#[derive(Debug, Clone, serde::Deserialize)]
+#[non_exhaustive]
+pub struct OAuthError {
+    /// The error code, e.g. `bad_verification_code`.
+    pub error: String,
+    pub error_description: Option<String>,
+    pub error_uri: Option<String>,
+}
+
+impl fmt::Display for OAuthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.error)?;
+
+        if let Some(description) = &self.error_description {
+            write!(f, ": {description}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for OAuthError {}
+
+/// An error returned in the `errors` array of a GitHub GraphQL response.
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[non_exhaustive]
+pub struct GraphQLError {
+    pub message: String,
+    /// The kind of error, e.g. `NOT_FOUND`.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// The path to the response field that errored.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub path: Vec<serde_json::Value>,
+    /// The locations in the query that caused the error.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub locations: Vec<GraphQLErrorLocation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<serde_json::Value>,
+}
+
+impl fmt::Display for GraphQLError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for GraphQLError {}
+
+/// A location in a GraphQL query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[non_exhaustive]
+pub struct GraphQLErrorLocation {
+    pub line: u64,
+    pub column: u64,
+}
@@ -6,6 +6,10 @@ use snafu::ResultExt;
 /// A trait for mapping from a `http::Response` to an another type.
 #[async_trait::async_trait]
 pub trait FromResponse: Sized {
+    /// Whether `Self` is a page of a paginated listing. Requests for pages
+    /// are sent with the client's default page size, if it has one.
+    const PAGINATED: bool = false;
+
     async fn from_response<B>(response: http::Response<B>) -> crate::Result<Self>
     where
         B: Body<Data = Bytes, Error = crate::Error> + Send;
@@ -19,7 +23,118 @@ impl<T: serde::de::DeserializeOwned> FromResponse for T {
     {
         let (_, body) = response.into_parts();
         let body = body.collect().await?.to_bytes();
-        let de = &mut serde_json::Deserializer::from_slice(&body);
-        return serde_path_to_error::deserialize(de).context(crate::error::JsonSnafu);
+        // Responses such as `204 No Content` have no body. Read it as `null`,
+        // so that it can be mapped to `()`, `Option<T>` or `serde_json::Value`.
+        let json: &[u8] = if body.is_empty() { b"null" } else { &body };
+        let de = &mut serde_json::Deserializer::from_slice(json);
+        return serde_path_to_error::deserialize(de).context(crate::error::JsonSnafu { body });
+    }
+}
+
+/// A response body mapped with [`FromResponse`], together with the status
+/// and headers of the response it came from.
+///
+/// Request it in place of `T` from any of [`Octocrab`](crate::Octocrab)'s
+/// HTTP methods to read headers that the typed body doesn't carry, such as
+/// `Location` or `X-GitHub-Request-Id`.
+/// ```no_run
+/// # async fn run() -> octocrab::Result<()> {
+/// use octocrab::{models::Repository, FullResponse};
+///
+/// let response: FullResponse<Repository> = octocrab::instance()
+///     .get("/repos/rust-lang/rust", None::<&()>)
+///     .await?;
+/// println!("{} ({:?})", response.body.name, response.request_id());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct FullResponse<T> {
+    pub status: http::StatusCode,
+    pub headers: http::HeaderMap,
+    pub body: T,
+}
+
+impl<T> FullResponse<T> {
+    /// The `X-GitHub-Request-Id` GitHub assigned to the request, which
+    /// GitHub Support asks for when investigating a problem.
+    pub fn request_id(&self) -> Option<&str> {
+        self.headers
+            .get("x-github-request-id")
+            .and_then(|value| value.to_str().ok())
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: FromResponse> FromResponse for FullResponse<T> {
+    const PAGINATED: bool = T::PAGINATED;
+
+    async fn from_response<B>(response: http::Response<B>) -> crate::Result<Self>
+    where
+        B: Body<Data = Bytes, Error = crate::Error> + Send,
+    {
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = T::from_response(response).await?;
+        Ok(Self {
+            status,
+            headers,
+            body,
+        })
+    }
+}
+
+/// A response returned as is by [`Octocrab::raw`](crate::Octocrab::raw),
+/// whatever its status, with the body left to read however suits the
+/// endpoint.
+#[derive(Debug)]
+pub struct RawResponse {
+    status: http::StatusCode,
+    headers: http::HeaderMap,
+    body: http_body_util::combinators::BoxBody<Bytes, crate::Error>,
+}
+
+impl RawResponse {
+    pub(crate) fn new(
+        response: http::Response<http_body_util::combinators::BoxBody<Bytes, crate::Error>>,
+    ) -> Self {
+        let (parts, body) = response.into_parts();
+        Self {
+            status: parts.status,
+            headers: parts.headers,
+            body,
+        }
+    }
+
+    pub fn status(&self) -> http::StatusCode {
+        self.status
+    }
+
+    pub fn headers(&self) -> &http::HeaderMap {
+        &self.headers
+    }
+
+    /// Reads the whole body.
+    pub async fn bytes(self) -> crate::Result<Bytes> {
+        Ok(self.body.collect().await?.to_bytes())
+    }
+
+    /// Reads the whole body and deserializes it from JSON. An empty body is
+    /// read as `null`, like the typed HTTP methods do.
+    pub async fn json<T: serde::de::DeserializeOwned>(self) -> crate::Result<T> {
+        let response = http::Response::new(self.body);
+        T::from_response(response).await
+    }
+
+    /// Turns the response back into an `http::Response`, e.g. to stream its
+    /// body.
+    pub fn into_inner(
+        self,
+    ) -> http::Response<http_body_util::combinators::BoxBody<Bytes, crate::Error>> {
+        let mut response = http::Response::new(self.body);
+        *response.status_mut() = self.status;
+        *response.headers_mut() = self.headers;
+        response
     }
 }
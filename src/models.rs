@@ -18,6 +18,7 @@ pub mod gists;
 pub mod hooks;
 pub mod issues;
 pub mod orgs;
+pub mod projects;
 pub mod pulls;
 pub mod reactions;
 pub mod repos;
@@ -106,6 +107,9 @@ id_type!(
     CheckSuiteId,
     CheckRunId,
     CommentId,
+    DeploymentId,
+    DeploymentStatusId,
+    EnvironmentId,
     InstallationId,
     IssueEventId,
     IssueId,
@@ -121,6 +125,7 @@ id_type!(
     PushId,
     ReactionId,
     ReleaseId,
+    RepoInvitationId,
     RepositoryId,
     ReviewId,
     RunId,
@@ -128,6 +133,7 @@ id_type!(
     RunnerGroupId,
     RunnerLabelId,
     StatusId,
+    TagProtectionId,
     TeamId,
     TimelineEventId,
     ThreadId,
@@ -282,6 +288,9 @@ pub enum Event {
     Unsubscribed,
     /// An organization owner blocked a user from the organization.
     UserBlocked,
+    /// An event that isn't known to this version of the library.
+    #[serde(untagged)]
+    Other(String),
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -424,14 +433,10 @@ fn empty_string_is_none<'de, D>(deserializer: D) -> Result<Option<String>, D::Er
 where
     D: Deserializer<'de>,
 {
-    // try to deserialize our input string
-    let cast = String::deserialize(deserializer)?;
+    // try to deserialize our input string, which may also be null
+    let cast = Option::<String>::deserialize(deserializer)?;
     // if this string is empty then return None
-    if cast.is_empty() {
-        Ok(None)
-    } else {
-        Ok(Some(cast))
-    }
+    Ok(cast.filter(|cast| !cast.is_empty()))
 }
 
 /// The full profile for a user
@@ -456,9 +461,9 @@ pub struct UserProfile {
     pub received_events_url: Url,
     pub r#type: String,
     pub site_admin: bool,
-    pub name: String,
+    pub name: Option<String>,
     pub company: Option<String>,
-    #[serde(deserialize_with = "empty_string_is_none")]
+    #[serde(default, deserialize_with = "empty_string_is_none")]
     pub blog: Option<String>,
     pub location: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -839,6 +844,31 @@ pub struct Code {
     pub git_url: Url,
     pub html_url: Url,
     pub repository: Repository,
+    /// Only present when text match metadata was requested, see
+    /// [`crate::search::QueryHandler::text_match`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_matches: Option<Vec<TextMatch>>,
+}
+
+/// A fragment of a search result containing the matched search terms.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct TextMatch {
+    pub object_url: Option<Url>,
+    pub object_type: Option<String>,
+    /// The property of the result the fragment is from, e.g. `content`.
+    pub property: String,
+    pub fragment: String,
+    pub matches: Vec<TextMatchOffset>,
+}
+
+/// A single search term match within a [`TextMatch::fragment`].
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct TextMatchOffset {
+    pub text: String,
+    /// The start and end offsets of `text` within the fragment.
+    pub indices: Vec<u32>,
 }
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
@@ -1026,18 +1056,67 @@ pub struct InstallationToken {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub expires_at: Option<String>,
     pub permissions: InstallationPermissions,
+    /// Either `all` or `selected`, the repositories the token can access.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repository_selection: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub repositories: Option<Vec<Repository>>,
 }
 
+/// The public key to encrypt secret values with before creating or updating
+/// secrets.
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub struct PublicKey {
+    /// The ID to send along with values encrypted with this key.
     pub key_id: String,
+    /// The base64 encoded Curve25519 key, for use with a libsodium sealed box.
     pub key: String,
 }
 
+#[cfg(feature = "secrets-encryption")]
+#[cfg_attr(docsrs, doc(cfg(feature = "secrets-encryption")))]
+impl PublicKey {
+    /// Encrypts `value` with this key in a sealed box, returning the base64
+    /// encoded ciphertext GitHub expects as the `encrypted_value` of a secret.
+    /// Fails with an [`Error::Other`](crate::Error::Other) if the key isn't
+    /// a base64 encoded Curve25519 key.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let public_key = octocrab::instance()
+    ///     .actions()
+    ///     .get_repo_public_key("owner", "repo")
+    ///     .await?;
+    /// let encrypted_value = public_key.encrypt(b"Very secret value")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn encrypt(&self, value: &[u8]) -> crate::Result<String> {
+        use base64::{engine::general_purpose::STANDARD as B64, Engine};
+        use std::convert::TryInto;
+
+        let other = |message: String| crate::Error::Other {
+            source: message.into(),
+            backtrace: snafu::GenerateImplicitData::generate(),
+        };
+
+        let key: [u8; crypto_box::KEY_SIZE] = B64
+            .decode(&self.key)
+            .map_err(|error| other(format!("Invalid public key: {error}")))?
+            .try_into()
+            .map_err(|_| other("Invalid public key: not a Curve25519 key".to_owned()))?;
+        let encrypted = crypto_box::seal(
+            &mut crypto_box::aead::OsRng,
+            &crypto_box::PublicKey::from(key),
+            value,
+        )
+        .map_err(|_| other("Failed to encrypt the secret value".to_owned()))?;
+
+        Ok(B64.encode(encrypted))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RateLimit {
     pub resources: Resources,
@@ -1061,5 +1140,16 @@ pub struct Rate {
     pub limit: usize,
     pub used: usize,
     pub remaining: usize,
+    /// When the current rate limit window resets, in seconds since the Unix
+    /// epoch. See [`Rate::reset_at`] for it as a timestamp.
     pub reset: u64,
 }
+
+impl Rate {
+    /// When the current rate limit window resets.
+    pub fn reset_at(&self) -> Option<DateTime<Utc>> {
+        use std::convert::TryFrom;
+
+        DateTime::from_timestamp(i64::try_from(self.reset).ok()?, 0)
+    }
+}
@@ -52,3 +52,16 @@ pub struct ThreadSubscription {
     pub url: Url,
     pub thread_url: Url,
 }
+
+/// The authenticated user's subscription to a repository, i.e. whether they
+/// are watching it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct RepositorySubscription {
+    pub subscribed: bool,
+    pub ignored: bool,
+    pub reason: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub url: Url,
+    pub repository_url: Url,
+}
@@ -41,7 +41,9 @@ pub struct Config {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub insecure_ssl: Option<String>,
     pub url: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The key used to sign webhook payloads. This is write-only: GitHub
+    /// only returns it redacted, so it is never read from responses.
+    #[serde(skip_serializing_if = "Option::is_none", skip_deserializing)]
     pub secret: Option<String>,
 }
 
@@ -32,6 +32,8 @@ pub struct Issue {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub active_lock_reason: Option<String>,
     pub comments: u32,
+    /// Links to the pull request, if this issue is one. The issues endpoints
+    /// return pull requests as issues.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pull_request: Option<PullRequestLink>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -61,12 +63,16 @@ pub struct Comment {
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
+/// Why an issue was closed or reopened.
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum IssueStateReason {
+    /// Closed because the work is done.
     Completed,
+    /// Closed without doing the work, e.g. as a duplicate or won't fix.
     NotPlanned,
+    /// Opened again after being closed.
     Reopened,
 }
 
@@ -0,0 +1,24 @@
+use super::*;
+
+/// A card in a column of a project (classic).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Card {
+    pub url: Url,
+    pub id: CardId,
+    pub node_id: String,
+    /// The text of the card, if it isn't linked to an issue or pull request.
+    pub note: Option<String>,
+    pub creator: Option<Author>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[serde(default)]
+    pub archived: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column_name: Option<String>,
+    pub column_url: Url,
+    /// The issue or pull request of the card, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_url: Option<Url>,
+    pub project_url: Url,
+}
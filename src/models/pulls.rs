@@ -360,6 +360,18 @@ pub struct Merge {
     pub merged: bool,
 }
 
+/// The outcome of updating a pull request's branch with the latest changes
+/// of its base branch.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum BranchUpdate {
+    /// The update was accepted, and GitHub merges the base branch into the
+    /// head branch in the background.
+    Scheduled { message: String, url: Url },
+    /// The head branch already contains every commit of the base branch.
+    AlreadyUpToDate,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
@@ -7,7 +7,12 @@ use hyper::Response;
 use snafu::ResultExt;
 use url::Url;
 
+pub mod branch_protection;
+pub mod code_scanning;
+pub mod dependabot;
+pub mod deployments;
 pub mod secrets;
+pub mod traffic;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -49,6 +54,24 @@ pub struct RepoCommit {
     pub files: Option<Vec<DiffEntry>>,
 }
 
+/// An invitation for a user to collaborate on a repository.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct RepoInvitation {
+    pub id: RepoInvitationId,
+    pub node_id: String,
+    pub repository: Repository,
+    pub invitee: Option<Author>,
+    pub inviter: Option<Author>,
+    /// The permission the invitee is given, e.g. `read`, `write` or `admin`.
+    pub permissions: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    #[serde(default)]
+    pub expired: bool,
+    pub url: Url,
+    pub html_url: Url,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub struct RepoCommitPage {
@@ -223,14 +246,18 @@ impl Content {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// Returns `None` unless [`Content::encoding`] is `base64` and the
+    /// content decodes successfully.
     pub fn decoded_content(&self) -> Option<String> {
         use base64::Engine;
-        self.content.as_ref().map(|c| {
-            let mut content = c.as_bytes().to_owned();
-            content.retain(|b| !b" \n\t\r\x0b\x0c".contains(b));
-            let c = base64::prelude::BASE64_STANDARD.decode(content).unwrap();
-            String::from_utf8_lossy(&c).into_owned()
-        })
+        if self.encoding.as_deref() != Some("base64") {
+            return None;
+        }
+        let mut content = self.content.as_ref()?.as_bytes().to_owned();
+        content.retain(|b| !b" \n\t\r\x0b\x0c".contains(b));
+        let c = base64::prelude::BASE64_STANDARD.decode(content).ok()?;
+        Some(String::from_utf8_lossy(&c).into_owned())
     }
 }
 
@@ -240,16 +267,18 @@ impl crate::FromResponse for ContentItems {
     where
         B: Body<Data = Bytes, Error = crate::Error> + Send,
     {
-        let json: serde_json::Value =
-            serde_json::from_slice(response.into_body().collect().await?.to_bytes().as_ref())
-                .context(SerdeSnafu)?;
+        let body = response.into_body().collect().await?.to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).context(SerdeSnafu)?;
 
         if json.is_array() {
             Ok(ContentItems {
-                items: serde_json::from_value(json).context(crate::error::SerdeSnafu)?,
+                items: serde_path_to_error::deserialize(json)
+                    .context(crate::error::JsonSnafu { body })?,
             })
         } else {
-            let items = vec![serde_json::from_value(json).context(crate::error::SerdeSnafu)?];
+            let items =
+                vec![serde_path_to_error::deserialize(json)
+                    .context(crate::error::JsonSnafu { body })?];
 
             Ok(ContentItems { items })
         }
@@ -285,6 +314,21 @@ pub struct Tag {
     pub node_id: String,
 }
 
+/// A rule protecting the tags matching `pattern` from being created or
+/// deleted by anyone without admin or maintain access.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct TagProtection {
+    pub id: TagProtectionId,
+    pub pattern: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
@@ -380,6 +424,80 @@ pub struct GitTag {
     pub message: String,
 }
 
+/// A Git tree, listing the entries of a directory.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct GitTree {
+    pub sha: String,
+    pub url: Option<Url>,
+    pub tree: Vec<TreeEntry>,
+    /// Whether `tree` is incomplete because GitHub's limit for recursive
+    /// trees was exceeded.
+    #[serde(default)]
+    pub truncated: bool,
+}
+
+/// An entry of a [`GitTree`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct TreeEntry {
+    pub path: String,
+    /// The file mode, e.g. `100644` for a file or `040000` for a subdirectory.
+    pub mode: String,
+    #[serde(rename = "type")]
+    pub r#type: TreeEntryType,
+    pub sha: String,
+    /// The size in bytes, only set for blobs.
+    pub size: Option<u64>,
+    pub url: Option<Url>,
+}
+
+/// The kind of object a [`TreeEntry`] points to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum TreeEntryType {
+    Blob,
+    Tree,
+    /// A submodule.
+    Commit,
+}
+
+/// A Git blob, holding the contents of a file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct GitBlob {
+    pub sha: String,
+    pub node_id: String,
+    pub url: Url,
+    pub size: Option<u64>,
+    pub content: String,
+    /// Either `base64` or `utf-8`.
+    pub encoding: String,
+}
+
+impl GitBlob {
+    /// Returns the raw bytes of the blob, or `None` if [`GitBlob::encoding`]
+    /// is `base64` and the content does not decode.
+    pub fn decoded(&self) -> Option<Vec<u8>> {
+        use base64::Engine;
+        if self.encoding != "base64" {
+            return Some(self.content.clone().into_bytes());
+        }
+        let mut content = self.content.as_bytes().to_owned();
+        content.retain(|b| !b" \n\t\r\x0b\x0c".contains(b));
+        base64::prelude::BASE64_STANDARD.decode(content).ok()
+    }
+}
+
+/// A reference to a newly created [`GitBlob`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ShortBlob {
+    pub sha: String,
+    pub url: Url,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub struct MergeCommit {
@@ -0,0 +1,89 @@
+use super::super::*;
+
+/// The protection settings of a branch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct BranchProtection {
+    pub url: Option<Url>,
+    pub required_status_checks: Option<RequiredStatusChecks>,
+    pub enforce_admins: Option<ProtectionSetting>,
+    pub required_pull_request_reviews: Option<RequiredPullRequestReviews>,
+    pub restrictions: Option<BranchRestrictions>,
+    pub required_linear_history: Option<ProtectionSetting>,
+    pub allow_force_pushes: Option<ProtectionSetting>,
+    pub allow_deletions: Option<ProtectionSetting>,
+    pub required_conversation_resolution: Option<ProtectionSetting>,
+}
+
+/// A protection setting that can only be turned on or off.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ProtectionSetting {
+    pub url: Option<Url>,
+    pub enabled: bool,
+}
+
+/// The status checks that must pass before merging into a branch.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RequiredStatusChecks {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<Url>,
+    /// Whether branches must be up to date with the protected branch.
+    pub strict: bool,
+    /// The names of the required checks. Deprecated in favour of `checks`.
+    #[serde(default)]
+    pub contexts: Vec<String>,
+    #[serde(default)]
+    pub checks: Vec<StatusCheck>,
+}
+
+/// A required status check.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StatusCheck {
+    /// The name of the check.
+    pub context: String,
+    /// The ID of the app that must set the check. Without it, any app can.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_id: Option<AppId>,
+}
+
+/// The reviews a pull request needs before merging into a branch.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RequiredPullRequestReviews {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<Url>,
+    /// Whether approvals are dismissed when new commits are pushed.
+    #[serde(default)]
+    pub dismiss_stale_reviews: bool,
+    /// Whether code owners must approve.
+    #[serde(default)]
+    pub require_code_owner_reviews: bool,
+    /// The number of approvals required, between 0 and 6.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_approving_review_count: Option<u8>,
+    /// Whether the most recent push must be approved by someone else.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub require_last_push_approval: Option<bool>,
+}
+
+/// The users, teams and apps allowed to push to a branch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct BranchRestrictions {
+    pub url: Option<Url>,
+    #[serde(default)]
+    pub users: Vec<Author>,
+    #[serde(default)]
+    pub teams: Vec<teams::Team>,
+    #[serde(default)]
+    pub apps: Vec<App>,
+}
+
+/// The users, teams and apps to allow pushing to a branch, given by their
+/// login, team slug and app slug.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct PushRestrictions {
+    pub users: Vec<String>,
+    pub teams: Vec<String>,
+    pub apps: Vec<String>,
+}
@@ -0,0 +1,91 @@
+use super::super::*;
+
+/// An alert raised by a code scanning tool such as CodeQL.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct CodeScanningAlert {
+    pub number: u64,
+    pub state: CodeScanningAlertState,
+    pub rule: CodeScanningRule,
+    pub tool: CodeScanningTool,
+    pub most_recent_instance: Option<CodeScanningAlertInstance>,
+    pub url: Url,
+    pub html_url: Url,
+    pub instances_url: Option<Url>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub fixed_at: Option<DateTime<Utc>>,
+    pub dismissed_at: Option<DateTime<Utc>>,
+    pub dismissed_by: Option<Author>,
+    pub dismissed_reason: Option<String>,
+    pub dismissed_comment: Option<String>,
+}
+
+/// The state of a code scanning alert.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum CodeScanningAlertState {
+    Open,
+    Closed,
+    Dismissed,
+    Fixed,
+}
+
+/// The rule of a code scanning tool that raised an alert.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct CodeScanningRule {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub description: String,
+    /// The severity the tool reports, e.g. `warning` or `error`.
+    pub severity: Option<String>,
+    /// The security severity of the rule, e.g. `high` or `critical`.
+    pub security_severity_level: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// The tool that raised a code scanning alert.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct CodeScanningTool {
+    pub name: String,
+    pub guid: Option<String>,
+    pub version: Option<String>,
+}
+
+/// A place where a code scanning alert was found, on a particular ref.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct CodeScanningAlertInstance {
+    pub r#ref: String,
+    pub analysis_key: String,
+    pub environment: Option<String>,
+    pub category: Option<String>,
+    pub state: CodeScanningAlertState,
+    pub commit_sha: Option<String>,
+    pub message: Option<CodeScanningMessage>,
+    pub location: Option<CodeScanningLocation>,
+    #[serde(default)]
+    pub classifications: Vec<String>,
+}
+
+/// The message the tool attached to an alert instance.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct CodeScanningMessage {
+    pub text: String,
+}
+
+/// The region of a file an alert instance was found in.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct CodeScanningLocation {
+    pub path: String,
+    pub start_line: u64,
+    pub end_line: u64,
+    pub start_column: u64,
+    pub end_column: u64,
+}
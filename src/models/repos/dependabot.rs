@@ -0,0 +1,138 @@
+use super::super::*;
+
+/// An alert raised by Dependabot for a vulnerable dependency.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct DependabotAlert {
+    pub number: u64,
+    pub state: DependabotAlertState,
+    pub dependency: Dependency,
+    pub security_advisory: SecurityAdvisory,
+    /// The vulnerability of the advisory that affects the dependency.
+    pub security_vulnerability: SecurityVulnerability,
+    pub url: Url,
+    pub html_url: Url,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub dismissed_at: Option<DateTime<Utc>>,
+    pub dismissed_by: Option<Author>,
+    pub dismissed_reason: Option<String>,
+    pub dismissed_comment: Option<String>,
+    pub fixed_at: Option<DateTime<Utc>>,
+    pub auto_dismissed_at: Option<DateTime<Utc>>,
+}
+
+/// The state of a Dependabot alert.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum DependabotAlertState {
+    AutoDismissed,
+    Dismissed,
+    Fixed,
+    Open,
+}
+
+/// The severity of a security advisory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum AdvisorySeverity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+/// The vulnerable dependency of a Dependabot alert.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Dependency {
+    pub package: DependencyPackage,
+    /// The manifest the dependency is declared in, e.g. `Cargo.lock`.
+    pub manifest_path: String,
+    /// Whether the dependency is used in `development` or at `runtime`.
+    pub scope: Option<String>,
+}
+
+/// A package in a package ecosystem.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct DependencyPackage {
+    /// The package ecosystem, e.g. `rust` or `npm`.
+    pub ecosystem: String,
+    pub name: String,
+}
+
+/// A GitHub security advisory.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct SecurityAdvisory {
+    pub ghsa_id: String,
+    pub cve_id: Option<String>,
+    pub summary: String,
+    pub description: String,
+    #[serde(default)]
+    pub vulnerabilities: Vec<SecurityVulnerability>,
+    pub severity: AdvisorySeverity,
+    pub cvss: Option<Cvss>,
+    #[serde(default)]
+    pub cwes: Vec<Cwe>,
+    #[serde(default)]
+    pub identifiers: Vec<AdvisoryIdentifier>,
+    #[serde(default)]
+    pub references: Vec<AdvisoryReference>,
+    pub published_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub withdrawn_at: Option<DateTime<Utc>>,
+}
+
+/// The versions of a package affected by a security advisory.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct SecurityVulnerability {
+    pub package: DependencyPackage,
+    pub severity: AdvisorySeverity,
+    /// The affected versions, e.g. `< 1.2.3`.
+    pub vulnerable_version_range: String,
+    pub first_patched_version: Option<FirstPatchedVersion>,
+}
+
+/// The first version of a package that is no longer vulnerable.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct FirstPatchedVersion {
+    pub identifier: String,
+}
+
+/// The Common Vulnerability Scoring System score of an advisory.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Cvss {
+    pub score: f64,
+    pub vector_string: Option<String>,
+}
+
+/// A Common Weakness Enumeration entry.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Cwe {
+    pub cwe_id: String,
+    pub name: String,
+}
+
+/// An identifier of an advisory, such as its GHSA or CVE ID.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct AdvisoryIdentifier {
+    /// The kind of identifier, e.g. `GHSA` or `CVE`.
+    pub r#type: String,
+    pub value: String,
+}
+
+/// A link to more information about an advisory.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct AdvisoryReference {
+    pub url: Url,
+}
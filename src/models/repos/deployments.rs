@@ -0,0 +1,126 @@
+use super::super::*;
+
+/// A request to deploy a specific ref of a repository.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Deployment {
+    pub url: Url,
+    pub id: DeploymentId,
+    pub node_id: String,
+    pub sha: String,
+    /// The ref that was deployed, e.g. a branch, tag or SHA.
+    pub r#ref: String,
+    pub task: String,
+    #[serde(default)]
+    pub payload: serde_json::Value,
+    pub original_environment: Option<String>,
+    pub environment: String,
+    pub description: Option<String>,
+    pub creator: Option<Author>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub statuses_url: Url,
+    pub repository_url: Url,
+    pub transient_environment: Option<bool>,
+    pub production_environment: Option<bool>,
+}
+
+/// The response to creating a deployment.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum CreateDeploymentResponse {
+    /// The deployment was created.
+    Created(Box<Deployment>),
+    /// GitHub merged the default branch into the ref to deploy instead of
+    /// creating a deployment, because `auto_merge` was requested and the ref
+    /// was behind. Create the deployment again to deploy the merge.
+    MergedBranch { message: String },
+}
+
+/// A status of a deployment.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct DeploymentStatus {
+    pub url: Url,
+    pub id: DeploymentStatusId,
+    pub node_id: String,
+    pub state: DeploymentState,
+    pub creator: Option<Author>,
+    pub description: Option<String>,
+    pub environment: Option<String>,
+    pub target_url: Option<Url>,
+    /// The URL of the deployment's output.
+    pub log_url: Option<Url>,
+    /// The URL at which the deployed environment can be accessed.
+    pub environment_url: Option<Url>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deployment_url: Url,
+    pub repository_url: Url,
+}
+
+/// The state of a deployment status.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum DeploymentState {
+    Error,
+    Failure,
+    Inactive,
+    InProgress,
+    Queued,
+    Pending,
+    Success,
+}
+
+/// A deployment environment of a repository.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Environment {
+    pub id: EnvironmentId,
+    pub node_id: String,
+    pub name: String,
+    pub url: Url,
+    pub html_url: Url,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[serde(default)]
+    pub protection_rules: Vec<EnvironmentProtectionRule>,
+    /// Which branches can deploy to the environment, or `None` if any branch can.
+    pub deployment_branch_policy: Option<DeploymentBranchPolicy>,
+}
+
+/// A page of the deployment environments of a repository.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Environments {
+    pub total_count: u64,
+    pub environments: Vec<Environment>,
+}
+
+/// A rule that must pass before deploying to an environment.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct EnvironmentProtectionRule {
+    pub id: u64,
+    pub node_id: String,
+    /// The kind of rule, e.g. `required_reviewers`, `wait_timer` or `branch_policy`.
+    pub r#type: String,
+    /// The minutes to wait before deploying, for `wait_timer` rules.
+    pub wait_timer: Option<u32>,
+    /// Whether the user who triggered the deployment may not approve it.
+    pub prevent_self_review: Option<bool>,
+    /// The users and teams that may approve deployments, for
+    /// `required_reviewers` rules.
+    pub reviewers: Option<Vec<serde_json::Value>>,
+}
+
+/// Which branches can deploy to an environment.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct DeploymentBranchPolicy {
+    /// Whether only branches with branch protection can deploy.
+    pub protected_branches: bool,
+    /// Whether only branches matching custom name patterns can deploy.
+    pub custom_branch_policies: bool,
+}
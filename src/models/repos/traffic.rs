@@ -0,0 +1,50 @@
+use super::super::*;
+
+/// The views of a repository over the last 14 days.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Views {
+    pub count: u64,
+    pub uniques: u64,
+    /// The views of each day or week.
+    pub views: Vec<TrafficCount>,
+}
+
+/// The clones of a repository over the last 14 days.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Clones {
+    pub count: u64,
+    pub uniques: u64,
+    /// The clones of each day or week.
+    pub clones: Vec<TrafficCount>,
+}
+
+/// The traffic of a repository in the day or week starting at `timestamp`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct TrafficCount {
+    pub timestamp: DateTime<Utc>,
+    pub count: u64,
+    pub uniques: u64,
+}
+
+/// A site that referred people to a repository.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Referrer {
+    /// The referring site, e.g. `Google` or `github.com`.
+    pub referrer: String,
+    pub count: u64,
+    pub uniques: u64,
+}
+
+/// A page of a repository and how often it was viewed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PopularPath {
+    pub path: String,
+    pub title: String,
+    pub count: u64,
+    pub uniques: u64,
+}
@@ -77,6 +77,18 @@ pub struct TeamInvitation {
     pub invitation_teams_url: String,
 }
 
+/// A user's membership of a team.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct TeamMembership {
+    pub url: Url,
+    /// Either `member` or `maintainer`.
+    pub role: String,
+    /// Either `active`, or `pending` while the user hasn't accepted an
+    /// invitation to the organization yet.
+    pub state: String,
+}
+
 #[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
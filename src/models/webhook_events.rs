@@ -85,10 +85,33 @@ pub struct WebhookEvent {
     pub installation: Option<EventInstallation>,
     #[serde(skip)]
     pub kind: WebhookEventType,
-    #[serde(flatten)]
+    #[serde(flatten, serialize_with = "serialize_untagged_payload")]
     pub specific: WebhookEventPayload,
 }
 
+/// Serializes the specific payload without the variant name, so that a
+/// serialized [`WebhookEvent`] has the same shape as the payload GitHub sent
+/// (with e.g. `action` at the top level) and can be parsed again with
+/// [`WebhookEvent::try_from_header_and_body`].
+fn serialize_untagged_payload<S>(
+    payload: &WebhookEventPayload,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    use serde::ser::Error;
+
+    match serde_json::to_value(payload).map_err(S::Error::custom)? {
+        serde_json::Value::Object(tagged) if tagged.len() == 1 => tagged
+            .into_iter()
+            .next()
+            .map(|(_, untagged)| untagged)
+            .serialize(serializer),
+        untagged => untagged.serialize(serializer),
+    }
+}
+
 impl WebhookEvent {
     /// Deserialize the body of a webhook event according to the category in the header of the request.
     pub fn try_from_header_and_body<B>(header: &str, body: &B) -> Result<Self, serde_json::Error>
@@ -1221,6 +1244,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn serialize_round_trips_action() {
+        let json = include_str!("../../tests/resources/pull_request_opened_webhook_event.json");
+        let event = WebhookEvent::try_from_header_and_body("pull_request", json).unwrap();
+
+        let serialized = serde_json::to_value(&event).unwrap();
+        assert_eq!(serialized["action"], "opened");
+        assert!(serialized.get("PullRequest").is_none());
+
+        let round_tripped =
+            WebhookEvent::try_from_header_and_body("pull_request", &serialized.to_string())
+                .unwrap();
+        let WebhookEventPayload::PullRequest(pull_request_event) = round_tripped.specific else {
+            panic!(" event is of the wrong type {:?}", round_tripped)
+        };
+        assert_eq!(
+            pull_request_event.action,
+            PullRequestWebhookEventAction::Opened
+        );
+        assert_eq!(round_tripped.sender, event.sender);
+    }
+
+    #[test]
+    fn deserialize_unknown_event_keeps_payload() {
+        let json = r#"{
+            "action": "frobnicated",
+            "frobnicator": { "id": 1 }
+        }"#;
+        let event = WebhookEvent::try_from_header_and_body("frobnication", json).unwrap();
+        assert_eq!(
+            event.kind,
+            WebhookEventType::Unknown("frobnication".to_string())
+        );
+        let WebhookEventPayload::Unknown(ref payload) = event.specific else {
+            panic!(" event is of the wrong type {:?}", event)
+        };
+        assert_eq!(payload["action"], "frobnicated");
+
+        let serialized = serde_json::to_value(&event).unwrap();
+        assert_eq!(serialized["action"], "frobnicated");
+        assert_eq!(serialized["frobnicator"]["id"], 1);
+    }
+
     #[test]
     fn deserialize_push() {
         let json = include_str!("../../tests/resources/push_webhook_event.json");
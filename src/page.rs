@@ -2,10 +2,13 @@ use bytes::Bytes;
 use http::Uri;
 use http_body::Body;
 use http_body_util::BodyExt;
+use serde::de::DeserializeOwned;
+use std::ops::ControlFlow;
 use std::slice::Iter;
 use std::str::FromStr;
 
 use crate::error::{SerdeSnafu, UriSnafu};
+use crate::Octocrab;
 use snafu::{GenerateImplicitData, ResultExt};
 use url::form_urlencoded;
 
@@ -13,9 +16,6 @@ cfg_if::cfg_if! {
     if #[cfg(feature = "stream")] {
         use futures_core::Stream;
         use futures_util::stream::try_unfold;
-        use serde::de::DeserializeOwned;
-
-        use crate::Octocrab;
     }
 }
 
@@ -36,12 +36,64 @@ cfg_if::cfg_if! {
 #[derive(Clone, Debug)]
 pub struct Page<T> {
     pub items: Vec<T>,
+    /// Whether a search timed out before every match was found, in which
+    /// case `items` and `total_count` may be short. `None` for responses
+    /// that aren't search results.
     pub incomplete_results: Option<bool>,
+    /// The total number of results across all pages, for responses that
+    /// report it, such as search results.
     pub total_count: Option<u64>,
+    /// The total number of results given by the `X-Total-Count` header,
+    /// which some endpoints send instead of a `total_count` field. Read with
+    /// [`Page::total`].
+    total_count_header: Option<u64>,
+    /// The `rel="next"` link of the `Link` header, if present.
+    pub next: Option<Uri>,
+    /// The `rel="prev"` link of the `Link` header, if present.
+    pub prev: Option<Uri>,
+    /// The `rel="first"` link of the `Link` header, if present.
+    pub first: Option<Uri>,
+    /// The `rel="last"` link of the `Link` header, if present.
+    pub last: Option<Uri>,
+}
+
+/// The total number of results of a listing, returned by [`Page::total`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Total {
+    /// The `total_count` field of the response body.
+    pub count: Option<u64>,
+    /// The `X-Total-Count` header of the response.
+    pub header: Option<u64>,
+}
+
+impl Total {
+    /// The total number of results, preferring the `total_count` field over
+    /// the `X-Total-Count` header. `None` if the endpoint reports neither.
+    /// Search results may undercount when
+    /// [`incomplete_results`](Page::incomplete_results) is set.
+    pub fn estimate(&self) -> Option<u64> {
+        self.count.or(self.header)
+    }
+}
+
+/// The pagination metadata of a listing, returned by [`Page::info`]: the
+/// `Link` header rels together with the reported totals.
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PageInfo {
+    /// The `rel="next"` link of the `Link` header, if present.
     pub next: Option<Uri>,
+    /// The `rel="prev"` link of the `Link` header, if present.
     pub prev: Option<Uri>,
+    /// The `rel="first"` link of the `Link` header, if present.
     pub first: Option<Uri>,
+    /// The `rel="last"` link of the `Link` header, if present.
     pub last: Option<Uri>,
+    /// The total number of results, from the body or the headers.
+    pub total: Total,
+    /// Whether a search timed out before every match was found.
+    pub incomplete_results: Option<bool>,
 }
 
 #[cfg(feature = "stream")]
@@ -52,6 +104,83 @@ struct PageIterator<'octo, T> {
 }
 
 impl<T> Page<T> {
+    /// Fetches the page at `url`, such as a [`next`](Page::next) link that
+    /// was stored earlier. The link already carries the query and page of
+    /// the original request, so this resumes listing where it left off,
+    /// e.g. after a restart.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::{models::issues::Issue, Page};
+    ///
+    /// let crab = octocrab::instance();
+    /// let page = crab
+    ///     .issues("owner", "repo")
+    ///     .list()
+    ///     .since(chrono::Utc::now() - chrono::Duration::days(7))
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// let checkpoint = page.next.map(|next| next.to_string());
+    ///
+    /// // Later, possibly in another process.
+    /// if let Some(checkpoint) = checkpoint {
+    ///     let page = Page::<Issue>::from_url(&crab, checkpoint).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn from_url(crab: &Octocrab, url: impl AsRef<str>) -> crate::Result<Self>
+    where
+        T: DeserializeOwned,
+    {
+        crab.get(url, None::<&()>).await
+    }
+
+    /// Returns the total number of results reported for this listing, from
+    /// wherever the endpoint reports it.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance()
+    ///     .search()
+    ///     .repositories("tetris language:rust")
+    ///     .send()
+    ///     .await?;
+    /// println!("{:?} repositories", page.total().estimate());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn total(&self) -> Total {
+        Total {
+            count: self.total_count,
+            header: self.total_count_header,
+        }
+    }
+
+    /// Returns the pagination metadata of this page, so that it can be kept
+    /// or passed around without the items.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance()
+    ///     .issues("rust-lang", "rust")
+    ///     .list()
+    ///     .send()
+    ///     .await?;
+    /// let info = page.info();
+    /// println!("{:?} issues, next page: {:?}", info.total.estimate(), info.next);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn info(&self) -> PageInfo {
+        PageInfo {
+            next: self.next.clone(),
+            prev: self.prev.clone(),
+            first: self.first.clone(),
+            last: self.last.clone(),
+            total: self.total(),
+            incomplete_results: self.incomplete_results,
+        }
+    }
+
     /// Returns the current set of items, replacing it with an empty Vec.
     pub fn take_items(&mut self) -> Vec<T> {
         std::mem::take(&mut self.items)
@@ -73,10 +202,131 @@ impl<T> Page<T> {
         })
     }
 
+    /// Calls `f` with this page and then with each page after it, fetching
+    /// them one at a time, until `f` returns [`ControlFlow::Break`] or there
+    /// are no pages left. Returns the value `f` broke with, if any.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use std::ops::ControlFlow;
+    ///
+    /// let crab = octocrab::instance();
+    /// let page = crab.issues("owner", "repo").list().send().await?;
+    /// let bug = page
+    ///     .for_each_page(&crab, |page| {
+    ///         match page.items.into_iter().find(|issue| issue.title.contains("bug")) {
+    ///             Some(issue) => ControlFlow::Break(issue),
+    ///             None => ControlFlow::Continue(()),
+    ///         }
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn for_each_page<B, F>(self, crab: &Octocrab, mut f: F) -> crate::Result<Option<B>>
+    where
+        T: DeserializeOwned,
+        F: FnMut(Page<T>) -> ControlFlow<B>,
+    {
+        let mut page = Some(self);
+        while let Some(current) = page {
+            let next = current.next.clone();
+            if let ControlFlow::Break(value) = f(current) {
+                return Ok(Some(value));
+            }
+            page = crab.get_page(&next).await?;
+        }
+        Ok(None)
+    }
+
+    /// Like [`Page::for_each_page`], but stops at the first error, either
+    /// returned by `f` or from fetching the next page.
+    /// ```no_run
+    /// enum SyncError {
+    ///     GitHub(octocrab::Error),
+    ///     Locked(u64),
+    /// }
+    ///
+    /// impl From<octocrab::Error> for SyncError {
+    ///     fn from(error: octocrab::Error) -> Self {
+    ///         Self::GitHub(error)
+    ///     }
+    /// }
+    ///
+    /// # async fn run() -> Result<(), SyncError> {
+    /// let crab = octocrab::instance();
+    /// let page = crab.issues("owner", "repo").list().send().await?;
+    /// page.try_for_each_page(&crab, |page| {
+    ///     for issue in page {
+    ///         if issue.locked {
+    ///             return Err(SyncError::Locked(issue.number));
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// })
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn try_for_each_page<E, F>(self, crab: &Octocrab, mut f: F) -> Result<(), E>
+    where
+        T: DeserializeOwned,
+        E: From<crate::Error>,
+        F: FnMut(Page<T>) -> Result<(), E>,
+    {
+        let mut page = Some(self);
+        while let Some(current) = page {
+            let next = current.next.clone();
+            f(current)?;
+            page = crab.get_page(&next).await?;
+        }
+        Ok(())
+    }
+
+    /// Folds this page and each page after it into an accumulator, fetching
+    /// them one at a time. `f` can stop early by returning
+    /// [`ControlFlow::Break`] with the final value.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use std::ops::ControlFlow;
+    ///
+    /// let crab = octocrab::instance();
+    /// let page = crab
+    ///     .search()
+    ///     .issues_and_pull_requests("tokio repo:rust-lang/rust")
+    ///     .send()
+    ///     .await?;
+    /// let item_count = page
+    ///     .fold_pages(&crab, 0, |sum, page| ControlFlow::Continue(sum + page.items.len()))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fold_pages<A, F>(self, crab: &Octocrab, init: A, mut f: F) -> crate::Result<A>
+    where
+        T: DeserializeOwned,
+        F: FnMut(A, Page<T>) -> ControlFlow<A, A>,
+    {
+        let mut acc = init;
+        let mut page = Some(self);
+        while let Some(current) = page {
+            let next = current.next.clone();
+            acc = match f(acc, current) {
+                ControlFlow::Continue(acc) => acc,
+                ControlFlow::Break(acc) => return Ok(acc),
+            };
+            page = crab.get_page(&next).await?;
+        }
+        Ok(acc)
+    }
+
     /// Convert Page into a stream of results
     ///
     /// This will fetch new pages using the next link with in the page so that
-    /// it returns all the results that matched the original request.
+    /// it returns all the results that matched the original request. Pages
+    /// are only fetched once the items of the previous page have been
+    /// consumed, so unlike [`Octocrab::all_pages`] only one page is held in
+    /// memory at a time. If fetching a page fails, the error is yielded and
+    /// the stream ends.
     ///
     /// E.g. iterating across all of the repos in an org with too many to fit
     /// in one page of results:
@@ -139,6 +389,7 @@ impl<T> Default for Page<T> {
             items: Vec::new(),
             incomplete_results: None,
             total_count: None,
+            total_count_header: None,
             next: None,
             prev: None,
             first: None,
@@ -167,6 +418,8 @@ impl<'iter, T> IntoIterator for &'iter Page<T> {
 
 #[async_trait::async_trait]
 impl<T: serde::de::DeserializeOwned> crate::FromResponse for Page<T> {
+    const PAGINATED: bool = true;
+
     async fn from_response<B>(response: http::Response<B>) -> crate::Result<Self>
     where
         B: Body<Data = Bytes, Error = crate::Error> + Send,
@@ -177,16 +430,21 @@ impl<T: serde::de::DeserializeOwned> crate::FromResponse for Page<T> {
             next,
             last,
         } = get_links(response.headers())?;
+        let total_count_header = response
+            .headers()
+            .get("X-Total-Count")
+            .and_then(|value| value.to_str().ok()?.trim().parse().ok());
 
-        let json: serde_json::Value =
-            serde_json::from_slice(response.into_body().collect().await?.to_bytes().as_ref())
-                .context(SerdeSnafu)?;
+        let body = response.into_body().collect().await?.to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).context(SerdeSnafu)?;
 
         if json.is_array() {
             Ok(Self {
-                items: serde_json::from_value(json).context(crate::error::SerdeSnafu)?,
+                items: serde_path_to_error::deserialize(json)
+                    .context(crate::error::JsonSnafu { body })?,
                 incomplete_results: None,
                 total_count: None,
+                total_count_header,
                 next,
                 prev,
                 first,
@@ -211,12 +469,13 @@ impl<T: serde::de::DeserializeOwned> crate::FromResponse for Page<T> {
             .context(crate::error::OtherSnafu)?;
 
             Ok(Self {
-                items: serde_json::from_value(json.get(attr).cloned().unwrap())
-                    .context(crate::error::SerdeSnafu)?,
+                items: serde_path_to_error::deserialize(json.get(attr).cloned().unwrap())
+                    .context(crate::error::JsonSnafu { body })?,
                 incomplete_results: json
                     .get("incomplete_results")
                     .and_then(serde_json::Value::as_bool),
                 total_count: json.get("total_count").and_then(serde_json::Value::as_u64),
+                total_count_header,
                 next,
                 prev,
                 first,
@@ -10,9 +10,7 @@ pub enum State {
     Closed,
 }
 
-/// What to sort results by. Can be either `created`, `updated`, `popularity`
-/// (comment count) or `long-running` (age, filtering by pulls updated in the
-/// last month).
+/// The direction to sort results in. Can be either `asc` or `desc`.
 #[derive(Debug, Clone, Copy, serde::Serialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
@@ -23,7 +21,18 @@ pub enum Direction {
     Descending,
 }
 
-/// The reason for locking an issue.
+impl From<Direction> for String {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Ascending => "asc",
+            Direction::Descending => "desc",
+        }
+        .to_owned()
+    }
+}
+
+/// The reason for locking an issue. Serialized as the strings GitHub
+/// expects, e.g. `off-topic` and `too heated`.
 #[derive(Debug, Clone, Copy, serde::Serialize)]
 #[non_exhaustive]
 pub enum LockReason {
@@ -258,12 +267,73 @@ pub mod markdown {
     }
 }
 
+pub mod milestones {
+    //! Parameter types for the milestones API.
+
+    /// The state of a milestone.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "lowercase")]
+    #[non_exhaustive]
+    pub enum State {
+        Open,
+        Closed,
+    }
+
+    /// What to sort milestones by.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum Sort {
+        DueOn,
+        Completeness,
+    }
+}
+
+pub mod projects {
+    //! Parameter types for the projects API.
+
+    use crate::models::CardId;
+
+    /// Which cards of a project column to list.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum ArchivedState {
+        All,
+        Archived,
+        NotArchived,
+    }
+
+    /// Where to move a project card to.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[non_exhaustive]
+    pub enum CardPosition {
+        /// The top of the column.
+        Top,
+        /// The bottom of the column.
+        Bottom,
+        /// Right after the given card.
+        After(CardId),
+    }
+
+    impl serde::Serialize for CardPosition {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            match self {
+                Self::Top => serializer.serialize_str("top"),
+                Self::Bottom => serializer.serialize_str("bottom"),
+                Self::After(card_id) => serializer.collect_str(&format_args!("after:{card_id}")),
+            }
+        }
+    }
+}
+
 pub mod orgs {
     //! Parameter types for the organization API.
 
-    /// What to sort results by. Can be either `created`, `updated`, `popularity`
-    /// (comment count) or `long-running` (age, filtering by pulls updated in the
-    /// last month).
+    /// The role a user has in an organization.
     #[derive(Debug, Clone, Copy, serde::Serialize)]
     #[serde(rename_all = "snake_case")]
     #[non_exhaustive]
@@ -271,6 +341,27 @@ pub mod orgs {
         Member,
         Admin,
     }
+
+    /// What role to filter organization members by.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum MemberRole {
+        All,
+        Admin,
+        Member,
+    }
+
+    /// What to filter organization members by. `TwoFactorDisabled` is only
+    /// available to organization owners.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum MemberFilter {
+        #[serde(rename = "2fa_disabled")]
+        TwoFactorDisabled,
+        All,
+    }
 }
 
 pub mod pulls {
@@ -286,6 +377,7 @@ pub mod pulls {
         Created,
         Updated,
         Popularity,
+        #[serde(rename = "long-running")]
         LongRunning,
     }
 
@@ -334,6 +426,35 @@ pub mod pulls {
         Closed,
     }
 
+    /// The action to take on a pull request review.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[non_exhaustive]
+    pub enum ReviewEvent {
+        Approve,
+        RequestChanges,
+        Comment,
+        /// Leaves the review pending, to be submitted later. GitHub expects
+        /// no event at all for this, so it is never sent.
+        Pending,
+    }
+
+    /// A comment on the diff, left as part of a pull request review.
+    #[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize)]
+    pub struct ReviewComment {
+        /// The path of the file to comment on.
+        pub path: String,
+        /// The position in the diff to comment on. Deprecated by GitHub in
+        /// favour of `line`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub position: Option<u64>,
+        /// The line of the file to comment on.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub line: Option<u64>,
+        /// The text of the comment.
+        pub body: String,
+    }
+
     pub mod comments {
         /// What to sort results by. Can be either `created` or `updated`.
         #[derive(Debug, Clone, Copy, serde::Serialize)]
@@ -343,6 +464,16 @@ pub mod pulls {
             Created,
             Updated,
         }
+
+        /// The side of a split diff a review comment applies to. `Left` is
+        /// the old version of the file, `Right` the new one.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+        #[serde(rename_all = "UPPERCASE")]
+        #[non_exhaustive]
+        pub enum Side {
+            Left,
+            Right,
+        }
     }
 }
 
@@ -380,6 +511,16 @@ pub mod repos {
         FullName,
     }
 
+    /// The encoding of the content of a new blob.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[non_exhaustive]
+    pub enum BlobEncoding {
+        #[serde(rename = "utf-8")]
+        Utf8,
+        #[serde(rename = "base64")]
+        Base64,
+    }
+
     /// A Git reference, either a branch, tag, or rev.
     #[derive(Debug, Clone)]
     pub enum Reference {
@@ -449,6 +590,134 @@ pub mod repos {
             Stargazers,
         }
     }
+
+    pub mod collaborators {
+        /// How a collaborator is affiliated with a repository.
+        #[derive(Debug, Clone, Copy, serde::Serialize)]
+        #[serde(rename_all = "snake_case")]
+        #[non_exhaustive]
+        pub enum Affiliation {
+            /// Outside collaborators of an organization-owned repository.
+            Outside,
+            /// Collaborators with permissions to the repository itself,
+            /// regardless of organization membership.
+            Direct,
+            /// Every collaborator the authenticated user can see.
+            All,
+        }
+    }
+
+    pub mod traffic {
+        /// The time unit repository traffic is broken down by.
+        #[derive(Debug, Clone, Copy, serde::Serialize)]
+        #[serde(rename_all = "snake_case")]
+        #[non_exhaustive]
+        pub enum Per {
+            Day,
+            Week,
+        }
+    }
+
+    pub mod dependabot {
+        /// The state of a Dependabot alert.
+        #[derive(Debug, Clone, Copy, serde::Serialize)]
+        #[serde(rename_all = "snake_case")]
+        #[non_exhaustive]
+        pub enum State {
+            AutoDismissed,
+            Dismissed,
+            Fixed,
+            Open,
+        }
+
+        /// The severity of the advisory behind a Dependabot alert.
+        #[derive(Debug, Clone, Copy, serde::Serialize)]
+        #[serde(rename_all = "snake_case")]
+        #[non_exhaustive]
+        pub enum Severity {
+            Low,
+            Medium,
+            High,
+            Critical,
+        }
+    }
+
+    pub mod code_scanning {
+        /// The state of a code scanning alert.
+        #[derive(Debug, Clone, Copy, serde::Serialize)]
+        #[serde(rename_all = "snake_case")]
+        #[non_exhaustive]
+        pub enum State {
+            Open,
+            Closed,
+            Dismissed,
+            Fixed,
+        }
+
+        /// The severity of a code scanning alert, either the security
+        /// severity of the rule (`low` to `critical`) or the severity the
+        /// tool reported (`note` to `error`).
+        #[derive(Debug, Clone, Copy, serde::Serialize)]
+        #[serde(rename_all = "snake_case")]
+        #[non_exhaustive]
+        pub enum Severity {
+            Critical,
+            High,
+            Medium,
+            Low,
+            Warning,
+            Note,
+            Error,
+        }
+    }
+}
+
+pub mod search {
+    //! Parameter types for the search API.
+
+    /// What to sort repository search results by. Default: best match.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "kebab-case")]
+    #[non_exhaustive]
+    pub enum RepositorySort {
+        Stars,
+        Forks,
+        HelpWantedIssues,
+        Updated,
+    }
+
+    impl From<RepositorySort> for String {
+        fn from(sort: RepositorySort) -> Self {
+            match sort {
+                RepositorySort::Stars => "stars",
+                RepositorySort::Forks => "forks",
+                RepositorySort::HelpWantedIssues => "help-wanted-issues",
+                RepositorySort::Updated => "updated",
+            }
+            .to_owned()
+        }
+    }
+
+    /// What to sort user search results by. Default: best match.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum UserSort {
+        Followers,
+        Repositories,
+        Joined,
+    }
+
+    impl From<UserSort> for String {
+        fn from(sort: UserSort) -> Self {
+            match sort {
+                UserSort::Followers => "followers",
+                UserSort::Repositories => "repositories",
+                UserSort::Joined => "joined",
+            }
+            .to_owned()
+        }
+    }
 }
 
 pub mod teams {
@@ -470,6 +739,35 @@ pub mod teams {
         Maintain,
         Triage,
     }
+
+    /// The role a user has in a team.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum Role {
+        Member,
+        Maintainer,
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn serialize_permission() {
+            assert_eq!(
+                serde_json::to_value([
+                    Permission::Pull,
+                    Permission::Triage,
+                    Permission::Push,
+                    Permission::Maintain,
+                    Permission::Admin,
+                ])
+                .unwrap(),
+                serde_json::json!(["pull", "triage", "push", "maintain", "admin"])
+            )
+        }
+    }
 }
 
 pub mod workflows {
@@ -3,18 +3,35 @@ use std::sync::Arc;
 use http::{header::AUTHORIZATION, request::Request, HeaderValue, Uri};
 use tower::{Layer, Service};
 
+/// Request extension that keeps [`AuthHeader`] from adding its header.
+#[derive(Clone, Copy)]
+pub(crate) struct SkipAuthHeader;
+
+/// Whether `uri` is relative or points at the base or upload host, the only
+/// hosts credentials are sent to. Absolute urls must match the scheme of the
+/// host too, so that credentials are never sent in the clear to the `http`
+/// url of an `https` host.
+pub(crate) fn is_github_bound(uri: &Uri, base_uri: &Uri, upload_uri: &Uri) -> bool {
+    uri.authority().is_none()
+        || [base_uri, upload_uri]
+            .iter()
+            .any(|host| uri.scheme() == host.scheme() && uri.authority() == host.authority())
+}
+
 #[derive(Clone)]
 /// Layer that adds the authentication header to github-bound requests
 pub struct AuthHeaderLayer {
     pub(crate) auth_header: Arc<Option<HeaderValue>>,
     base_uri: Uri,
+    upload_uri: Uri,
 }
 
 impl AuthHeaderLayer {
-    pub fn new(auth_header: Option<HeaderValue>, base_uri: Uri) -> Self {
+    pub fn new(auth_header: Option<HeaderValue>, base_uri: Uri, upload_uri: Uri) -> Self {
         AuthHeaderLayer {
             auth_header: Arc::new(auth_header),
             base_uri,
+            upload_uri,
         }
     }
 }
@@ -27,6 +44,7 @@ impl<S> Layer<S> for AuthHeaderLayer {
             inner,
             auth_header: self.auth_header.clone(),
             base_uri: self.base_uri.clone(),
+            upload_uri: self.upload_uri.clone(),
         }
     }
 }
@@ -37,6 +55,7 @@ pub struct AuthHeader<S> {
     inner: S,
     pub(crate) auth_header: Arc<Option<HeaderValue>>,
     base_uri: Uri,
+    upload_uri: Uri,
 }
 
 impl<S, ReqBody> Service<Request<ReqBody>> for AuthHeader<S>
@@ -58,9 +77,11 @@ where
         // Only set the auth_header if the authority (host) is destined for
         // GitHub. Otherwise, leave it off as we could have been redirected
         // away from GitHub (via follow_location_to_data()), and we don't
-        // want to give our credentials to third-party services.
-        let authority = req.uri().authority();
-        if authority.is_none() || authority == self.base_uri.authority() {
+        // want to give our credentials to third-party services. Requests
+        // that already carry credentials of their own are left alone.
+        let has_own_auth = req.headers().contains_key(AUTHORIZATION)
+            || req.extensions().get::<SkipAuthHeader>().is_some();
+        if !has_own_auth && is_github_bound(req.uri(), &self.base_uri, &self.upload_uri) {
             if let Some(auth_header) = &*self.auth_header {
                 req.headers_mut().append(AUTHORIZATION, auth_header.clone());
             }
@@ -68,3 +89,23 @@ where
         self.inner.call(req)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::is_github_bound;
+    use http::Uri;
+
+    #[test]
+    fn only_configured_hosts_are_github_bound() {
+        let base_uri = Uri::from_static("https://github.example.com/api/v3");
+        let upload_uri = Uri::from_static("https://uploads.example.com");
+        let is_bound =
+            |uri: &'static str| is_github_bound(&Uri::from_static(uri), &base_uri, &upload_uri);
+
+        assert!(is_bound("/repos/owner/repo"));
+        assert!(is_bound("https://github.example.com/api/v3/repos?page=2"));
+        assert!(is_bound("https://uploads.example.com/repos/owner/repo"));
+        assert!(!is_bound("http://uploads.example.com/repos/owner/repo"));
+        assert!(!is_bound("https://objects.githubusercontent.com/asset"));
+    }
+}
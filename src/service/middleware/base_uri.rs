@@ -65,6 +65,13 @@ where
 
 // Join base URI and Path+Query, preserving any path in the base.
 fn overwrite_base_uri(base_uri: &http::Uri, current_uri: Uri) -> http::Uri {
+    // Absolute URIs, such as upload URLs or redirects, are already complete.
+    // `AuthHeader` only sends credentials along if they point at the base or
+    // upload host.
+    if current_uri.authority().is_some() {
+        return current_uri;
+    }
+
     let req_pandq = current_uri.path_and_query();
     let mut builder = uri::Builder::new();
     match current_uri.scheme() {
@@ -123,4 +130,15 @@ mod tests {
             "https://example.com/foo/bar/api/v1/nodes?hi=yes"
         );
     }
+
+    #[test]
+    fn absolute_uri() {
+        let base_uri = http::Uri::from_static("https://github.example.com/api/v3");
+        let upload_uri =
+            http::Uri::from_static("https://github.example.com/api/uploads/repos?name=a");
+        assert_eq!(
+            super::overwrite_base_uri(&base_uri, upload_uri),
+            "https://github.example.com/api/uploads/repos?name=a"
+        );
+    }
 }
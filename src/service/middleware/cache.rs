@@ -0,0 +1,483 @@
+//! Cache `GET` responses and revalidate them with their `ETag`.
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeMap, HashMap},
+    hash::{Hash, Hasher},
+    sync::{Arc, Mutex, PoisonError},
+    task::{Context, Poll},
+};
+
+use bytes::{Bytes, BytesMut};
+use futures::{
+    future::{BoxFuture, FutureExt, TryFutureExt},
+    stream, StreamExt,
+};
+use http::{
+    header::{
+        ACCEPT, AUTHORIZATION, CACHE_CONTROL, CONTENT_LENGTH, CONTENT_TYPE, ETAG, IF_NONE_MATCH,
+        TRANSFER_ENCODING,
+    },
+    HeaderMap, HeaderName, HeaderValue, Method, Request, Response, StatusCode, Uri,
+};
+use http_body::Frame;
+use http_body_util::{combinators::BoxBody, BodyExt, BodyStream, Full, StreamBody};
+use tower::{BoxError, Layer, Service};
+
+/// Responses with larger bodies, such as big file contents, aren't cached.
+const MAX_BODY_SIZE: usize = 1024 * 1024;
+
+/// Identifies a cached response: GitHub's responses vary with the media type
+/// requested in `Accept` and with the credentials they were requested with.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CacheKey {
+    pub uri: Uri,
+    pub accept: Option<HeaderValue>,
+    /// A hash of the `Authorization` header, so that responses are never
+    /// shared between credentials without the credentials being stored.
+    pub authorization: Option<u64>,
+}
+
+impl CacheKey {
+    fn new<B>(req: &Request<B>) -> Self {
+        let authorization = req.headers().get(AUTHORIZATION).map(|value| {
+            let mut hasher = DefaultHasher::new();
+            value.as_bytes().hash(&mut hasher);
+            hasher.finish()
+        });
+        Self {
+            uri: req.uri().clone(),
+            accept: req.headers().get(ACCEPT).cloned(),
+            authorization,
+        }
+    }
+}
+
+/// A response stored in a [`CacheStorage`], along with the `ETag` it was
+/// served with.
+#[derive(Debug, Clone)]
+pub struct CachedResponse {
+    pub etag: HeaderValue,
+    pub headers: HeaderMap,
+    pub body: Bytes,
+}
+
+impl CachedResponse {
+    /// The number of bytes the response takes up, roughly.
+    fn size(&self) -> usize {
+        let headers: usize = self
+            .headers
+            .iter()
+            .map(|(name, value)| name.as_str().len() + value.len())
+            .sum();
+        self.etag.len() + headers + self.body.len()
+    }
+}
+
+/// Storage for the responses cached by [`CacheLayer`].
+///
+/// [`InMemoryCache`] keeps them in memory; implement this trait to keep them
+/// elsewhere, e.g. in Redis to share them between processes.
+#[async_trait::async_trait]
+pub trait CacheStorage: Send + Sync {
+    /// Returns the response cached for `key`, if any.
+    async fn get(&self, key: &CacheKey) -> Option<CachedResponse>;
+
+    /// Caches `response` for `key`, replacing any previous one.
+    async fn put(&self, key: &CacheKey, response: CachedResponse);
+}
+
+/// A [`CacheStorage`] keeping up to `max_bytes` of responses in memory,
+/// evicting the least recently used ones when full.
+pub struct InMemoryCache {
+    max_bytes: usize,
+    entries: Mutex<Entries>,
+}
+
+#[derive(Default)]
+struct Entries {
+    responses: HashMap<CacheKey, Entry>,
+    /// The keys of `responses`, by when they were last used.
+    recency: BTreeMap<u64, CacheKey>,
+    bytes: usize,
+    clock: u64,
+}
+
+struct Entry {
+    response: CachedResponse,
+    last_used: u64,
+}
+
+impl Entries {
+    fn tick(&mut self) -> u64 {
+        self.clock += 1;
+        self.clock
+    }
+
+    fn remove(&mut self, key: &CacheKey) {
+        if let Some(entry) = self.responses.remove(key) {
+            self.recency.remove(&entry.last_used);
+            self.bytes -= entry.response.size();
+        }
+    }
+}
+
+impl InMemoryCache {
+    pub fn new(max_bytes: usize) -> Self {
+        Self {
+            max_bytes,
+            entries: Mutex::default(),
+        }
+    }
+
+    fn entries(&self) -> std::sync::MutexGuard<'_, Entries> {
+        // The entries are consistent between statements that can't panic,
+        // so they're still usable if another thread panicked.
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[async_trait::async_trait]
+impl CacheStorage for InMemoryCache {
+    async fn get(&self, key: &CacheKey) -> Option<CachedResponse> {
+        let mut entries = self.entries();
+        let now = entries.tick();
+        let entry = entries.responses.get_mut(key)?;
+        let last_used = std::mem::replace(&mut entry.last_used, now);
+        let response = entry.response.clone();
+        entries.recency.remove(&last_used);
+        entries.recency.insert(now, key.clone());
+        Some(response)
+    }
+
+    async fn put(&self, key: &CacheKey, response: CachedResponse) {
+        let size = response.size();
+        let mut entries = self.entries();
+        entries.remove(key);
+        if size > self.max_bytes {
+            return;
+        }
+        while entries.bytes + size > self.max_bytes {
+            match entries.recency.pop_first() {
+                Some((_, oldest)) => entries.remove(&oldest),
+                None => break,
+            }
+        }
+        let now = entries.tick();
+        entries.bytes += size;
+        entries.recency.insert(now, key.clone());
+        entries.responses.insert(
+            key.clone(),
+            Entry {
+                response,
+                last_used: now,
+            },
+        );
+    }
+}
+
+/// Layer that applies [`Cache`], storing responses in a [`CacheStorage`].
+#[derive(Clone)]
+pub struct CacheLayer {
+    storage: Arc<dyn CacheStorage>,
+}
+
+impl CacheLayer {
+    pub fn new(storage: Arc<dyn CacheStorage>) -> Self {
+        Self { storage }
+    }
+}
+
+impl<S> Layer<S> for CacheLayer {
+    type Service = Cache<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        Cache {
+            inner,
+            storage: self.storage.clone(),
+        }
+    }
+}
+
+/// Middleware that caches successful `GET` responses carrying an `ETag`.
+///
+/// Once a response is cached, requests for the same URI, media type and
+/// credentials are sent with `If-None-Match`, and a `304 Not Modified` is
+/// answered with the cached response, so it's deserialized from the cached
+/// body rather than from the empty one. GitHub doesn't count such requests
+/// against the rate limit.
+///
+/// Only JSON bodies of up to 1 MiB are cached, so downloads such as release
+/// assets or archives pass through. Responses sent with
+/// `Cache-Control: no-store` aren't cached either, and requests that already
+/// carry `If-None-Match`, such as those of
+/// [`Octocrab::get_etagged`](crate::Octocrab::get_etagged), bypass the cache.
+#[derive(Clone)]
+pub struct Cache<S> {
+    inner: S,
+    storage: Arc<dyn CacheStorage>,
+}
+
+impl<S, ReqBody> Service<Request<ReqBody>> for Cache<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<BoxBody<Bytes, crate::Error>>>
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send,
+    S::Error: Into<BoxError>,
+    ReqBody: Send + 'static,
+{
+    type Error = BoxError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+    type Response = S::Response;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, mut req: Request<ReqBody>) -> Self::Future {
+        if req.method() != Method::GET || req.headers().contains_key(IF_NONE_MATCH) {
+            return self.inner.call(req).map_err(Into::into).boxed();
+        }
+
+        // Take the service that was polled ready, leaving a clone behind.
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let storage = self.storage.clone();
+        async move {
+            let key = CacheKey::new(&req);
+            let cached = storage.get(&key).await;
+            if let Some(cached) = &cached {
+                req.headers_mut().insert(IF_NONE_MATCH, cached.etag.clone());
+            }
+
+            let response = inner.call(req).await.map_err(Into::into)?;
+            if let Some(cached) = cached {
+                if response.status() == StatusCode::NOT_MODIFIED {
+                    return Ok(from_cache(cached, response.headers()));
+                }
+            }
+            if response.status() != StatusCode::OK
+                || !is_json(response.headers())
+                || is_no_store(response.headers())
+            {
+                return Ok(response);
+            }
+            let etag = match response.headers().get(ETAG) {
+                Some(etag) => etag.clone(),
+                None => return Ok(response),
+            };
+
+            let (parts, body) = response.into_parts();
+            let body = match buffer(body, MAX_BODY_SIZE).await? {
+                Ok(body) => body,
+                Err(body) => return Ok(Response::from_parts(parts, body)),
+            };
+            let cached = CachedResponse {
+                etag,
+                headers: parts.headers.clone(),
+                body: body.clone(),
+            };
+            storage.put(&key, cached).await;
+            Ok(Response::from_parts(parts, full(body)))
+        }
+        .boxed()
+    }
+}
+
+/// Reads `body` into memory if it is at most `limit` bytes long. Otherwise
+/// returns a body that sends what was read and then the rest of `body`.
+async fn buffer(
+    mut body: BoxBody<Bytes, crate::Error>,
+    limit: usize,
+) -> Result<Result<Bytes, BoxBody<Bytes, crate::Error>>, crate::Error> {
+    let mut buffered = BytesMut::new();
+    while let Some(frame) = body.frame().await.transpose()? {
+        // Trailers aren't cached, GitHub doesn't send any.
+        if let Ok(data) = frame.into_data() {
+            buffered.extend_from_slice(&data);
+        }
+        if buffered.len() > limit {
+            let read = stream::iter(Some(Ok(Frame::data(buffered.freeze()))));
+            let body = StreamBody::new(read.chain(BodyStream::new(body)));
+            return Ok(Err(BodyExt::boxed(body)));
+        }
+    }
+    Ok(Ok(buffered.freeze()))
+}
+
+fn full(body: Bytes) -> BoxBody<Bytes, crate::Error> {
+    Full::new(body).map_err(|never| match never {}).boxed()
+}
+
+/// Turns a `304 Not Modified` into the cached response, updated with the
+/// headers of the `304`, which carry e.g. the current rate limit.
+fn from_cache(
+    cached: CachedResponse,
+    not_modified: &HeaderMap,
+) -> Response<BoxBody<Bytes, crate::Error>> {
+    let mut response = Response::new(full(cached.body));
+    let headers = response.headers_mut();
+    *headers = cached.headers;
+    let is_fresh = |name: &HeaderName| *name != CONTENT_LENGTH && *name != TRANSFER_ENCODING;
+    for name in not_modified.keys().filter(|name| is_fresh(name)) {
+        headers.remove(name);
+    }
+    for (name, value) in not_modified.iter().filter(|(name, _)| is_fresh(name)) {
+        headers.append(name, value.clone());
+    }
+    response
+}
+
+/// Whether the body is JSON, e.g. `application/json` or a GitHub media type
+/// such as `application/vnd.github+json`.
+fn is_json(headers: &HeaderMap) -> bool {
+    headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .map(|media_type| {
+            let media_type = media_type.trim().to_ascii_lowercase();
+            media_type == "application/json" || media_type.ends_with("+json")
+        })
+        .unwrap_or(false)
+}
+
+fn is_no_store(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(CACHE_CONTROL)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|directive| directive.trim().eq_ignore_ascii_case("no-store"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(uri: &'static str) -> CacheKey {
+        CacheKey {
+            uri: Uri::from_static(uri),
+            accept: None,
+            authorization: None,
+        }
+    }
+
+    fn response(body: &'static str) -> CachedResponse {
+        CachedResponse {
+            etag: HeaderValue::from_static("\"abc\""),
+            headers: HeaderMap::new(),
+            body: Bytes::from_static(body.as_bytes()),
+        }
+    }
+
+    #[tokio::test]
+    async fn evicts_least_recently_used() {
+        // Room for two responses of `"abc"` and one byte each.
+        let cache = InMemoryCache::new(12);
+        let (a, b, c) = (key("/a"), key("/b"), key("/c"));
+        cache.put(&a, response("a")).await;
+        cache.put(&b, response("b")).await;
+        cache.get(&a).await;
+        cache.put(&c, response("c")).await;
+
+        assert!(cache.get(&a).await.is_some());
+        assert!(cache.get(&b).await.is_none());
+        assert!(cache.get(&c).await.is_some());
+    }
+
+    #[tokio::test]
+    async fn skips_responses_larger_than_the_cache() {
+        let cache = InMemoryCache::new(12);
+        let (a, b) = (key("/a"), key("/b"));
+        cache.put(&a, response("a")).await;
+        cache.put(&b, response("too large to fit")).await;
+
+        assert!(cache.get(&a).await.is_some());
+        assert!(cache.get(&b).await.is_none());
+    }
+
+    #[test]
+    fn keys_on_media_type_and_credentials() {
+        let request = |accept: &'static str, token: &'static str| {
+            Request::get("/repos/owner/repo/pulls/1")
+                .header(ACCEPT, accept)
+                .header(AUTHORIZATION, token)
+                .body(())
+                .unwrap()
+        };
+        let json = CacheKey::new(&request("application/json", "Bearer a"));
+
+        assert_eq!(
+            json,
+            CacheKey::new(&request("application/json", "Bearer a"))
+        );
+        assert_ne!(
+            json,
+            CacheKey::new(&request("application/vnd.github.v3.diff", "Bearer a"))
+        );
+        assert_ne!(
+            json,
+            CacheKey::new(&request("application/json", "Bearer b"))
+        );
+    }
+
+    #[tokio::test]
+    async fn passes_large_bodies_through() {
+        let body = full(Bytes::from_static(b"0123456789"));
+
+        let body = buffer(body, 4).await.unwrap().unwrap_err();
+
+        assert_eq!(body.collect().await.unwrap().to_bytes(), "0123456789");
+    }
+
+    #[test]
+    fn detects_json() {
+        let mut headers = HeaderMap::new();
+        assert!(!is_json(&headers));
+        headers.insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static("application/json; charset=utf-8"),
+        );
+        assert!(is_json(&headers));
+        headers.insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static("application/vnd.github.v3.diff"),
+        );
+        assert!(!is_json(&headers));
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/zip"));
+        assert!(!is_json(&headers));
+    }
+
+    #[test]
+    fn detects_no_store() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            CACHE_CONTROL,
+            HeaderValue::from_static("private, max-age=60"),
+        );
+        assert!(!is_no_store(&headers));
+        headers.append(CACHE_CONTROL, HeaderValue::from_static("No-Store"));
+        assert!(is_no_store(&headers));
+    }
+
+    #[test]
+    fn updates_cached_headers() {
+        let mut cached = response("{}");
+        cached
+            .headers
+            .insert("x-ratelimit-remaining", HeaderValue::from_static("10"));
+        cached
+            .headers
+            .insert(CONTENT_LENGTH, HeaderValue::from_static("2"));
+        let mut not_modified = HeaderMap::new();
+        not_modified.insert("x-ratelimit-remaining", HeaderValue::from_static("9"));
+        not_modified.insert(CONTENT_LENGTH, HeaderValue::from_static("0"));
+
+        let response = from_cache(cached, &not_modified);
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["x-ratelimit-remaining"], "9");
+        assert_eq!(response.headers()[CONTENT_LENGTH], "2");
+    }
+}
@@ -4,7 +4,10 @@ use http::{header::HeaderName, request::Request, HeaderValue};
 use tower::{Layer, Service};
 
 #[derive(Clone)]
-/// Layer that adds a static set of extra headers to each request
+/// Layer that adds a static set of extra headers to each request.
+///
+/// Headers already set on the request take precedence: a static header is
+/// left out when the request carries any value under the same name.
 pub struct ExtraHeadersLayer {
     pub(crate) headers: Arc<Vec<(HeaderName, HeaderValue)>>,
 }
@@ -49,7 +52,15 @@ where
     }
 
     fn call(&mut self, mut req: Request<ReqBody>) -> Self::Future {
-        req.headers_mut().extend(self.headers.iter().cloned());
+        // Decide up front, so that a static header with several values (e.g.
+        // one `Accept` per preview) is added in full.
+        let defaults: Vec<_> = self
+            .headers
+            .iter()
+            .filter(|(name, _)| !req.headers().contains_key(name))
+            .cloned()
+            .collect();
+        req.headers_mut().extend(defaults);
         self.inner.call(req)
     }
 }
@@ -1,21 +1,89 @@
-use futures_util::future;
-use http::{Request, Response};
-use hyper_util::client::legacy::Error;
+use std::time::Duration;
+
+use futures_util::future::{self, BoxFuture, FutureExt};
+use http::{header::RETRY_AFTER, Method, Request, Response, StatusCode};
 use tower::retry::Policy;
 
+use crate::OctoBody;
+
+/// How failed requests are retried, set with
+/// [`OctocrabBuilder::add_retry_config`](crate::OctocrabBuilder::add_retry_config).
 #[derive(Clone)]
 pub enum RetryConfig {
+    /// Never retry a request.
     None,
+    /// Immediately retry any request up to the given number of times on
+    /// connection errors, server errors and `429 Too Many Requests`.
     Simple(usize),
+    /// Retry with exponential backoff, see [`BackoffConfig`].
+    Backoff(BackoffConfig),
+}
+
+impl From<BackoffConfig> for RetryConfig {
+    fn from(config: BackoffConfig) -> Self {
+        RetryConfig::Backoff(config)
+    }
+}
+
+/// Retries with exponential backoff on connection errors, server errors,
+/// `429 Too Many Requests` and `403 Forbidden` responses carrying a
+/// `Retry-After` header, as GitHub sends for secondary rate limits.
+///
+/// The delay starts at `base_delay` and doubles on every attempt, but never
+/// exceeds `max_delay`. A `Retry-After` header takes precedence over the
+/// computed delay, still capped at `max_delay`. Only `GET` and `HEAD`
+/// requests are retried unless `retry_non_idempotent` is set.
+/// ```no_run
+/// # fn run() -> octocrab::Result<()> {
+/// use std::time::Duration;
+/// use octocrab::service::middleware::retry::BackoffConfig;
+///
+/// let mut builder = octocrab::Octocrab::builder();
+/// builder.add_retry_config(BackoffConfig {
+///     max_retries: 5,
+///     max_delay: Duration::from_secs(120),
+///     ..BackoffConfig::default()
+/// });
+/// let octocrab = builder.build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct BackoffConfig {
+    pub max_retries: usize,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for BackoffConfig {
+    /// Three retries, waiting from one second up to a minute.
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+fn is_idempotent(method: &Method) -> bool {
+    method == Method::GET || method == Method::HEAD
+}
+
+/// Parses the `Retry-After` header of a response, in either of its forms.
+fn retry_after<B>(response: &Response<B>) -> Option<Duration> {
+    crate::service::parse_retry_after(response.headers().get(RETRY_AFTER)?)
 }
 
-impl<B> Policy<Request<String>, Response<B>, Error> for RetryConfig {
-    type Future = futures_util::future::Ready<Self>;
+impl<B, E> Policy<Request<OctoBody>, Response<B>, E> for RetryConfig {
+    type Future = BoxFuture<'static, Self>;
 
     fn retry(
         &self,
-        _req: &Request<String>,
-        result: Result<&Response<B>, &Error>,
+        req: &Request<OctoBody>,
+        result: Result<&Response<B>, &E>,
     ) -> Option<Self::Future> {
         match self {
             RetryConfig::None => None,
@@ -23,7 +91,7 @@ impl<B> Policy<Request<String>, Response<B>, Error> for RetryConfig {
                 Ok(response) => {
                     if response.status().is_server_error() || response.status() == 429 {
                         if *count > 0 {
-                            Some(future::ready(RetryConfig::Simple(count - 1)))
+                            Some(future::ready(RetryConfig::Simple(count - 1)).boxed())
                         } else {
                             None
                         }
@@ -33,16 +101,57 @@ impl<B> Policy<Request<String>, Response<B>, Error> for RetryConfig {
                 }
                 Err(_) => {
                     if *count > 0 {
-                        Some(future::ready(RetryConfig::Simple(count - 1)))
+                        Some(future::ready(RetryConfig::Simple(count - 1)).boxed())
                     } else {
                         None
                     }
                 }
             },
+            RetryConfig::Backoff(BackoffConfig {
+                max_retries,
+                base_delay,
+                max_delay,
+                retry_non_idempotent,
+            }) => {
+                if *max_retries == 0 || !(*retry_non_idempotent || is_idempotent(req.method())) {
+                    return None;
+                }
+
+                let delay = match result {
+                    Ok(response) => {
+                        let status = response.status();
+                        let retry_after = retry_after(response);
+                        let retryable = status.is_server_error()
+                            || status == StatusCode::TOO_MANY_REQUESTS
+                            || (status == StatusCode::FORBIDDEN && retry_after.is_some());
+                        if !retryable {
+                            return None;
+                        }
+                        retry_after.unwrap_or(*base_delay)
+                    }
+                    Err(_) => *base_delay,
+                }
+                .min(*max_delay);
+
+                let next = RetryConfig::Backoff(BackoffConfig {
+                    max_retries: max_retries - 1,
+                    base_delay: base_delay.saturating_mul(2).min(*max_delay),
+                    max_delay: *max_delay,
+                    retry_non_idempotent: *retry_non_idempotent,
+                });
+
+                Some(
+                    async move {
+                        tokio::time::sleep(delay).await;
+                        next
+                    }
+                    .boxed(),
+                )
+            }
         }
     }
 
-    fn clone_request(&self, req: &Request<String>) -> Option<Request<String>> {
+    fn clone_request(&self, req: &Request<OctoBody>) -> Option<Request<OctoBody>> {
         match self {
             RetryConfig::None => None,
             _ => {
@@ -55,7 +164,8 @@ impl<B> Policy<Request<String>, Response<B>, Error> for RetryConfig {
                     new_req = new_req.header(name, value);
                 }
 
-                let body = req.body().clone();
+                // Streamed bodies can only be sent once
+                let body = req.body().try_clone()?;
                 let new_req = new_req.body(body).expect(
                     "This should never panic, as we are cloning a components from existing request",
                 );
@@ -1 +1,76 @@
+use std::time::Duration;
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use http::HeaderValue;
+
 pub mod middleware;
+
+/// Parses a `Retry-After` header, which is either a number of seconds or an
+/// HTTP-date. A date is turned into the time left until it from now, and a
+/// date in the past into a zero duration. Returns `None` for any other
+/// value.
+pub fn parse_retry_after(value: &HeaderValue) -> Option<Duration> {
+    parse_retry_after_at(value, Utc::now())
+}
+
+fn parse_retry_after_at(value: &HeaderValue, now: DateTime<Utc>) -> Option<Duration> {
+    let value = value.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = parse_http_date(value)?;
+    Some((date - now).to_std().unwrap_or(Duration::ZERO))
+}
+
+/// Parses the preferred IMF-fixdate form of an HTTP-date, along with the
+/// obsolete RFC 850 and asctime forms that recipients must still accept.
+fn parse_http_date(value: &str) -> Option<DateTime<Utc>> {
+    if let Ok(date) = DateTime::parse_from_rfc2822(value) {
+        return Some(date.with_timezone(&Utc));
+    }
+    ["%A, %d-%b-%y %H:%M:%S GMT", "%a %b %e %H:%M:%S %Y"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        .map(|date| date.and_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2015-10-21T07:28:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn parse(value: &'static str) -> Option<Duration> {
+        parse_retry_after_at(&HeaderValue::from_static(value), now())
+    }
+
+    #[test]
+    fn parse_seconds() {
+        assert_eq!(parse("120"), Some(Duration::from_secs(120)));
+        assert_eq!(parse(" 0 "), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn parse_http_dates() {
+        let expected = Some(Duration::from_secs(90));
+        assert_eq!(parse("Wed, 21 Oct 2015 07:29:30 GMT"), expected);
+        assert_eq!(parse("Wednesday, 21-Oct-15 07:29:30 GMT"), expected);
+        assert_eq!(parse("Wed Oct 21 07:29:30 2015"), expected);
+    }
+
+    #[test]
+    fn parse_past_http_date_as_zero() {
+        assert_eq!(parse("Wed, 21 Oct 2015 07:00:00 GMT"), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn parse_garbage_as_none() {
+        assert_eq!(parse("soon"), None);
+        assert_eq!(parse("-5"), None);
+        assert_eq!(parse(""), None);
+    }
+}
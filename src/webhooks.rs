@@ -0,0 +1,105 @@
+//! Helpers for receiving GitHub webhooks.
+//!
+//! Requires the `webhook-signature` feature.
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// The prefix GitHub puts in front of the hex digest in the
+/// `X-Hub-Signature-256` header.
+const SIGNATURE_PREFIX: &str = "sha256=";
+
+/// Verifies that `body` was signed by GitHub with the webhook `secret`.
+///
+/// `signature_header` is the value of the `X-Hub-Signature-256` header sent
+/// with the delivery, e.g. `sha256=757107ea…`. The HMAC-SHA256 of `body` is
+/// compared against it in constant time. A header that is missing the
+/// `sha256=` prefix or isn't valid hex is treated as a mismatch.
+///
+/// ```
+/// let secret = "It's a Secret to Everybody";
+/// let signature = "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+///
+/// assert!(octocrab::webhooks::verify_signature(secret, b"Hello, World!", signature));
+/// assert!(!octocrab::webhooks::verify_signature(secret, b"Goodbye, World!", signature));
+/// ```
+pub fn verify_signature(
+    secret: impl AsRef<[u8]>,
+    body: impl AsRef<[u8]>,
+    signature_header: &str,
+) -> bool {
+    let Some(signature) = signature_header
+        .trim()
+        .strip_prefix(SIGNATURE_PREFIX)
+        .and_then(decode_hex)
+    else {
+        return false;
+    };
+
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_ref()).expect("HMAC accepts keys of any length");
+    mac.update(body.as_ref());
+    mac.verify_slice(&signature).is_ok()
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    let pairs = hex.as_bytes().chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        return None;
+    }
+
+    pairs
+        .map(|pair| {
+            let high = (pair[0] as char).to_digit(16)?;
+            let low = (pair[1] as char).to_digit(16)?;
+            Some((high * 16 + low) as u8)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::verify_signature;
+
+    // The example from GitHub's "Validating webhook deliveries" guide.
+    const SECRET: &str = "It's a Secret to Everybody";
+    const BODY: &[u8] = b"Hello, World!";
+    const SIGNATURE: &str =
+        "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+
+    #[test]
+    fn accepts_valid_signature() {
+        assert!(verify_signature(SECRET, BODY, SIGNATURE));
+        assert!(verify_signature(
+            SECRET,
+            BODY,
+            &SIGNATURE.to_uppercase().replace("SHA256", "sha256")
+        ));
+    }
+
+    #[test]
+    fn rejects_wrong_secret_or_body() {
+        assert!(!verify_signature("not the secret", BODY, SIGNATURE));
+        assert!(!verify_signature(SECRET, b"Hello, World?", SIGNATURE));
+    }
+
+    #[test]
+    fn rejects_malformed_header() {
+        let digest = SIGNATURE.strip_prefix("sha256=").unwrap();
+
+        assert!(!verify_signature(SECRET, BODY, ""));
+        assert!(!verify_signature(SECRET, BODY, digest));
+        assert!(!verify_signature(SECRET, BODY, &format!("sha1={digest}")));
+        assert!(!verify_signature(
+            SECRET,
+            BODY,
+            &SIGNATURE[..SIGNATURE.len() - 1]
+        ));
+        assert!(!verify_signature(
+            SECRET,
+            BODY,
+            &SIGNATURE[..SIGNATURE.len() - 2]
+        ));
+        assert!(!verify_signature(SECRET, BODY, "sha256=zz"));
+        assert!(!verify_signature(SECRET, BODY, "sha256=ü1"));
+    }
+}
@@ -0,0 +1,93 @@
+use octocrab::{params::actions::ArchiveFormat, Octocrab};
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const ZIP: &[u8] = &[0x50, 0x4b, 0x03, 0x04, 0x14, 0x00];
+
+/// Mounts `api_path` on the API server, redirecting to a zip on a separate
+/// storage server, which stands in for a third-party host.
+async fn setup_redirect_api(api_path: String) -> (MockServer, MockServer) {
+    let api_server = MockServer::start().await;
+    let storage_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/logs.zip"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(ZIP))
+        .expect(1)
+        .mount(&storage_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(api_path))
+        .and(header("Authorization", "Bearer secret"))
+        .respond_with(
+            ResponseTemplate::new(302)
+                .append_header("Location", format!("{}/logs.zip", storage_server.uri())),
+        )
+        .expect(1)
+        .mount(&api_server)
+        .await;
+
+    (api_server, storage_server)
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder()
+        .base_uri(uri)
+        .unwrap()
+        .personal_token("secret".to_string())
+        .build()
+        .unwrap()
+}
+
+async fn assert_no_credentials_sent(storage_server: &MockServer) {
+    let requests = storage_server.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 1);
+    assert!(
+        !requests[0].headers.contains_key("Authorization"),
+        "credentials leaked to the storage host"
+    );
+}
+
+#[tokio::test]
+async fn should_download_workflow_run_logs() {
+    let (api_server, storage_server) =
+        setup_redirect_api(format!("/repos/{OWNER}/{REPO}/actions/runs/1234/logs")).await;
+    let client = setup_octocrab(&api_server.uri());
+
+    let result = client
+        .actions()
+        .download_workflow_run_logs(OWNER, REPO, 1234u64.into())
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().as_ref(), ZIP);
+    assert_no_credentials_sent(&storage_server).await;
+}
+
+#[tokio::test]
+async fn should_download_artifact() {
+    let (api_server, storage_server) =
+        setup_redirect_api(format!("/repos/{OWNER}/{REPO}/actions/artifacts/42/zip")).await;
+    let client = setup_octocrab(&api_server.uri());
+
+    let result = client
+        .actions()
+        .download_artifact(OWNER, REPO, 42u64.into(), ArchiveFormat::Zip)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().as_ref(), ZIP);
+    assert_no_credentials_sent(&storage_server).await;
+}
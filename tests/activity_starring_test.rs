@@ -0,0 +1,141 @@
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+async fn setup_starred_api(http_method: &str, template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method(http_method))
+        .and(path(format!("/user/starred/{OWNER}/{REPO}")))
+        .respond_with(template)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    mock_server
+}
+
+#[tokio::test]
+async fn should_report_starred_repo() {
+    let mock_server = setup_starred_api("GET", ResponseTemplate::new(204)).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.activity().is_starred(OWNER, REPO).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert!(result.unwrap());
+}
+
+#[tokio::test]
+async fn should_report_unstarred_repo() {
+    let mock_server = setup_starred_api("GET", ResponseTemplate::new(404)).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.activity().is_starred(OWNER, REPO).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert!(!result.unwrap());
+}
+
+#[tokio::test]
+async fn should_star_and_unstar_repo() {
+    let mock_server = MockServer::start().await;
+    for http_method in ["PUT", "DELETE"] {
+        Mock::given(method(http_method))
+            .and(path(format!("/user/starred/{OWNER}/{REPO}")))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+    let client = setup_octocrab(&mock_server.uri());
+
+    let star = client.activity().star(OWNER, REPO).await;
+    assert!(
+        star.is_ok(),
+        "expected successful result, got error: {:#?}",
+        star
+    );
+
+    let unstar = client.activity().unstar(OWNER, REPO).await;
+    assert!(
+        unstar.is_ok(),
+        "expected successful result, got error: {:#?}",
+        unstar
+    );
+}
+
+#[tokio::test]
+async fn should_fail_to_star_missing_repo() {
+    let template = ResponseTemplate::new(404).set_body_json(json!({
+        "message": "Not Found",
+        "documentation_url": "https://docs.github.com/rest/activity/starring#star-a-repository-for-the-authenticated-user"
+    }));
+    let mock_server = setup_starred_api("PUT", template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    match client.activity().star(OWNER, REPO).await {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, http::StatusCode::NOT_FOUND);
+        }
+        result => panic!("expected a GitHub error, got: {:#?}", result),
+    }
+}
+
+#[tokio::test]
+async fn should_set_and_delete_subscription() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/subscription")))
+        .and(body_json(json!({ "subscribed": true, "ignored": false })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "subscribed": true,
+            "ignored": false,
+            "reason": null,
+            "created_at": "2012-10-06T21:34:12Z",
+            "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/subscription"),
+            "repository_url": format!("https://api.github.com/repos/{OWNER}/{REPO}")
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/subscription")))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+    let repo = client.repos(OWNER, REPO);
+
+    let set = repo.set_subscription(true, false).await;
+    assert!(
+        set.is_ok(),
+        "expected successful result, got error: {:#?}",
+        set
+    );
+    assert!(set.unwrap().subscribed);
+
+    let delete = repo.delete_subscription().await;
+    assert!(
+        delete.is_ok(),
+        "expected successful result, got error: {:#?}",
+        delete
+    );
+}
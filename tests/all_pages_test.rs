@@ -0,0 +1,104 @@
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+
+fn label(id: u64, name: &str) -> serde_json::Value {
+    json!({
+        "id": id,
+        "node_id": "MDU6TGFiZWwyMDgwNDU5NDY=",
+        "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/labels/{name}"),
+        "name": name,
+        "description": null,
+        "color": "f29513",
+        "default": false
+    })
+}
+
+async fn setup_paginated_labels_api(second_page: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+    let labels_path = format!("/repos/{OWNER}/{REPO}/labels");
+    let next = format!("{}{labels_path}?page=2", mock_server.uri());
+
+    Mock::given(method("GET"))
+        .and(path(&labels_path))
+        .and(query_param("page", "2"))
+        .respond_with(second_page)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(&labels_path))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!([label(1, "bug"), label(2, "enhancement")]))
+                .append_header(
+                    "Link",
+                    format!(r#"<{next}>; rel="next", <{next}>; rel="last""#),
+                ),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_combine_all_pages() {
+    let second_page = ResponseTemplate::new(200).set_body_json(json!([label(3, "question")]));
+    let mock_server = setup_paginated_labels_api(second_page).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let page = client
+        .issues(OWNER, REPO)
+        .list_labels_for_repo()
+        .send()
+        .await
+        .unwrap();
+    let result = client.all_pages(page).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let labels = result.unwrap();
+    assert_eq!(labels.len(), 3);
+    assert_eq!(labels[2].name, "question");
+}
+
+#[tokio::test]
+async fn should_stop_on_rate_limit_error() {
+    let second_page = ResponseTemplate::new(403)
+        .append_header("x-ratelimit-remaining", "0")
+        .set_body_json(json!({
+            "message": "API rate limit exceeded for 127.0.0.1.",
+            "documentation_url": "https://docs.github.com/rest/overview/resources-in-the-rest-api#rate-limiting"
+        }));
+    let mock_server = setup_paginated_labels_api(second_page).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let page = client
+        .issues(OWNER, REPO)
+        .list_labels_for_repo()
+        .send()
+        .await
+        .unwrap();
+
+    match client.all_pages(page).await {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, http::StatusCode::FORBIDDEN);
+        }
+        result => panic!("expected a GitHub error, got: {:#?}", result),
+    }
+}
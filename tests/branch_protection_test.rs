@@ -0,0 +1,132 @@
+use octocrab::{models::repos::branch_protection::RequiredStatusChecks, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const BRANCH: &str = "main";
+
+fn protection_path() -> String {
+    format!("/repos/{OWNER}/{REPO}/branches/{BRANCH}/protection")
+}
+
+fn protection() -> serde_json::Value {
+    let url = format!("https://api.github.com{}", protection_path());
+    json!({
+        "url": url,
+        "required_status_checks": {
+            "url": format!("{url}/required_status_checks"),
+            "strict": true,
+            "contexts": ["ci"],
+            "contexts_url": format!("{url}/required_status_checks/contexts"),
+            "checks": [{ "context": "ci", "app_id": null }]
+        },
+        "enforce_admins": {
+            "url": format!("{url}/enforce_admins"),
+            "enabled": false
+        },
+        "required_linear_history": { "enabled": true },
+        "allow_force_pushes": { "enabled": false },
+        "allow_deletions": { "enabled": false }
+    })
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_get_branch_protection() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(protection_path()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(protection()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .get_branch_protection(BRANCH)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let protection = result.unwrap();
+    let checks = protection.required_status_checks.unwrap();
+    assert!(checks.strict);
+    assert_eq!(checks.checks[0].context, "ci");
+    assert!(!protection.enforce_admins.unwrap().enabled);
+    assert!(protection.required_linear_history.unwrap().enabled);
+    assert!(protection.required_pull_request_reviews.is_none());
+    assert!(protection.restrictions.is_none());
+}
+
+#[tokio::test]
+async fn should_update_branch_protection_with_nulls() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path(protection_path()))
+        .and(body_json(json!({
+            "required_status_checks": {
+                "strict": true,
+                "contexts": ["ci"],
+                "checks": []
+            },
+            "enforce_admins": null,
+            "required_pull_request_reviews": null,
+            "restrictions": null
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(protection()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .update_branch_protection(BRANCH)
+        .required_status_checks(RequiredStatusChecks {
+            strict: true,
+            contexts: vec!["ci".to_string()],
+            ..Default::default()
+        })
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_delete_branch_protection() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(protection_path()))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .delete_branch_protection(BRANCH)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
@@ -0,0 +1,146 @@
+use octocrab::models::Repository;
+use octocrab::Octocrab;
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ROUTE: &str = "/repos/org/some-repo";
+const ETAG: &str = "\"644b5b0155e6404a9cc4bd9d8b1ae730\"";
+
+fn repository() -> Repository {
+    serde_json::from_str(include_str!("resources/repository.json")).unwrap()
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder()
+        .base_uri(uri)
+        .unwrap()
+        .with_cache(1024 * 1024)
+        .build()
+        .unwrap()
+}
+
+async fn mount_not_modified(mock_server: &MockServer, times: u64) {
+    Mock::given(method("GET"))
+        .and(path(ROUTE))
+        .and(header("If-None-Match", ETAG))
+        .respond_with(ResponseTemplate::new(304).insert_header("ETag", ETAG))
+        .with_priority(1)
+        .expect(times)
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn should_answer_not_modified_from_cache() {
+    let mock_server = MockServer::start().await;
+    mount_not_modified(&mock_server, 2).await;
+    Mock::given(method("GET"))
+        .and(path(ROUTE))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("ETag", ETAG)
+                .insert_header("Cache-Control", "private, max-age=60, s-maxage=60")
+                .set_body_json(repository()),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    for _ in 0..3 {
+        let result = client.repos("org", "some-repo").get().await;
+
+        assert!(
+            result.is_ok(),
+            "expected successful result, got error: {:#?}",
+            result
+        );
+        assert_eq!(result.unwrap().id, repository().id);
+    }
+}
+
+#[tokio::test]
+async fn should_not_cache_no_store_responses() {
+    let mock_server = MockServer::start().await;
+    mount_not_modified(&mock_server, 0).await;
+    Mock::given(method("GET"))
+        .and(path(ROUTE))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("ETag", ETAG)
+                .insert_header("Cache-Control", "no-store")
+                .set_body_json(repository()),
+        )
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    for _ in 0..2 {
+        let result = client.repos("org", "some-repo").get().await;
+
+        assert!(
+            result.is_ok(),
+            "expected successful result, got error: {:#?}",
+            result
+        );
+    }
+}
+
+#[tokio::test]
+async fn should_leave_etagged_requests_to_the_caller() {
+    let mock_server = MockServer::start().await;
+    mount_not_modified(&mock_server, 1).await;
+    Mock::given(method("GET"))
+        .and(path(ROUTE))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("ETag", ETAG)
+                .set_body_json(repository()),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let first = client
+        .get_etagged::<Repository, _, ()>(ROUTE, None, None)
+        .await
+        .unwrap();
+    let second = client
+        .get_etagged::<Repository, _, ()>(ROUTE, None, first.etag)
+        .await
+        .unwrap();
+
+    assert!(first.value.is_some());
+    assert!(second.value.is_none());
+}
+
+#[tokio::test]
+async fn should_not_cache_downloads() {
+    let mock_server = MockServer::start().await;
+    mount_not_modified(&mock_server, 0).await;
+    Mock::given(method("GET"))
+        .and(path(ROUTE))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("ETag", ETAG)
+                .set_body_raw(b"PK\x03\x04".to_vec(), "application/zip"),
+        )
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    for _ in 0..2 {
+        let result = client._get(ROUTE).await;
+
+        assert!(
+            result.is_ok(),
+            "expected successful result, got error: {:#?}",
+            result
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use octocrab::{
+    models::CheckRunId,
+    params::checks::{
+        CheckRunOutput, CheckRunOutputAnnotation, CheckRunOutputAnnotationLevel,
+        CheckRunOutputImage, CheckRunStatus,
+    },
+    Octocrab,
+};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_partial_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const HEAD_SHA: &str = "ce587453ced02b1526dfb4cb910479d431683101";
+
+fn check_run() -> serde_json::Value {
+    serde_json::from_str(include_str!("resources/check_run.json")).unwrap()
+}
+
+fn output(annotations: u32) -> CheckRunOutput {
+    CheckRunOutput {
+        title: "Lints".to_string(),
+        summary: "Clippy found some problems".to_string(),
+        text: Some("Run `cargo clippy --fix` to apply the suggestions".to_string()),
+        annotations: (1..=annotations)
+            .map(|line| CheckRunOutputAnnotation {
+                path: "src/lib.rs".to_string(),
+                start_line: line,
+                end_line: line,
+                start_column: None,
+                end_column: None,
+                annotation_level: CheckRunOutputAnnotationLevel::Warning,
+                message: "needless borrow".to_string(),
+                title: None,
+                raw_details: None,
+            })
+            .collect(),
+        images: vec![CheckRunOutputImage {
+            alt: "Lint trend".to_string(),
+            image_url: "https://example.com/lints.png".to_string(),
+            caption: None,
+        }],
+    }
+}
+
+/// The method, number of annotations and number of images of each request.
+async fn annotation_counts(mock_server: &MockServer) -> Vec<(String, usize, usize)> {
+    mock_server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .map(|request| {
+            let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+            let annotations = body["output"]["annotations"].as_array().unwrap().len();
+            let images = body["output"]["images"].as_array().map_or(0, Vec::len);
+            (request.method.to_string(), annotations, images)
+        })
+        .collect()
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_create_check_run_and_chunk_annotations() {
+    let check_run = check_run();
+    let check_run_id = check_run["id"].as_u64().unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/check-runs")))
+        .and(body_partial_json(json!({
+            "name": "clippy",
+            "head_sha": HEAD_SHA,
+            "status": "in_progress",
+            "details_url": "https://example.com/clippy"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(&check_run))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/check-runs/{check_run_id}"
+        )))
+        .and(body_partial_json(json!({
+            "output": {
+                "title": "Lints",
+                "summary": "Clippy found some problems",
+                "text": "Run `cargo clippy --fix` to apply the suggestions"
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&check_run))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .checks(OWNER, REPO)
+        .create_check_run("clippy", HEAD_SHA)
+        .status(CheckRunStatus::InProgress)
+        .details_url("https://example.com/clippy")
+        .output(output(120))
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(
+        annotation_counts(&mock_server).await,
+        vec![
+            ("POST".to_string(), 50, 1),
+            ("PATCH".to_string(), 50, 0),
+            ("PATCH".to_string(), 20, 0)
+        ]
+    );
+}
+
+#[tokio::test]
+async fn should_update_check_run_in_one_request_when_annotations_fit() {
+    let check_run = check_run();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PATCH"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/check-runs/42")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&check_run))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .checks(OWNER, REPO)
+        .update_check_run(CheckRunId(42))
+        .output(output(50))
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(
+        annotation_counts(&mock_server).await,
+        vec![("PATCH".to_string(), 50, 1)]
+    );
+}
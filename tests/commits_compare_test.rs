@@ -0,0 +1,133 @@
+use octocrab::{models::commits::GithubCommitStatus, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+
+fn commit(sha: &str) -> serde_json::Value {
+    let url = format!("https://api.github.com/repos/{OWNER}/{REPO}/commits/{sha}");
+    json!({
+        "url": url,
+        "sha": sha,
+        "node_id": "MDY6Q29tbWl0NmRjYjA5YjViNTc4NzVmMzM0ZjYxYWViZWQ2OTVlMmU0MTkzZGI1ZQ==",
+        "html_url": format!("https://github.com/{OWNER}/{REPO}/commit/{sha}"),
+        "comments_url": format!("{url}/comments"),
+        "commit": {
+            "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/git/commits/{sha}"),
+            "author": { "name": "Monalisa Octocat", "email": "support@github.com", "date": "2011-04-14T16:00:49Z" },
+            "committer": { "name": "Monalisa Octocat", "email": "support@github.com", "date": "2011-04-14T16:00:49Z" },
+            "message": "Fix all the bugs",
+            "tree": { "url": "https://api.github.com/repos/octocat/Hello-World/tree/6dcb09b5b57875f334f61aebed695e2e4193db5e", "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e" },
+            "comment_count": 0,
+            "verification": null
+        },
+        "author": null,
+        "committer": null,
+        "parents": []
+    })
+}
+
+fn comparison(status: &str) -> serde_json::Value {
+    json!({
+        "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/compare/v1.0.0...v1.1.0"),
+        "html_url": format!("https://github.com/{OWNER}/{REPO}/compare/v1.0.0...v1.1.0"),
+        "permalink_url": format!("https://github.com/{OWNER}/{REPO}/compare/{OWNER}:bbcd538...{OWNER}:0328041"),
+        "diff_url": format!("https://github.com/{OWNER}/{REPO}/compare/v1.0.0...v1.1.0.diff"),
+        "patch_url": format!("https://github.com/{OWNER}/{REPO}/compare/v1.0.0...v1.1.0.patch"),
+        "base_commit": commit("bbcd538c8e72b8c175046e27cc8f907076331401"),
+        "merge_base_commit": commit("bbcd538c8e72b8c175046e27cc8f907076331401"),
+        "status": status,
+        "ahead_by": 2,
+        "behind_by": 0,
+        "total_commits": 2,
+        "commits": [
+            commit("6dcb09b5b57875f334f61aebed695e2e4193db5e"),
+            commit("0328041d1152db8ae77652d1618a02e57f745f17")
+        ],
+        "files": [{
+            "sha": "bbcd538c8e72b8c175046e27cc8f907076331401",
+            "filename": "CHANGELOG.md",
+            "status": "modified",
+            "additions": 10,
+            "deletions": 2,
+            "changes": 12,
+            "blob_url": format!("https://github.com/{OWNER}/{REPO}/blob/0328041/CHANGELOG.md"),
+            "raw_url": format!("https://github.com/{OWNER}/{REPO}/raw/0328041/CHANGELOG.md"),
+            "contents_url": format!("https://api.github.com/repos/{OWNER}/{REPO}/contents/CHANGELOG.md?ref=0328041"),
+            "patch": "@@ -1,2 +1,10 @@"
+        }]
+    })
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_compare_with_three_dots_and_page_commits() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/compare/v1.0.0...v1.1.0"
+        )))
+        .and(query_param("per_page", "100"))
+        .and(query_param("page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(comparison("ahead")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .commits(OWNER, REPO)
+        .compare("v1.0.0", "v1.1.0")
+        .per_page(100)
+        .page(1u32)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let comparison = result.unwrap();
+    assert!(matches!(comparison.status, GithubCommitStatus::Ahead));
+    assert_eq!(comparison.ahead_by, 2);
+    assert_eq!(comparison.total_commits, 2);
+    assert_eq!(comparison.commits.len(), 2);
+    assert_eq!(comparison.files.unwrap()[0].filename, "CHANGELOG.md");
+}
+
+#[tokio::test]
+async fn should_compare_with_two_dots() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/compare/main..feature")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(comparison("diverged")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .commits(OWNER, REPO)
+        .compare("main", "feature")
+        .two_dot(true)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert!(matches!(
+        result.unwrap().status,
+        GithubCommitStatus::Diverged
+    ));
+}
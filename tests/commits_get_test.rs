@@ -0,0 +1,112 @@
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const SHA: &str = "6dcb09b5b57875f334f61aebed695e2e4193db5e";
+
+fn parent(sha: &str) -> serde_json::Value {
+    json!({
+        "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/commits/{sha}"),
+        "sha": sha
+    })
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_get_merge_commit_with_stats_and_files() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/commits/{SHA}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/commits/{SHA}"),
+            "sha": SHA,
+            "node_id": "MDY6Q29tbWl0NmRjYjA5YjViNTc4NzVmMzM0ZjYxYWViZWQ2OTVlMmU0MTkzZGI1ZQ==",
+            "html_url": format!("https://github.com/{OWNER}/{REPO}/commit/{SHA}"),
+            "comments_url": format!("https://api.github.com/repos/{OWNER}/{REPO}/commits/{SHA}/comments"),
+            "commit": {
+                "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/git/commits/{SHA}"),
+                "author": { "name": "Monalisa Octocat", "email": "support@github.com", "date": "2011-04-14T16:00:49Z" },
+                "committer": { "name": "Monalisa Octocat", "email": "support@github.com", "date": "2011-04-14T16:00:49Z" },
+                "message": "Merge pull request #6 from feature",
+                "tree": {
+                    "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/tree/{SHA}"),
+                    "sha": SHA
+                },
+                "comment_count": 0
+            },
+            "author": null,
+            "committer": null,
+            "parents": [
+                parent("7638417db6d59f3c431d3e1f261cc637155684cd"),
+                parent("0328041d1152db8ae77652d1618a02e57f745f17")
+            ],
+            "stats": { "additions": 104, "deletions": 4, "total": 108 },
+            "files": [{
+                "sha": "bbcd538c8e72b8c175046e27cc8f907076331401",
+                "filename": "file1.txt",
+                "status": "added",
+                "additions": 103,
+                "deletions": 21,
+                "changes": 124,
+                "blob_url": format!("https://github.com/{OWNER}/{REPO}/blob/{SHA}/file1.txt"),
+                "raw_url": format!("https://github.com/{OWNER}/{REPO}/raw/{SHA}/file1.txt"),
+                "contents_url": format!("https://api.github.com/repos/{OWNER}/{REPO}/contents/file1.txt?ref={SHA}"),
+                "patch": "@@ -29,7 +29,7 @@"
+            }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.commits(OWNER, REPO).get(SHA).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let commit = result.unwrap();
+    assert_eq!(commit.parents.len(), 2);
+    assert_eq!(
+        commit.parents[1].sha.as_deref(),
+        Some("0328041d1152db8ae77652d1618a02e57f745f17")
+    );
+    let stats = commit.stats.unwrap();
+    assert_eq!(stats.additions, Some(104));
+    assert_eq!(stats.total, Some(108));
+    let files = commit.files.unwrap();
+    assert_eq!(files[0].filename, "file1.txt");
+    assert_eq!(files[0].patch.as_deref(), Some("@@ -29,7 +29,7 @@"));
+}
+
+#[tokio::test]
+async fn should_request_commit_diff_media_type() {
+    let diff = "diff --git a/file1.txt b/file1.txt\n";
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/commits/{SHA}")))
+        .and(header("Accept", "application/vnd.github.v3.diff"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(diff))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.commits(OWNER, REPO).get_diff(SHA).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap(), diff);
+}
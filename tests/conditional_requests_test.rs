@@ -0,0 +1,102 @@
+use octocrab::{
+    etag::{EntityTag, Etagged},
+    Octocrab,
+};
+use serde_json::json;
+use wiremock::{
+    matchers::{header, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ETAG: &str = "\"abcd\"";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_return_value_and_etag() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "name": "repo" }))
+                .insert_header("etag", ETAG),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result: octocrab::Result<Etagged<serde_json::Value>> = client
+        .get_etagged("/repos/owner/repo", None::<&()>, None)
+        .await;
+
+    match result {
+        Ok(Etagged {
+            etag: Some(etag),
+            value: Some(value),
+        }) => {
+            assert_eq!(etag, EntityTag::strong("abcd".to_string()));
+            assert_eq!(value["name"], "repo");
+        }
+        unexpected => panic!("expected a value and an etag, got {:#?}", unexpected),
+    }
+}
+
+#[tokio::test]
+async fn should_not_decode_not_modified_response() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo"))
+        .and(header("If-None-Match", ETAG))
+        .respond_with(ResponseTemplate::new(304).insert_header("etag", ETAG))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result: octocrab::Result<Etagged<serde_json::Value>> = client
+        .get_etagged(
+            "/repos/owner/repo",
+            None::<&()>,
+            Some(EntityTag::strong("abcd".to_string())),
+        )
+        .await;
+
+    match result {
+        Ok(Etagged {
+            etag: Some(etag),
+            value: None,
+        }) => assert_eq!(etag, EntityTag::strong("abcd".to_string())),
+        unexpected => panic!("expected no value and an etag, got {:#?}", unexpected),
+    }
+}
+
+#[tokio::test]
+async fn should_send_etag_and_paging_when_listing_artifacts() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/actions/runs/42/artifacts"))
+        .and(header("If-None-Match", ETAG))
+        .and(query_param("per_page", "10"))
+        .respond_with(ResponseTemplate::new(304).insert_header("etag", ETAG))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .actions()
+        .list_workflow_run_artifacts("owner", "repo", 42u64.into())
+        .per_page(10)
+        .etag(Some(EntityTag::strong("abcd".to_string())))
+        .send()
+        .await;
+
+    match result {
+        Ok(Etagged { value: None, .. }) => {}
+        unexpected => panic!("expected no page, got {:#?}", unexpected),
+    }
+}
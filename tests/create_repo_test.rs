@@ -0,0 +1,71 @@
+use octocrab::{models::Repository, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ORG: &str = "org";
+
+async fn setup_create_repo_api(route: &str) -> MockServer {
+    let repo: Repository = serde_json::from_str(include_str!("resources/repository.json")).unwrap();
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path(route))
+        .and(body_json(json!({
+            "name": "Hello-World",
+            "description": "This is your first repo!",
+            "private": true
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(repo))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_create_repo_for_authenticated_user() {
+    let mock_server = setup_create_repo_api("/user/repos").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .current()
+        .create_repo("Hello-World")
+        .description("This is your first repo!")
+        .private(true)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_create_repo_for_org() {
+    let mock_server = setup_create_repo_api(&format!("/orgs/{ORG}/repos")).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .orgs(ORG)
+        .create_repo("Hello-World")
+        .description("This is your first repo!")
+        .private(true)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
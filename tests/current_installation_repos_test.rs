@@ -0,0 +1,89 @@
+use octocrab::{
+    models::{AppId, InstallationId},
+    Octocrab,
+};
+use serde_json::json;
+use wiremock::{
+    matchers::{header, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const INSTALLATION_ID: u64 = 42;
+
+fn setup_app(uri: &str) -> Octocrab {
+    let key = jsonwebtoken::EncodingKey::from_rsa_pem(include_bytes!(
+        "resources/test_app_private_key.pem"
+    ))
+    .unwrap();
+    Octocrab::builder()
+        .base_uri(uri)
+        .unwrap()
+        .app(AppId(1), key)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn should_list_installation_repos() {
+    let repository: serde_json::Value =
+        serde_json::from_str(include_str!("resources/repository.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/app/installations/{INSTALLATION_ID}/access_tokens"
+        )))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "token": "ghs_installation",
+            "expires_at": "2100-01-01T00:00:00Z",
+            "permissions": {}
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/installation/repositories"))
+        .and(query_param("per_page", "100"))
+        .and(header("Authorization", "Bearer ghs_installation"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "total_count": 1,
+            "repositories": [repository]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_app(&mock_server.uri()).installation(InstallationId(INSTALLATION_ID));
+
+    let result = client
+        .current()
+        .list_installation_repos()
+        .per_page(100)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let page = result.unwrap();
+    assert_eq!(page.total_count, Some(1));
+    assert_eq!(page.items.len(), 1);
+}
+
+#[tokio::test]
+async fn should_reject_clients_not_authenticated_as_installation() {
+    let mock_server = MockServer::start().await;
+    let client = setup_app(&mock_server.uri());
+
+    let result = client.current().list_installation_repos().send().await;
+
+    match result {
+        Err(octocrab::Error::Other { source, .. }) => {
+            assert!(source
+                .to_string()
+                .contains("authenticated as an installation"));
+        }
+        result => panic!("expected an error, got: {:#?}", result),
+    }
+    assert!(mock_server.received_requests().await.unwrap().is_empty());
+}
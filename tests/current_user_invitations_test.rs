@@ -0,0 +1,110 @@
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const INVITATION_ID: u64 = 1296269;
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_accept_listed_invitation() {
+    let repository: serde_json::Value =
+        serde_json::from_str(include_str!("resources/repository.json")).unwrap();
+    let issue: serde_json::Value =
+        serde_json::from_str(include_str!("resources/issue.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/user/repository_invitations"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([{
+            "id": INVITATION_ID,
+            "node_id": "MDEwOkludml0YXRpb24x",
+            "repository": repository,
+            "invitee": issue["user"],
+            "inviter": issue["user"],
+            "permissions": "write",
+            "created_at": "2016-06-13T14:52:50-05:00",
+            "expired": false,
+            "url": "https://api.github.com/user/repository_invitations/1296269",
+            "html_url": "https://github.com/octocat/Hello-World/invitations"
+        }])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path(format!(
+            "/user/repository_invitations/{INVITATION_ID}"
+        )))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let invitations = client
+        .current()
+        .list_repository_invitations()
+        .send()
+        .await
+        .unwrap();
+    let result = client
+        .current()
+        .accept_invitation(invitations.items[0].id)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_decline_invitation() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!(
+            "/user/repository_invitations/{INVITATION_ID}"
+        )))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.current().decline_invitation(INVITATION_ID).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_return_error_for_expired_invitation() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PATCH"))
+        .and(path(format!("/user/repository_invitations/{INVITATION_ID}")))
+        .respond_with(ResponseTemplate::new(409).set_body_json(json!({
+            "message": "Conflict",
+            "documentation_url": "https://docs.github.com/rest/collaborators/invitations#accept-a-repository-invitation"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.current().accept_invitation(INVITATION_ID).await;
+
+    match result {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, 409);
+        }
+        result => panic!("expected a GitHub error, got: {:#?}", result),
+    }
+}
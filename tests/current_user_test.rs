@@ -0,0 +1,144 @@
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn user_profile() -> serde_json::Value {
+    json!({
+        "login": "octocat",
+        "id": 1,
+        "node_id": "MDQ6VXNlcjE=",
+        "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+        "gravatar_id": "",
+        "url": "https://api.github.com/users/octocat",
+        "html_url": "https://github.com/octocat",
+        "followers_url": "https://api.github.com/users/octocat/followers",
+        "following_url": "https://api.github.com/users/octocat/following{/other_user}",
+        "gists_url": "https://api.github.com/users/octocat/gists{/gist_id}",
+        "starred_url": "https://api.github.com/users/octocat/starred{/owner}{/repo}",
+        "subscriptions_url": "https://api.github.com/users/octocat/subscriptions",
+        "organizations_url": "https://api.github.com/users/octocat/orgs",
+        "repos_url": "https://api.github.com/users/octocat/repos",
+        "events_url": "https://api.github.com/users/octocat/events{/privacy}",
+        "received_events_url": "https://api.github.com/users/octocat/received_events",
+        "type": "User",
+        "site_admin": false,
+        "name": null,
+        "company": "GitHub",
+        "blog": null,
+        "location": "San Francisco",
+        "email": null,
+        "hireable": null,
+        "bio": "There once was...",
+        "twitter_username": null,
+        "public_repos": 2,
+        "public_gists": 1,
+        "followers": 20,
+        "following": 0,
+        "created_at": "2008-01-14T04:33:35Z",
+        "updated_at": "2008-01-14T04:33:35Z"
+    })
+}
+
+async fn setup_user_api(template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/user"))
+        .respond_with(template)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    mock_server
+}
+
+fn unauthorized(message: &str) -> ResponseTemplate {
+    ResponseTemplate::new(401).set_body_json(json!({
+        "message": message,
+        "documentation_url": "https://docs.github.com/rest/users/users#get-the-authenticated-user"
+    }))
+}
+
+#[tokio::test]
+async fn should_get_profile_with_null_name_and_blog() {
+    let mock_server =
+        setup_user_api(ResponseTemplate::new(200).set_body_json(user_profile())).await;
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .personal_token("token".to_string())
+        .build()
+        .unwrap();
+
+    let result = client.current().profile().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let profile = result.unwrap();
+    assert_eq!(profile.name, None);
+    assert_eq!(profile.blog, None);
+    assert_eq!(profile.bio.as_deref(), Some("There once was..."));
+    assert_eq!(profile.followers, 20);
+}
+
+#[tokio::test]
+async fn should_return_missing_auth_without_credentials() {
+    let mock_server = setup_user_api(unauthorized("Requires authentication")).await;
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let result = client.current().user().await;
+
+    match result {
+        Err(octocrab::Error::MissingAuth { .. }) => {}
+        result => panic!("expected a MissingAuth error, got: {:#?}", result),
+    }
+}
+
+#[tokio::test]
+async fn should_keep_github_error_for_rejected_credentials() {
+    let mock_server = setup_user_api(unauthorized("Bad credentials")).await;
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .personal_token("expired".to_string())
+        .build()
+        .unwrap();
+
+    let result = client.current().profile().await;
+
+    match result {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, http::StatusCode::UNAUTHORIZED);
+        }
+        result => panic!("expected a GitHub error, got: {:#?}", result),
+    }
+}
+
+#[tokio::test]
+async fn should_return_missing_auth_for_anonymous_clone() {
+    let mock_server = setup_user_api(unauthorized("Requires authentication")).await;
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .personal_token("token".to_string())
+        .build()
+        .unwrap()
+        .with_auth(octocrab::auth::Auth::None);
+
+    let result = client.current().profile().await;
+
+    match result {
+        Err(octocrab::Error::MissingAuth { .. }) => {}
+        result => panic!("expected a MissingAuth error, got: {:#?}", result),
+    }
+}
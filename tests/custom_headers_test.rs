@@ -0,0 +1,118 @@
+use http::{header::ACCEPT, HeaderMap, HeaderValue};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, Request, ResponseTemplate,
+};
+
+const PREVIEW: &str = "application/vnd.github.mercy-preview+json";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder()
+        .base_uri(uri)
+        .unwrap()
+        .add_preview("nebula")
+        .build()
+        .unwrap()
+}
+
+fn accept(preview: &'static str) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(ACCEPT, HeaderValue::from_static(preview));
+    headers
+}
+
+fn accept_values(request: &Request) -> Vec<String> {
+    request
+        .headers
+        .get_all(ACCEPT)
+        .iter()
+        .map(|value| value.to_str().unwrap().to_owned())
+        .collect()
+}
+
+#[tokio::test]
+async fn should_replace_default_accept_on_get() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo"))
+        .and(header(
+            "User-Agent",
+            concat!("octocrab/", env!("CARGO_PKG_VERSION")),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "name": "repo" })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result: octocrab::Result<serde_json::Value> = client
+        .get_with_headers("/repos/owner/repo", None::<&()>, Some(accept(PREVIEW)))
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let requests = mock_server.received_requests().await.unwrap();
+    assert_eq!(accept_values(&requests[0]), vec![PREVIEW]);
+}
+
+#[tokio::test]
+async fn should_replace_default_accept_on_post() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/template/generate"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({ "name": "new-repo" })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result: octocrab::Result<serde_json::Value> = client
+        .post_with_headers(
+            "/repos/owner/template/generate",
+            Some(&json!({ "name": "new-repo" })),
+            Some(accept("application/vnd.github.baptiste-preview+json")),
+        )
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let requests = mock_server.received_requests().await.unwrap();
+    assert_eq!(
+        accept_values(&requests[0]),
+        vec!["application/vnd.github.baptiste-preview+json"]
+    );
+}
+
+#[tokio::test]
+async fn should_keep_default_accept_without_override() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "name": "repo" })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result: octocrab::Result<serde_json::Value> =
+        client.get("/repos/owner/repo", None::<&()>).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let requests = mock_server.received_requests().await.unwrap();
+    assert_eq!(
+        accept_values(&requests[0]),
+        vec!["application/vnd.github.nebula-preview"]
+    );
+}
@@ -0,0 +1,71 @@
+use std::convert::Infallible;
+use std::sync::{Arc, Mutex};
+
+use bytes::Bytes;
+use http::{Request, Response, StatusCode};
+use http_body_util::Full;
+use octocrab::{AuthState, OctoBody, OctocrabBuilder};
+use serde_json::json;
+
+#[tokio::test]
+async fn should_send_requests_through_custom_service() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let recorder = seen.clone();
+    let service = tower::service_fn(move |request: Request<OctoBody>| {
+        recorder
+            .lock()
+            .unwrap()
+            .push((request.method().clone(), request.uri().to_string()));
+        async move {
+            let body = json!({ "login": "octocat", "id": 1 }).to_string();
+            Ok::<_, Infallible>(Response::new(Full::new(Bytes::from(body))))
+        }
+    });
+    let client = OctocrabBuilder::new_empty()
+        .with_service(service)
+        .with_auth(AuthState::None)
+        .build()
+        .unwrap();
+
+    let result: octocrab::Result<serde_json::Value> =
+        client.get("/users/octocat", None::<&()>).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap()["login"], "octocat");
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec![(http::Method::GET, "/users/octocat".to_string())]
+    );
+}
+
+#[tokio::test]
+async fn should_map_error_statuses_from_custom_service() {
+    let service = tower::service_fn(|_: Request<OctoBody>| async {
+        let body = json!({
+            "message": "Not Found",
+            "documentation_url": "https://docs.github.com/rest"
+        })
+        .to_string();
+        let mut response = Response::new(Full::new(Bytes::from(body)));
+        *response.status_mut() = StatusCode::NOT_FOUND;
+        Ok::<_, Infallible>(response)
+    });
+    let client = OctocrabBuilder::new_empty()
+        .with_service(service)
+        .with_auth(AuthState::None)
+        .build()
+        .unwrap();
+
+    let result = client.repos("owner", "missing").get().await;
+
+    match result {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, StatusCode::NOT_FOUND);
+        }
+        result => panic!("expected a GitHub error, got: {:#?}", result),
+    }
+}
@@ -0,0 +1,149 @@
+// Tests for the page size set with `OctocrabBuilder::default_per_page`.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::{models::issues::Issue, Octocrab, Page};
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+
+async fn setup_api() -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/issues")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(Vec::<Issue>::new()))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/issues was not received"),
+    )
+    .await;
+    mock_server
+}
+
+async fn received_query(mock_server: &MockServer) -> Option<String> {
+    let requests = mock_server.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 1);
+    requests[0].url.query().map(String::from)
+}
+
+#[tokio::test]
+async fn should_send_the_default_page_size() {
+    let mock_server = setup_api().await;
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .default_per_page(100)
+        .build()
+        .unwrap();
+
+    let result: octocrab::Result<Page<Issue>> =
+        client.issues(OWNER, REPO).list().page(2u32).send().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(
+        received_query(&mock_server).await.as_deref(),
+        Some("page=2&per_page=100")
+    );
+}
+
+#[tokio::test]
+async fn should_prefer_the_page_size_of_the_request() {
+    let mock_server = setup_api().await;
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .default_per_page(100)
+        .build()
+        .unwrap();
+
+    let result = client.issues(OWNER, REPO).list().per_page(10).send().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(
+        received_query(&mock_server).await.as_deref(),
+        Some("per_page=10")
+    );
+}
+
+#[tokio::test]
+async fn should_clamp_the_default_page_size() {
+    let mock_server = setup_api().await;
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .default_per_page(250)
+        .build()
+        .unwrap();
+
+    let result = client.issues(OWNER, REPO).list().send().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(
+        received_query(&mock_server).await.as_deref(),
+        Some("per_page=100")
+    );
+}
+
+#[tokio::test]
+async fn should_not_send_a_page_size_by_default() {
+    let mock_server = setup_api().await;
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let result = client.issues(OWNER, REPO).list().send().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(received_query(&mock_server).await.as_deref(), Some(""));
+}
+
+#[tokio::test]
+async fn should_not_send_a_page_size_for_single_resources() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/issues/1")))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(include_str!("resources/issue.json")),
+        )
+        .mount(&mock_server)
+        .await;
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .default_per_page(100)
+        .build()
+        .unwrap();
+
+    let result = client.issues(OWNER, REPO).get(1).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(received_query(&mock_server).await, None);
+}
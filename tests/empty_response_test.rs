@@ -0,0 +1,97 @@
+// Tests for mapping responses without a body, such as `204 No Content`.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::Octocrab;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ROUTE: &str = "/repos/org/some-repo/git/refs/heads/feature";
+
+async fn setup_api(verb: &str, status: u16) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method(verb))
+        .and(path(ROUTE))
+        .respond_with(ResponseTemplate::new(status))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, &format!("{verb} on {ROUTE} was not received")).await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_map_no_content_of_delete_to_unit() {
+    let mock_server = setup_api("DELETE", 204).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result: octocrab::Result<()> = client.delete(ROUTE, None::<&()>).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_map_no_content_of_post_to_unit() {
+    let mock_server = setup_api("POST", 204).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result: octocrab::Result<()> = client.post(ROUTE, None::<&()>).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_map_no_content_of_put_to_unit() {
+    let mock_server = setup_api("PUT", 204).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result: octocrab::Result<()> = client.put(ROUTE, None::<&()>).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_map_no_content_of_patch_to_unit() {
+    let mock_server = setup_api("PATCH", 204).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result: octocrab::Result<()> = client.patch(ROUTE, None::<&()>).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_map_reset_content_to_none() {
+    let mock_server = setup_api("POST", 205).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result: octocrab::Result<Option<serde_json::Value>> = client.post(ROUTE, None::<&()>).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap(), None);
+}
@@ -0,0 +1,61 @@
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[tokio::test]
+async fn should_join_relative_paths_onto_base_path() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v3/repos/owner/repo/labels"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+    for base_uri in [
+        format!("{}/api/v3", mock_server.uri()),
+        format!("{}/api/v3/", mock_server.uri()),
+    ] {
+        let client = Octocrab::builder()
+            .base_uri(base_uri)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let result = client
+            .issues("owner", "repo")
+            .list_labels_for_repo()
+            .send()
+            .await;
+
+        assert!(
+            result.is_ok(),
+            "expected successful result, got error: {:#?}",
+            result
+        );
+    }
+}
+
+#[test]
+fn should_reject_relative_base_uri_at_build_time() {
+    for base_uri in ["ghe.corp.example", "/api/v3", "not a uri"] {
+        match Octocrab::builder().base_uri(base_uri) {
+            Err(octocrab::Error::UriParse { .. }) => {}
+            Err(error) => panic!(
+                "expected a UriParse error for {}, got: {:#?}",
+                base_uri, error
+            ),
+            Ok(_) => panic!("expected {} to be rejected", base_uri),
+        }
+    }
+}
+
+#[test]
+fn should_reject_relative_upload_uri_at_build_time() {
+    assert!(matches!(
+        Octocrab::builder().upload_uri("/api/uploads"),
+        Err(octocrab::Error::UriParse { .. })
+    ));
+}
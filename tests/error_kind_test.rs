@@ -0,0 +1,137 @@
+#![cfg(feature = "retry")]
+
+use octocrab::{service::middleware::retry::RetryConfig, ErrorKind, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ROUTE: &str = "/repos/org/some-repo";
+
+async fn setup_api(template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(ROUTE))
+        .respond_with(template)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    let mut builder = Octocrab::builder().base_uri(uri).unwrap();
+    builder.add_retry_config(RetryConfig::None);
+    builder.build().unwrap()
+}
+
+#[tokio::test]
+async fn should_classify_error_statuses_as_http() {
+    let mock_server = setup_api(ResponseTemplate::new(404).set_body_json(json!({
+        "message": "Not Found",
+        "documentation_url": "https://docs.github.com/rest/repos/repos#get-a-repository"
+    })))
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let error = client.repos("org", "some-repo").get().await.unwrap_err();
+
+    match error.kind() {
+        ErrorKind::Http {
+            status,
+            github_error,
+        } => {
+            assert_eq!(status, http::StatusCode::NOT_FOUND);
+            assert_eq!(github_error.message, "Not Found");
+        }
+        kind => panic!("expected an HTTP error, got: {:#?}", kind),
+    }
+    assert!(!error.is_retriable());
+}
+
+#[tokio::test]
+async fn should_retry_server_errors() {
+    let mock_server = setup_api(ResponseTemplate::new(502).set_body_json(json!({
+        "message": "Server Error"
+    })))
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let error = client.repos("org", "some-repo").get().await.unwrap_err();
+
+    assert!(matches!(error.kind(), ErrorKind::Http { status, .. } if status == 502));
+    assert!(error.is_retriable());
+}
+
+#[tokio::test]
+async fn should_keep_body_of_decode_errors() {
+    let body = r#"{"id":"not a number"}"#;
+    let mock_server = setup_api(ResponseTemplate::new(200).set_body_string(body)).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let error = client.repos("org", "some-repo").get().await.unwrap_err();
+
+    match error.kind() {
+        ErrorKind::Decode {
+            body: Some(raw), ..
+        } => assert_eq!(raw.as_ref(), body.as_bytes()),
+        kind => panic!("expected a decode error, got: {:#?}", kind),
+    }
+    assert!(!error.is_retriable());
+}
+
+#[tokio::test]
+async fn should_classify_connection_errors_as_transport() {
+    // Nothing listens on the port once the listener is dropped.
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let client = setup_octocrab(&format!("http://127.0.0.1:{port}"));
+
+    let error = client.repos("org", "some-repo").get().await.unwrap_err();
+
+    assert!(
+        matches!(error.kind(), ErrorKind::Transport),
+        "expected a transport error, got: {:#?}",
+        error
+    );
+    assert!(error.is_retriable());
+}
+
+#[tokio::test]
+async fn should_classify_missing_credentials_as_auth() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/user"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+            "message": "Requires authentication",
+            "documentation_url": "https://docs.github.com/rest/users/users#get-the-authenticated-user"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let error = client.current().profile().await.unwrap_err();
+
+    assert!(matches!(error.kind(), ErrorKind::Auth));
+    assert!(!error.is_retriable());
+}
+
+#[tokio::test]
+async fn should_classify_rejected_credentials_as_auth() {
+    let mock_server = setup_api(ResponseTemplate::new(401).set_body_json(json!({
+        "message": "Bad credentials",
+        "documentation_url": "https://docs.github.com/rest"
+    })))
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let error = client.repos("org", "some-repo").get().await.unwrap_err();
+
+    assert!(matches!(error.kind(), ErrorKind::Auth));
+    assert!(!error.is_retriable());
+}
@@ -0,0 +1,108 @@
+#![cfg(feature = "follow-redirect")]
+
+use octocrab::models::{issues::Issue, Repository};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const OLD_NAME: &str = "old-repo";
+
+fn repository() -> Repository {
+    serde_json::from_str(include_str!("resources/repository.json")).unwrap()
+}
+
+fn issue() -> Issue {
+    serde_json::from_str(include_str!("resources/issue.json")).unwrap()
+}
+
+/// Redirects `method` requests to `from` to `to` on the same server, like
+/// GitHub does for the old paths of renamed repositories.
+async fn redirect(mock_server: &MockServer, method_name: &str, status: u16, from: &str, to: &str) {
+    Mock::given(method(method_name))
+        .and(path(from))
+        .respond_with(
+            ResponseTemplate::new(status)
+                .insert_header("Location", format!("{}{to}", mock_server.uri()).as_str()),
+        )
+        .expect(1)
+        .mount(mock_server)
+        .await;
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder()
+        .base_uri(uri)
+        .unwrap()
+        .personal_token("secret".to_string())
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn should_resolve_renamed_repository() {
+    let mock_server = MockServer::start().await;
+    redirect(
+        &mock_server,
+        "GET",
+        301,
+        &format!("/repos/{OWNER}/{OLD_NAME}"),
+        "/repositories/1",
+    )
+    .await;
+    Mock::given(method("GET"))
+        .and(path("/repositories/1"))
+        .and(header("Authorization", "Bearer secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(repository()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, OLD_NAME).get().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().id, repository().id);
+}
+
+#[tokio::test]
+async fn should_resend_method_and_body_on_temporary_redirect() {
+    let mock_server = MockServer::start().await;
+    redirect(
+        &mock_server,
+        "PATCH",
+        307,
+        &format!("/repos/{OWNER}/{OLD_NAME}/issues/1"),
+        "/repositories/1/issues/1",
+    )
+    .await;
+    Mock::given(method("PATCH"))
+        .and(path("/repositories/1/issues/1"))
+        .and(body_json(json!({ "title": "Renamed" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(issue()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .issues(OWNER, OLD_NAME)
+        .update(1)
+        .title("Renamed")
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().id, issue().id);
+}
@@ -0,0 +1,93 @@
+use octocrab::{models::Label, FullResponse, Octocrab, Page};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const REQUEST_ID: &str = "CAFE:1234:5678AB:9ABCDE:65F1A2B3";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn label() -> serde_json::Value {
+    json!({
+        "id": 208045946,
+        "node_id": "MDU6TGFiZWwyMDgwNDU5NDY=",
+        "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/labels/bug"),
+        "name": "bug",
+        "description": "Something isn't working",
+        "color": "f29513",
+        "default": true
+    })
+}
+
+#[tokio::test]
+async fn should_return_body_with_headers() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/labels")))
+        .respond_with(
+            ResponseTemplate::new(201)
+                .set_body_json(label())
+                .insert_header("x-github-request-id", REQUEST_ID)
+                .insert_header(
+                    "location",
+                    format!("https://api.github.com/repos/{OWNER}/{REPO}/labels/bug"),
+                ),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result: octocrab::Result<FullResponse<Label>> = client
+        .post(
+            format!("/repos/{OWNER}/{REPO}/labels"),
+            Some(&json!({ "name": "bug", "color": "f29513" })),
+        )
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let response = result.unwrap();
+    assert_eq!(response.status, http::StatusCode::CREATED);
+    assert_eq!(response.request_id(), Some(REQUEST_ID));
+    assert!(response.headers.contains_key(http::header::LOCATION));
+    assert_eq!(response.body.name, "bug");
+}
+
+#[tokio::test]
+async fn should_wrap_pages() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/labels")))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!([label()]))
+                .insert_header("x-github-request-id", REQUEST_ID),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result: octocrab::Result<FullResponse<Page<Label>>> = client
+        .get(format!("/repos/{OWNER}/{REPO}/labels"), None::<&()>)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let response = result.unwrap();
+    assert_eq!(response.request_id(), Some(REQUEST_ID));
+    assert_eq!(response.body.items.len(), 1);
+}
@@ -0,0 +1,146 @@
+use octocrab::{Error, Octocrab};
+use serde::Deserialize;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[derive(Debug, Deserialize)]
+struct Viewer {
+    viewer: Login,
+}
+
+#[derive(Debug, Deserialize)]
+struct Login {
+    login: String,
+}
+
+async fn setup_graphql_api(body: serde_json::Value, response: serde_json::Value) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_json(body))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_return_graphql_data() {
+    let query = "{ viewer { login }}";
+    let mock_server = setup_graphql_api(
+        json!({ "query": query }),
+        json!({ "data": { "viewer": { "login": "octocat" } } }),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.graphql_query::<Viewer>(query).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().viewer.login, "octocat");
+}
+
+#[tokio::test]
+async fn should_surface_graphql_errors() {
+    let query = "query($login: String!) { user(login: $login) { name }}";
+    let mock_server = setup_graphql_api(
+        json!({ "query": query, "variables": { "login": "nobody" } }),
+        json!({
+            "data": { "user": null },
+            "errors": [{
+                "type": "NOT_FOUND",
+                "path": ["user"],
+                "locations": [{ "line": 1, "column": 27 }],
+                "message": "Could not resolve to a User with the login of 'nobody'."
+            }]
+        }),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .graphql_with_variables::<serde_json::Value>(query, json!({ "login": "nobody" }))
+        .await;
+
+    match result {
+        Err(Error::GraphQL { errors, .. }) => {
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].r#type.as_deref(), Some("NOT_FOUND"));
+            assert_eq!(errors[0].path, vec![json!("user")]);
+            assert_eq!(errors[0].locations[0].line, 1);
+            assert_eq!(errors[0].locations[0].column, 27);
+        }
+        result => panic!("expected a GraphQL error, got: {:#?}", result),
+    }
+}
+
+#[tokio::test]
+async fn should_return_partial_graphql_data_with_errors() {
+    let query = "{ viewer { login } user(login: \"nobody\") { name }}";
+    let mock_server = setup_graphql_api(
+        json!({ "query": query }),
+        json!({
+            "data": { "viewer": { "login": "octocat" }, "user": null },
+            "errors": [{
+                "type": "NOT_FOUND",
+                "path": ["user"],
+                "locations": [{ "line": 1, "column": 20 }],
+                "message": "Could not resolve to a User with the login of 'nobody'."
+            }]
+        }),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .graphql_raw::<Viewer>(&json!({ "query": query }))
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let response = result.unwrap();
+    assert_eq!(response.data.unwrap().viewer.login, "octocat");
+    assert_eq!(response.errors.len(), 1);
+    assert_eq!(response.errors[0].path, vec![json!("user")]);
+    assert_eq!(response.errors[0].locations[0].line, 1);
+    assert_eq!(response.errors[0].locations[0].column, 20);
+}
+
+#[tokio::test]
+async fn should_return_graphql_data_without_errors() {
+    let query = "{ viewer { login }}";
+    let mock_server = setup_graphql_api(
+        json!({ "query": query }),
+        json!({ "data": { "viewer": { "login": "octocat" } } }),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .graphql_raw::<Viewer>(&json!({ "query": query }))
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let response = result.unwrap();
+    assert_eq!(response.data.unwrap().viewer.login, "octocat");
+    assert!(response.errors.is_empty());
+}
@@ -0,0 +1,60 @@
+use std::sync::{Arc, Mutex};
+
+use octocrab::{
+    models::{AppId, InstallationId},
+    Octocrab,
+};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const INSTALLATION_ID: u64 = 42;
+
+#[tokio::test]
+async fn should_call_hook_with_minted_tokens() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/app/installations/{INSTALLATION_ID}/access_tokens"
+        )))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "token": "ghs_installation",
+            "expires_at": "2100-01-01T00:00:00Z",
+            "permissions": {}
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let key = jsonwebtoken::EncodingKey::from_rsa_pem(include_bytes!(
+        "resources/test_app_private_key.pem"
+    ))
+    .unwrap();
+    let refreshed = Arc::new(Mutex::new(Vec::new()));
+    let hook = refreshed.clone();
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .app(AppId(1), key)
+        .on_token_refresh(move |token| hook.lock().unwrap().push(token.clone()))
+        .build()
+        .unwrap();
+
+    let result = client
+        .installation_and_token(InstallationId(INSTALLATION_ID))
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let refreshed = refreshed.lock().unwrap();
+    assert_eq!(refreshed.len(), 1);
+    assert_eq!(refreshed[0].token, "ghs_installation");
+    assert_eq!(
+        refreshed[0].expires_at.as_deref(),
+        Some("2100-01-01T00:00:00Z")
+    );
+}
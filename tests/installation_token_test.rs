@@ -0,0 +1,67 @@
+use octocrab::{
+    models::{AppId, InstallationId},
+    Octocrab,
+};
+use serde_json::json;
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const INSTALLATION_ID: u64 = 42;
+
+fn setup_app(uri: &str) -> Octocrab {
+    let key = jsonwebtoken::EncodingKey::from_rsa_pem(include_bytes!(
+        "resources/test_app_private_key.pem"
+    ))
+    .unwrap();
+    Octocrab::builder()
+        .base_uri(uri)
+        .unwrap()
+        .app(AppId(1), key)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn should_return_installation_token_and_reuse_it() {
+    let repository: serde_json::Value =
+        serde_json::from_str(include_str!("resources/repository.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/app/installations/{INSTALLATION_ID}/access_tokens"
+        )))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "token": "ghs_installation",
+            "expires_at": "2100-01-01T00:00:00Z",
+            "permissions": { "contents": "read" },
+            "repository_selection": "selected"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/org/some-repo"))
+        .and(header("Authorization", "Bearer ghs_installation"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(repository))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_app(&mock_server.uri());
+
+    let result = client
+        .installation_with_token(InstallationId(INSTALLATION_ID))
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let (installation, token) = result.unwrap();
+    assert_eq!(token.token, "ghs_installation");
+    assert_eq!(token.expires_at.as_deref(), Some("2100-01-01T00:00:00Z"));
+    assert_eq!(token.repository_selection.as_deref(), Some("selected"));
+    installation.repos("org", "some-repo").get().await.unwrap();
+}
@@ -0,0 +1,63 @@
+use octocrab::{models::issues::Issue, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const ISSUE_NUMBER: u64 = 1347;
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+async fn setup_assignees_api(http_method: &str, assignees: &[&str]) -> MockServer {
+    let issue: Issue = serde_json::from_str(include_str!("resources/issue.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method(http_method))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/issues/{ISSUE_NUMBER}/assignees"
+        )))
+        .and(body_json(json!({ "assignees": assignees })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(issue))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    mock_server
+}
+
+#[tokio::test]
+async fn should_add_assignees() {
+    let mock_server = setup_assignees_api("POST", &["ferris", "octocat"]).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .issues(OWNER, REPO)
+        .add_assignees(ISSUE_NUMBER, &["ferris", "octocat"])
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_remove_assignees() {
+    let mock_server = setup_assignees_api("DELETE", &["ferris"]).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .issues(OWNER, REPO)
+        .remove_assignees(ISSUE_NUMBER, &["ferris"])
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
@@ -0,0 +1,72 @@
+use octocrab::{models::issues::Comment, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const ISSUE_NUMBER: u64 = 123;
+const COMMENT_ID: u64 = 1633968123;
+
+fn comment() -> Comment {
+    serde_json::from_str(include_str!("resources/issue_comment.json")).unwrap()
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_create_comment() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/issues/{ISSUE_NUMBER}/comments"
+        )))
+        .and(body_json(json!({ "body": "Beep Boop" })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(comment()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .issues(OWNER, REPO)
+        .create_comment(ISSUE_NUMBER, "Beep Boop")
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().id, COMMENT_ID.into());
+}
+
+#[tokio::test]
+async fn should_update_comment_with_patch() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PATCH"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/issues/comments/{COMMENT_ID}"
+        )))
+        .and(body_json(json!({ "body": "Boop Beep" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(comment()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .issues(OWNER, REPO)
+        .update_comment(COMMENT_ID.into(), "Boop Beep")
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
@@ -100,3 +100,51 @@ async fn should_delete_label() {
         result
     );
 }
+
+#[tokio::test]
+async fn should_surface_missing_label_as_github_error() {
+    let template = ResponseTemplate::new(404).set_body_json(serde_json::json!({
+        "message": "Label does not exist",
+        "documentation_url": "https://docs.github.com/rest/issues/labels#remove-a-label-from-an-issue"
+    }));
+    let mock_server = setup_remove_label_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let issues = client.issues(OWNER.to_owned(), REPO.to_owned());
+
+    let result = issues
+        .remove_label(ISSUE_NUMBER, LABEL_NAME.to_owned())
+        .await;
+
+    match result {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, http::StatusCode::NOT_FOUND);
+            assert_eq!(source.message, "Label does not exist");
+        }
+        result => panic!("expected a GitHub error, got: {:#?}", result),
+    }
+}
+
+#[tokio::test]
+async fn should_clear_labels() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/issues/{ISSUE_NUMBER}/labels"
+        )))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let issues = client.issues(OWNER.to_owned(), REPO.to_owned());
+
+    let result = issues.clear_labels(ISSUE_NUMBER).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
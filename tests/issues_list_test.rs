@@ -0,0 +1,87 @@
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+
+/// An issue and a pull request, as the issues endpoints return both.
+fn mixed_page() -> serde_json::Value {
+    let issue: serde_json::Value =
+        serde_json::from_str(include_str!("resources/issue.json")).unwrap();
+    let mut pull_request = issue.clone();
+    pull_request["number"] = json!(1348);
+    pull_request["pull_request"] = json!({
+        "url": "https://api.github.com/repos/org/some-repo/pulls/1348",
+        "html_url": "https://github.com/org/some-repo/pull/1348",
+        "diff_url": "https://github.com/org/some-repo/pull/1348.diff",
+        "patch_url": "https://github.com/org/some-repo/pull/1348.patch"
+    });
+    json!([issue, pull_request])
+}
+
+async fn setup_api() -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/issues")))
+        .and(query_param("state", "all"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mixed_page()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_list_pull_requests_as_issues() {
+    let mock_server = setup_api().await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .issues(OWNER, REPO)
+        .list()
+        .state(octocrab::params::State::All)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let issues = result.unwrap().items;
+    assert_eq!(issues.len(), 2);
+    assert!(issues[0].pull_request.is_none());
+    assert!(issues[1].pull_request.is_some());
+}
+
+#[tokio::test]
+async fn should_exclude_pull_requests() {
+    let mock_server = setup_api().await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .issues(OWNER, REPO)
+        .list()
+        .state(octocrab::params::State::All)
+        .exclude_pull_requests()
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let issues = result.unwrap().items;
+    assert_eq!(issues.len(), 1);
+    assert_ne!(issues[0].number, 1348);
+    assert!(issues[0].pull_request.is_none());
+}
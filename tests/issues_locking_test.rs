@@ -160,3 +160,54 @@ async fn lock_returns_false() {
     let result = result.unwrap();
     assert!(!result, "expected the result to be false: {}", result);
 }
+
+#[tokio::test]
+async fn lock_sends_reason_as_github_string() {
+    let template = ResponseTemplate::new(204);
+    let mock_server = setup_issue_lock_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+    let issues = client.issues(OWNER.to_owned(), REPO.to_owned());
+
+    for reason in [
+        LockReason::OffTopic,
+        LockReason::TooHeated,
+        LockReason::Resolved,
+        LockReason::Spam,
+    ] {
+        issues.lock(ISSUE_NUMBER, reason).await.unwrap();
+    }
+
+    let bodies: Vec<serde_json::Value> = mock_server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .map(|request| request.body_json().unwrap())
+        .collect();
+    assert_eq!(
+        bodies,
+        vec![
+            serde_json::json!({ "lock_reason": "off-topic" }),
+            serde_json::json!({ "lock_reason": "too heated" }),
+            serde_json::json!({ "lock_reason": "resolved" }),
+            serde_json::json!({ "lock_reason": "spam" }),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn lock_without_reason_sends_empty_body() {
+    let template = ResponseTemplate::new(204);
+    let mock_server = setup_issue_lock_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    client
+        .issues(OWNER.to_owned(), REPO.to_owned())
+        .lock(ISSUE_NUMBER, None)
+        .await
+        .unwrap();
+
+    let requests = mock_server.received_requests().await.unwrap();
+    assert!(requests[0].body.is_empty());
+    assert_eq!(requests[0].headers.get("Content-Length").unwrap(), "0");
+}
@@ -1,7 +1,64 @@
-use octocrab::models::timelines::TimelineEvent;
+use octocrab::models::{timelines::TimelineEvent, Event};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
 
 #[tokio::test]
 async fn should_deserialize() {
     let _: Vec<TimelineEvent> =
         serde_json::from_str(include_str!("resources/issues_list_timeline_events.json")).unwrap();
 }
+
+#[tokio::test]
+async fn should_list_timeline_events_with_preview() {
+    let mut events: serde_json::Value =
+        serde_json::from_str(include_str!("resources/issues_list_timeline_events.json")).unwrap();
+    events.as_array_mut().unwrap().push(json!({
+        "event": "frobnicated",
+        "created_at": "2011-04-14T16:00:49Z"
+    }));
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/issues/1347/timeline"))
+        .and(header(
+            "accept",
+            "application/vnd.github.mockingbird-preview+json",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(events))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .issues("owner", "repo")
+        .list_timeline_events(1347)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let requests = mock_server.received_requests().await.unwrap();
+    assert_eq!(requests[0].url.query(), Some(""));
+
+    let items = result.unwrap().items;
+    let renamed = items
+        .iter()
+        .find(|event| event.event == Event::Renamed)
+        .unwrap();
+    assert!(renamed.rename.is_some());
+    assert_eq!(
+        items.last().unwrap().event,
+        Event::Other("frobnicated".to_string())
+    );
+}
@@ -0,0 +1,131 @@
+use chrono::{TimeZone, Utc};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const THREAD_ID: u64 = 123;
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+async fn setup_mark_all_as_read_api(template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path("/notifications"))
+        .and(body_json(json!({ "last_read_at": "2024-01-02T03:04:05Z" })))
+        .respond_with(template)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    mock_server
+}
+
+#[tokio::test]
+async fn should_mark_all_as_read_immediately() {
+    let mock_server = setup_mark_all_as_read_api(ResponseTemplate::new(205)).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .activity()
+        .notifications()
+        .mark_all_as_read(Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap())
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_mark_all_as_read_in_the_background() {
+    let template = ResponseTemplate::new(202).set_body_json(json!({
+        "message": "Unread notifications couldn't be marked in a single request. Notifications are being marked as read in the background."
+    }));
+    let mock_server = setup_mark_all_as_read_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .activity()
+        .notifications()
+        .mark_all_as_read(Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap())
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_set_thread_subscription() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path(format!(
+            "/notifications/threads/{THREAD_ID}/subscription"
+        )))
+        .and(body_json(json!({ "ignored": true })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "subscribed": true,
+            "ignored": true,
+            "reason": null,
+            "created_at": "2012-10-06T21:34:12Z",
+            "url": format!("https://api.github.com/notifications/threads/{THREAD_ID}/subscription"),
+            "thread_url": format!("https://api.github.com/notifications/threads/{THREAD_ID}")
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .activity()
+        .notifications()
+        .set_thread_subscription(THREAD_ID.into(), true)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert!(result.unwrap().ignored);
+}
+
+#[tokio::test]
+async fn should_list_notifications_with_filters() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/notifications"))
+        .and(query_param("all", "true"))
+        .and(query_param("participating", "true"))
+        .and(query_param("since", "2024-01-02T03:04:05Z"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .activity()
+        .notifications()
+        .list()
+        .all(true)
+        .participating(true)
+        .since(Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap())
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert!(result.unwrap().items.is_empty());
+}
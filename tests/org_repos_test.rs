@@ -0,0 +1,62 @@
+use octocrab::{models::Repository, params, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ORG: &str = "org";
+
+fn repository() -> serde_json::Value {
+    serde_json::from_str(include_str!("resources/repository.json")).unwrap()
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_list_org_repos_of_type() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/orgs/{ORG}/repos")))
+        .and(query_param("type", "forks"))
+        .and(query_param("sort", "pushed"))
+        .and(query_param("per_page", "100"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header(
+                    "Link",
+                    format!(
+                        "<{}/orgs/{ORG}/repos?type=forks&sort=pushed&per_page=100&page=2>; rel=\"next\"",
+                        mock_server.uri()
+                    )
+                    .as_str(),
+                )
+                .set_body_json(json!([repository()])),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .orgs(ORG)
+        .list_repos()
+        .repo_type(params::repos::Type::Forks)
+        .sort(params::repos::Sort::Pushed)
+        .per_page(100)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let page = result.unwrap();
+    let expected: Repository = serde_json::from_value(repository()).unwrap();
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.items[0].id, expected.id);
+    assert!(page.next.is_some());
+}
@@ -0,0 +1,154 @@
+use octocrab::{
+    params::orgs::{MemberFilter, MemberRole, Role},
+    Octocrab,
+};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ORG: &str = "org";
+const USERNAME: &str = "ferris";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_report_requester_not_being_a_member() {
+    let mock_server = MockServer::start().await;
+    let public_members = format!("/orgs/{ORG}/public_members/{USERNAME}");
+    Mock::given(method("GET"))
+        .and(path(format!("/orgs/{ORG}/members/{USERNAME}")))
+        .respond_with(
+            ResponseTemplate::new(302)
+                .append_header("Location", format!("{}{public_members}", mock_server.uri())),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(public_members))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    match client.orgs(ORG).check_membership(USERNAME).await {
+        Err(octocrab::Error::NotOrganizationMember { .. }) => {}
+        result => panic!("expected a not a member error, got: {:#?}", result),
+    }
+}
+
+#[tokio::test]
+async fn should_set_membership() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path(format!("/orgs/{ORG}/memberships/{USERNAME}")))
+        .and(body_json(json!({ "role": "admin" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "url": format!("https://api.github.com/orgs/{ORG}/memberships/{USERNAME}"),
+            "state": "pending",
+            "role": "admin",
+            "organization_url": format!("https://api.github.com/orgs/{ORG}"),
+            "organization": {
+                "login": ORG,
+                "id": 1,
+                "node_id": "MDEyOk9yZ2FuaXphdGlvbjE=",
+                "url": format!("https://api.github.com/orgs/{ORG}"),
+                "repos_url": format!("https://api.github.com/orgs/{ORG}/repos"),
+                "events_url": format!("https://api.github.com/orgs/{ORG}/events"),
+                "hooks_url": format!("https://api.github.com/orgs/{ORG}/hooks"),
+                "issues_url": format!("https://api.github.com/orgs/{ORG}/issues"),
+                "members_url": format!("https://api.github.com/orgs/{ORG}/members{{/member}}"),
+                "public_members_url": format!("https://api.github.com/orgs/{ORG}/public_members{{/member}}"),
+                "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+                "description": "A great organization"
+            },
+            "user": {
+                "login": USERNAME,
+                "id": 2,
+                "node_id": "MDQ6VXNlcjI=",
+                "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+                "gravatar_id": "",
+                "url": format!("https://api.github.com/users/{USERNAME}"),
+                "html_url": format!("https://github.com/{USERNAME}"),
+                "followers_url": format!("https://api.github.com/users/{USERNAME}/followers"),
+                "following_url": format!("https://api.github.com/users/{USERNAME}/following{{/other_user}}"),
+                "gists_url": format!("https://api.github.com/users/{USERNAME}/gists{{/gist_id}}"),
+                "starred_url": format!("https://api.github.com/users/{USERNAME}/starred{{/owner}}{{/repo}}"),
+                "subscriptions_url": format!("https://api.github.com/users/{USERNAME}/subscriptions"),
+                "organizations_url": format!("https://api.github.com/users/{USERNAME}/orgs"),
+                "repos_url": format!("https://api.github.com/users/{USERNAME}/repos"),
+                "events_url": format!("https://api.github.com/users/{USERNAME}/events{{/privacy}}"),
+                "received_events_url": format!("https://api.github.com/users/{USERNAME}/received_events"),
+                "type": "User",
+                "site_admin": false
+            }
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.orgs(ORG).set_membership(USERNAME, Role::Admin).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let membership = result.unwrap();
+    assert_eq!(membership.state, "pending");
+    assert_eq!(membership.role, "admin");
+}
+
+#[tokio::test]
+async fn should_remove_member() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!("/orgs/{ORG}/members/{USERNAME}")))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.orgs(ORG).remove_member(USERNAME).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_list_members_by_role_and_filter() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/orgs/{ORG}/members")))
+        .and(query_param("role", "admin"))
+        .and(query_param("filter", "2fa_disabled"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .orgs(ORG)
+        .list_members()
+        .role(MemberRole::Admin)
+        .filter(MemberFilter::TwoFactorDisabled)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert!(result.unwrap().items.is_empty());
+}
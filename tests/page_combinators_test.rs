@@ -0,0 +1,173 @@
+use std::ops::ControlFlow;
+
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+
+fn label(id: u64, name: &str) -> serde_json::Value {
+    json!({
+        "id": id,
+        "node_id": "MDU6TGFiZWwyMDgwNDU5NDY=",
+        "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/labels/{name}"),
+        "name": name,
+        "description": null,
+        "color": "f29513",
+        "default": false
+    })
+}
+
+async fn setup_paginated_labels_api(
+    second_page: ResponseTemplate,
+    second_page_requests: u64,
+) -> MockServer {
+    let mock_server = MockServer::start().await;
+    let labels_path = format!("/repos/{OWNER}/{REPO}/labels");
+    let next = format!("{}{labels_path}?page=2", mock_server.uri());
+
+    Mock::given(method("GET"))
+        .and(path(&labels_path))
+        .and(query_param("page", "2"))
+        .respond_with(second_page)
+        .expect(second_page_requests)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(&labels_path))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!([label(1, "bug"), label(2, "enhancement")]))
+                .append_header("Link", format!(r#"<{next}>; rel="next""#)),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn second_page() -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!([label(3, "question")]))
+}
+
+#[tokio::test]
+async fn should_fold_all_pages() {
+    let mock_server = setup_paginated_labels_api(second_page(), 1).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let page = client
+        .issues(OWNER, REPO)
+        .list_labels_for_repo()
+        .send()
+        .await
+        .unwrap();
+    let result = page
+        .fold_pages(&client, 0, |count, page| {
+            ControlFlow::Continue(count + page.items.len())
+        })
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap(), 3);
+}
+
+#[tokio::test]
+async fn should_stop_before_fetching_more_pages() {
+    let mock_server = setup_paginated_labels_api(second_page(), 0).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let page = client
+        .issues(OWNER, REPO)
+        .list_labels_for_repo()
+        .send()
+        .await
+        .unwrap();
+    let result = page
+        .for_each_page(&client, |page| {
+            match page.items.into_iter().find(|label| label.name == "bug") {
+                Some(label) => ControlFlow::Break(label),
+                None => ControlFlow::Continue(()),
+            }
+        })
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().unwrap().name, "bug");
+}
+
+#[tokio::test]
+async fn should_visit_every_page_without_break() {
+    let mock_server = setup_paginated_labels_api(second_page(), 1).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let page = client
+        .issues(OWNER, REPO)
+        .list_labels_for_repo()
+        .send()
+        .await
+        .unwrap();
+    let mut names = Vec::new();
+    let result = page
+        .for_each_page(&client, |page| {
+            names.extend(page.items.into_iter().map(|label| label.name));
+            ControlFlow::<()>::Continue(())
+        })
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap(), None);
+    assert_eq!(names, ["bug", "enhancement", "question"]);
+}
+
+#[tokio::test]
+async fn should_stop_on_the_first_error() {
+    let second_page = ResponseTemplate::new(403).set_body_json(json!({
+        "message": "API rate limit exceeded for 127.0.0.1.",
+        "documentation_url": "https://docs.github.com/rest/overview/resources-in-the-rest-api#rate-limiting"
+    }));
+    let mock_server = setup_paginated_labels_api(second_page, 1).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let page = client
+        .issues(OWNER, REPO)
+        .list_labels_for_repo()
+        .send()
+        .await
+        .unwrap();
+    let mut pages = 0;
+    let result = page
+        .try_for_each_page(&client, |_| {
+            pages += 1;
+            Ok::<_, octocrab::Error>(())
+        })
+        .await;
+
+    match result {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, http::StatusCode::FORBIDDEN);
+        }
+        result => panic!("expected a GitHub error, got: {:#?}", result),
+    }
+    assert_eq!(pages, 1);
+}
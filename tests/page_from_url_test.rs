@@ -0,0 +1,66 @@
+use octocrab::{models::issues::Issue, Octocrab, Page};
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const SINCE: &str = "2024-01-01T00:00:00Z";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_resume_listing_from_a_stored_next_link() {
+    let issue: Issue = serde_json::from_str(include_str!("resources/issue.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    let issues_path = format!("/repos/{OWNER}/{REPO}/issues");
+    let next = format!(
+        "{}{issues_path}?since=2024-01-01T00%3A00%3A00Z&per_page=1&page=2",
+        mock_server.uri()
+    );
+
+    Mock::given(method("GET"))
+        .and(path(&issues_path))
+        .and(query_param("since", SINCE))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(vec![&issue]))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(&issues_path))
+        .and(query_param("since", SINCE))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(vec![&issue])
+                .append_header("Link", format!(r#"<{next}>; rel="next""#)),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let first = client
+        .issues(OWNER, REPO)
+        .list()
+        .since(chrono::DateTime::parse_from_rfc3339(SINCE).unwrap())
+        .per_page(1)
+        .send()
+        .await
+        .unwrap();
+    let checkpoint = first.next.unwrap().to_string();
+
+    let result = Page::<Issue>::from_url(&client, checkpoint).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let page = result.unwrap();
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.next, None);
+}
@@ -0,0 +1,95 @@
+use octocrab::{models, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+
+fn label(id: u64, name: &str) -> serde_json::Value {
+    json!({
+        "id": id,
+        "node_id": "MDU6TGFiZWwyMDgwNDU5NDY=",
+        "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/labels/{name}"),
+        "name": name,
+        "description": null,
+        "color": "f29513",
+        "default": false
+    })
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_walk_pages_backwards() {
+    let mock_server = MockServer::start().await;
+    let labels_path = format!("/repos/{OWNER}/{REPO}/labels");
+    let page_url = |page: u32| format!("{}{labels_path}?page={page}", mock_server.uri());
+
+    Mock::given(method("GET"))
+        .and(path(&labels_path))
+        .and(query_param("page", "2"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!([label(2, "enhancement")]))
+                .append_header(
+                    "Link",
+                    format!(
+                        r#"<{}>; rel="prev", <{}>; rel="first""#,
+                        page_url(1),
+                        page_url(1)
+                    ),
+                ),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(&labels_path))
+        .and(query_param("page", "1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!([label(1, "bug")]))
+                .append_header(
+                    "Link",
+                    format!(
+                        r#"<{}>; rel="next", <{}>; rel="last""#,
+                        page_url(2),
+                        page_url(2)
+                    ),
+                ),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let last = client
+        .issues(OWNER, REPO)
+        .list_labels_for_repo()
+        .page(2u32)
+        .send()
+        .await
+        .unwrap();
+    assert!(last.next.is_none());
+    assert!(last.last.is_none());
+    assert!(last.first.is_some());
+
+    let previous = client
+        .get_previous_page::<models::Label>(&last)
+        .await
+        .unwrap()
+        .expect("a previous page");
+    assert_eq!(previous.items[0].name, "bug");
+    assert!(previous.prev.is_none());
+
+    let none = client
+        .get_previous_page::<models::Label>(&previous)
+        .await
+        .unwrap();
+    assert!(none.is_none());
+}
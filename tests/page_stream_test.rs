@@ -0,0 +1,104 @@
+#![cfg(all(feature = "stream", feature = "retry"))]
+
+use futures_util::StreamExt;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+
+fn label(id: u64, name: &str) -> serde_json::Value {
+    json!({
+        "id": id,
+        "node_id": "MDU6TGFiZWwyMDgwNDU5NDY=",
+        "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/labels/{name}"),
+        "name": name,
+        "description": null,
+        "color": "f29513",
+        "default": false
+    })
+}
+
+async fn setup_paginated_labels_api(second_page: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+    let labels_path = format!("/repos/{OWNER}/{REPO}/labels");
+    let next = format!("{}{labels_path}?page=2", mock_server.uri());
+
+    Mock::given(method("GET"))
+        .and(path(&labels_path))
+        .and(query_param("page", "2"))
+        .respond_with(second_page)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(&labels_path))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!([label(1, "bug"), label(2, "enhancement")]))
+                .append_header("Link", format!(r#"<{next}>; rel="next""#)),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_stream_items_across_pages() {
+    let second_page = ResponseTemplate::new(200).set_body_json(json!([label(3, "question")]));
+    let mock_server = setup_paginated_labels_api(second_page).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let page = client
+        .issues(OWNER, REPO)
+        .list_labels_for_repo()
+        .send()
+        .await
+        .unwrap();
+    let names: Vec<String> = page
+        .into_stream(&client)
+        .map(|label| label.unwrap().name)
+        .collect()
+        .await;
+
+    assert_eq!(names, vec!["bug", "enhancement", "question"]);
+}
+
+#[tokio::test]
+async fn should_yield_error_and_end_stream() {
+    let second_page = ResponseTemplate::new(500).set_body_json(json!({
+        "message": "Server Error",
+        "documentation_url": null
+    }));
+    let mock_server = setup_paginated_labels_api(second_page).await;
+    let mut builder = Octocrab::builder().base_uri(mock_server.uri()).unwrap();
+    builder.add_retry_config(octocrab::service::middleware::retry::RetryConfig::None);
+    let client = builder.build().unwrap();
+
+    let page = client
+        .issues(OWNER, REPO)
+        .list_labels_for_repo()
+        .send()
+        .await
+        .unwrap();
+    let items: Vec<_> = page.into_stream(&client).collect().await;
+
+    assert_eq!(items.len(), 3);
+    assert!(items[0].is_ok() && items[1].is_ok());
+    match &items[2] {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, http::StatusCode::INTERNAL_SERVER_ERROR);
+        }
+        result => panic!("expected a GitHub error, got: {:#?}", result),
+    }
+}
@@ -0,0 +1,124 @@
+use octocrab::{models::issues::Issue, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_estimate_total_from_header() {
+    let issue: Issue = serde_json::from_str(include_str!("resources/issue.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/org/some-repo/issues"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(vec![&issue])
+                .append_header("X-Total-Count", "57"),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let page = client
+        .issues("org", "some-repo")
+        .list()
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(page.total_count, None);
+    assert_eq!(page.total().header, Some(57));
+    assert_eq!(page.total().estimate(), Some(57));
+}
+
+#[tokio::test]
+async fn should_prefer_total_count_field() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search/issues"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({
+                    "total_count": 3,
+                    "incomplete_results": false,
+                    "items": []
+                }))
+                .append_header("X-Total-Count", "57"),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let page = client
+        .search()
+        .issues_and_pull_requests("is:open")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(page.total().estimate(), Some(3));
+}
+
+#[tokio::test]
+async fn should_have_no_estimate_without_total() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/org/some-repo/issues"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let page = client
+        .issues("org", "some-repo")
+        .list()
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(page.total().estimate(), None);
+}
+
+#[tokio::test]
+async fn should_bundle_links_and_totals_in_page_info() {
+    let mock_server = MockServer::start().await;
+    let next = format!("{}/repos/org/some-repo/issues?page=2", mock_server.uri());
+    let last = format!("{}/repos/org/some-repo/issues?page=3", mock_server.uri());
+    Mock::given(method("GET"))
+        .and(path("/repos/org/some-repo/issues"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!([]))
+                .append_header("X-Total-Count", "57")
+                .append_header(
+                    "Link",
+                    format!(r#"<{next}>; rel="next", <{last}>; rel="last""#).as_str(),
+                ),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let page = client
+        .issues("org", "some-repo")
+        .list()
+        .send()
+        .await
+        .unwrap();
+    let info = page.info();
+
+    assert_eq!(info.next, Some(next.parse().unwrap()));
+    assert_eq!(info.last, Some(last.parse().unwrap()));
+    assert_eq!(info.prev, None);
+    assert_eq!(info.total.estimate(), Some(57));
+    assert_eq!(info.incomplete_results, None);
+}
@@ -0,0 +1,241 @@
+// Tests for calls to the /projects/columns API.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::{
+    models::{projects::Card, ProjectCardContentType, ProjectColumn},
+    params::projects::CardPosition,
+    Octocrab, Page,
+};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, header, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const PROJECT_ID: u64 = 1002604;
+const COLUMN_ID: u64 = 367;
+const CARD_ID: u64 = 1478;
+const INERTIA_PREVIEW: &str = "application/vnd.github.inertia-preview+json";
+
+fn column() -> serde_json::Value {
+    json!({
+        "url": "https://api.github.com/projects/columns/367",
+        "project_url": "https://api.github.com/projects/120",
+        "cards_url": "https://api.github.com/projects/columns/367/cards",
+        "id": COLUMN_ID,
+        "node_id": "MDEzOlByb2plY3RDb2x1bW4zNjc=",
+        "name": "To Do",
+        "created_at": "2016-09-05T14:18:44Z",
+        "updated_at": "2016-09-05T14:22:28Z"
+    })
+}
+
+fn card() -> serde_json::Value {
+    let issue: serde_json::Value =
+        serde_json::from_str(include_str!("resources/issue.json")).unwrap();
+    json!({
+        "url": "https://api.github.com/projects/columns/cards/1478",
+        "id": CARD_ID,
+        "node_id": "MDExOlByb2plY3RDYXJkMTQ3OA==",
+        "note": "Add payload for delete Project column",
+        "creator": issue["user"],
+        "created_at": "2016-09-05T14:21:06Z",
+        "updated_at": "2016-09-05T14:20:22Z",
+        "archived": false,
+        "column_url": "https://api.github.com/projects/columns/367",
+        "content_url": "https://api.github.com/repos/api-playground/projects-test/issues/3",
+        "project_url": "https://api.github.com/projects/120"
+    })
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_list_columns() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/projects/{PROJECT_ID}/columns")))
+        .and(header("accept", INERTIA_PREVIEW))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([column()])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /projects/{PROJECT_ID}/columns was not received"),
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result: octocrab::Result<Page<ProjectColumn>> =
+        client.projects().list_columns(PROJECT_ID).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let columns = result.unwrap();
+    assert_eq!(columns.items.len(), 1);
+    assert_eq!(columns.items[0].name, "To Do");
+}
+
+#[tokio::test]
+async fn should_create_column() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/projects/{PROJECT_ID}/columns")))
+        .and(header("accept", INERTIA_PREVIEW))
+        .and(body_json(json!({ "name": "To Do" })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(column()))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("POST on /projects/{PROJECT_ID}/columns was not received"),
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client.projects().create_column(PROJECT_ID, "To Do").await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().id.into_inner(), COLUMN_ID);
+}
+
+#[tokio::test]
+async fn should_list_cards() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/projects/columns/{COLUMN_ID}/cards")))
+        .and(header("accept", INERTIA_PREVIEW))
+        .and(query_param("per_page", "100"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([card()])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /projects/columns/{COLUMN_ID}/cards was not received"),
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .projects()
+        .list_cards(COLUMN_ID)
+        .per_page(100)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let cards = result.unwrap();
+    assert_eq!(cards.items.len(), 1);
+    assert_eq!(cards.items[0].id.into_inner(), CARD_ID);
+}
+
+#[tokio::test]
+async fn should_create_card_for_an_issue() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/projects/columns/{COLUMN_ID}/cards")))
+        .and(header("accept", INERTIA_PREVIEW))
+        .and(body_json(
+            json!({ "content_id": 3, "content_type": "Issue" }),
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(card()))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("POST on /projects/columns/{COLUMN_ID}/cards was not received"),
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result: octocrab::Result<Card> = client
+        .projects()
+        .create_card(COLUMN_ID)
+        .content(3u64, ProjectCardContentType::Issue)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_move_card_to_another_column() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/projects/columns/cards/{CARD_ID}/moves")))
+        .and(header("accept", INERTIA_PREVIEW))
+        .and(body_json(
+            json!({ "position": "after:1477", "column_id": 368 }),
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({})))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("POST on /projects/columns/cards/{CARD_ID}/moves was not received"),
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .projects()
+        .move_card(
+            CARD_ID,
+            CardPosition::After(1477u64.into()),
+            Some(368u64.into()),
+        )
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_send_the_preview_to_existing_endpoints() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!("/projects/{PROJECT_ID}")))
+        .and(header("accept", INERTIA_PREVIEW))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("DELETE on /projects/{PROJECT_ID} was not received"),
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .projects()
+        .delete_project(PROJECT_ID as u32)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
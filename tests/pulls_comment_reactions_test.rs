@@ -0,0 +1,112 @@
+use octocrab::{models::reactions::ReactionContent, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "octocat";
+const REPO: &str = "hello-world";
+const COMMENT_ID: u64 = 42;
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn reaction() -> serde_json::Value {
+    let issue: serde_json::Value =
+        serde_json::from_str(include_str!("resources/issue.json")).unwrap();
+    let user = &issue["user"];
+    json!({
+        "id": 1,
+        "node_id": "MDg6UmVhY3Rpb24x",
+        "user": user,
+        "content": "rocket",
+        "created_at": "2016-05-20T20:09:31Z"
+    })
+}
+
+#[tokio::test]
+async fn should_create_comment_reaction() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/pulls/comments/{COMMENT_ID}/reactions"
+        )))
+        .and(body_json(json!({ "content": "rocket" })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(reaction()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .create_comment_reaction(COMMENT_ID, ReactionContent::Rocket)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().content, ReactionContent::Rocket);
+}
+
+#[tokio::test]
+async fn should_list_comment_reactions() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/pulls/comments/{COMMENT_ID}/reactions"
+        )))
+        .and(query_param("content", "rocket"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([reaction()])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .list_comment_reactions(COMMENT_ID)
+        .content(ReactionContent::Rocket)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().items.len(), 1);
+}
+
+#[tokio::test]
+async fn should_return_error_when_deleting_missing_reaction() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/pulls/comments/{COMMENT_ID}/reactions/1"
+        )))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "message": "Not Found",
+            "documentation_url": "https://docs.github.com/rest"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .delete_comment_reaction(COMMENT_ID, 1)
+        .await;
+
+    match result {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, 404);
+        }
+        result => panic!("expected a GitHub error, got: {:#?}", result),
+    }
+}
@@ -0,0 +1,65 @@
+use octocrab::{models::repos::RepoCommit, Octocrab};
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const PULL_NUMBER: u64 = 2;
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_list_commits_and_follow_next_page() {
+    // Commits listed for a pull request don't include their files.
+    let mut commit: serde_json::Value =
+        serde_json::from_str(include_str!("resources/repos_merges_201.json")).unwrap();
+    commit.as_object_mut().unwrap().remove("files");
+    let commit: RepoCommit = serde_json::from_value(commit).unwrap();
+    let mock_server = MockServer::start().await;
+    let commits_path = format!("/repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}/commits");
+    let next = format!("{}{commits_path}?per_page=1&page=2", mock_server.uri());
+
+    Mock::given(method("GET"))
+        .and(path(&commits_path))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(vec![&commit]))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(&commits_path))
+        .and(query_param("per_page", "1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(vec![&commit])
+                .append_header("Link", format!(r#"<{next}>; rel="next""#)),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let first = client
+        .pulls(OWNER, REPO)
+        .commits(PULL_NUMBER)
+        .per_page(1)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(first.items, vec![commit.clone()]);
+
+    let result = client.get_page::<RepoCommit>(&first.next).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let second = result.unwrap().unwrap();
+    assert_eq!(second.items, vec![commit]);
+    assert_eq!(second.next, None);
+}
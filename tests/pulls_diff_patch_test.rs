@@ -0,0 +1,67 @@
+use octocrab::Octocrab;
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const PULL_NUMBER: u64 = 2;
+const DIFF: &str = r#"diff --git a/README.md b/README.md
+index 3b18e51..8c1d6b4 100644
+--- a/README.md
++++ b/README.md
+@@ -1 +1 @@
+-hello world
++hello octocrab
+"#;
+
+async fn setup_api(accept: &str, body: &str) -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}")))
+        .and(header("Accept", accept))
+        .respond_with(ResponseTemplate::new(200).set_body_string(body))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_request_diff_media_type() {
+    let mock_server = setup_api("application/vnd.github.v3.diff", DIFF).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.pulls(OWNER, REPO).get_diff(PULL_NUMBER).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap(), DIFF);
+}
+
+#[tokio::test]
+async fn should_request_patch_media_type() {
+    let patch =
+        format!("From 6dcb09b5b57875f334f61aebed695e2e4193db5e Mon Sep 17 00:00:00 2001\n{DIFF}");
+    let mock_server = setup_api("application/vnd.github.v3.patch", &patch).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.pulls(OWNER, REPO).get_patch(PULL_NUMBER).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap(), patch);
+}
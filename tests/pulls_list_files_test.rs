@@ -0,0 +1,80 @@
+use octocrab::{models::repos::DiffEntryStatus, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const PULL_NUMBER: u64 = 2;
+
+async fn setup_list_files_api(template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}/files"
+        )))
+        .and(query_param("per_page", "100"))
+        .respond_with(template)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_list_files_with_optional_patch() {
+    let template = ResponseTemplate::new(200).set_body_json(json!([
+        {
+            "sha": "bbcd538c8e72b8c175046e27cc8f907076331401",
+            "filename": "file1.txt",
+            "status": "added",
+            "additions": 103,
+            "deletions": 21,
+            "changes": 124,
+            "blob_url": "https://github.com/octocat/Hello-World/blob/6dcb09b5b57875f334f61aebed695e2e4193db5e/file1.txt",
+            "raw_url": "https://github.com/octocat/Hello-World/raw/6dcb09b5b57875f334f61aebed695e2e4193db5e/file1.txt",
+            "contents_url": "https://api.github.com/repos/octocat/Hello-World/contents/file1.txt?ref=6dcb09b5b57875f334f61aebed695e2e4193db5e",
+            "patch": "@@ -132,7 +132,7 @@ module Test @@ -1000,7 +1000,7 @@ module Test"
+        },
+        {
+            "sha": "e69de29bb2d1d6434b8b29ae775ad8c2e48c5391",
+            "filename": "logo.png",
+            "status": "modified",
+            "additions": 0,
+            "deletions": 0,
+            "changes": 0,
+            "blob_url": "https://github.com/octocat/Hello-World/blob/6dcb09b5b57875f334f61aebed695e2e4193db5e/logo.png",
+            "raw_url": "https://github.com/octocat/Hello-World/raw/6dcb09b5b57875f334f61aebed695e2e4193db5e/logo.png",
+            "contents_url": "https://api.github.com/repos/octocat/Hello-World/contents/logo.png?ref=6dcb09b5b57875f334f61aebed695e2e4193db5e"
+        }
+    ]));
+    let mock_server = setup_list_files_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .list_files(PULL_NUMBER)
+        .per_page(100)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let files = result.unwrap().items;
+    assert_eq!(files.len(), 2);
+    assert_eq!(files[0].status, DiffEntryStatus::Added);
+    assert!(files[0].patch.is_some());
+    assert_eq!(files[1].filename, "logo.png");
+    assert!(files[1].patch.is_none());
+}
@@ -0,0 +1,98 @@
+use octocrab::{params::pulls::MergeMethod, Error, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const PULL_NUMBER: u64 = 2;
+const SHA: &str = "6dcb09b5b57875f334f61aebed695e2e4193db5e";
+
+async fn setup_merge_api(template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}/merge"
+        )))
+        .and(body_json(json!({ "sha": SHA, "merge_method": "squash" })))
+        .respond_with(template)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+async fn merge(client: &Octocrab) -> octocrab::Result<octocrab::models::pulls::Merge> {
+    client
+        .pulls(OWNER, REPO)
+        .merge(PULL_NUMBER)
+        .sha(SHA)
+        .method(MergeMethod::Squash)
+        .send()
+        .await
+}
+
+#[tokio::test]
+async fn should_merge_pull_request() {
+    let template = ResponseTemplate::new(200).set_body_json(json!({
+        "sha": SHA,
+        "merged": true,
+        "message": "Pull Request successfully merged"
+    }));
+    let mock_server = setup_merge_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = merge(&client).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let merge = result.unwrap();
+    assert!(merge.merged);
+    assert_eq!(merge.sha.as_deref(), Some(SHA));
+}
+
+#[tokio::test]
+async fn should_surface_not_mergeable_as_github_error() {
+    let template = ResponseTemplate::new(405).set_body_json(json!({
+        "message": "Pull Request is not mergeable",
+        "documentation_url": "https://docs.github.com/rest/pulls/pulls#merge-a-pull-request"
+    }));
+    let mock_server = setup_merge_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    match merge(&client).await {
+        Err(Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, http::StatusCode::METHOD_NOT_ALLOWED);
+            assert_eq!(source.message, "Pull Request is not mergeable");
+        }
+        result => panic!("expected a GitHub error, got: {:#?}", result),
+    }
+}
+
+#[tokio::test]
+async fn should_surface_sha_mismatch_as_github_error() {
+    let template = ResponseTemplate::new(409).set_body_json(json!({
+        "message": "Head branch was modified. Review and try the merge again.",
+        "documentation_url": "https://docs.github.com/rest/pulls/pulls#merge-a-pull-request"
+    }));
+    let mock_server = setup_merge_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    match merge(&client).await {
+        Err(Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, http::StatusCode::CONFLICT);
+        }
+        result => panic!("expected a GitHub error, got: {:#?}", result),
+    }
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "poll")]
+use octocrab::Octocrab;
+use serde_json::json;
+use std::time::Duration;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "octocat";
+const REPO: &str = "Hello-World";
+const PULL_NUMBER: u64 = 1347;
+
+fn pull_request(mergeable: Option<bool>, mergeable_state: &str) -> serde_json::Value {
+    let mut pr: serde_json::Value =
+        serde_json::from_str(include_str!("resources/pull_request.json")).unwrap();
+    pr["mergeable"] = json!(mergeable);
+    pr["mergeable_state"] = json!(mergeable_state);
+    pr
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_poll_until_mergeable_is_known() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(pull_request(None, "unknown")))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(pull_request(Some(false), "dirty")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .mergeable(PULL_NUMBER, 5, Duration::from_millis(1))
+        .await;
+
+    assert_eq!(result.unwrap(), Some(false));
+}
+
+#[tokio::test]
+async fn should_give_up_polling_after_attempts() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(pull_request(None, "unknown")))
+        .expect(3)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .mergeable(PULL_NUMBER, 3, Duration::from_millis(1))
+        .await;
+
+    assert_eq!(result.unwrap(), None);
+}
@@ -0,0 +1,94 @@
+use octocrab::{models::pulls::PullRequest, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const PULL_NUMBER: u64 = 2;
+
+async fn setup_requested_reviewers_api(http_method: &str, body: serde_json::Value) -> MockServer {
+    let pull_request: PullRequest =
+        serde_json::from_str(include_str!("resources/pull_request.json")).unwrap();
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method(http_method))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}/requested_reviewers"
+        )))
+        .and(body_json(body))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&pull_request))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_request_reviews() {
+    let mock_server = setup_requested_reviewers_api(
+        "POST",
+        json!({ "reviewers": ["user1"], "team_reviewers": ["team1"] }),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .request_reviews(
+            PULL_NUMBER,
+            vec!["user1".to_string()],
+            vec!["team1".to_string()],
+        )
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().number, PULL_NUMBER);
+}
+
+#[tokio::test]
+async fn should_omit_empty_reviewer_lists() {
+    let mock_server =
+        setup_requested_reviewers_api("POST", json!({ "reviewers": ["user1"] })).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .request_reviews(PULL_NUMBER, vec!["user1".to_string()], vec![])
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_remove_requested_reviewers() {
+    let mock_server =
+        setup_requested_reviewers_api("DELETE", json!({ "team_reviewers": ["team1"] })).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .remove_requested_reviewers(PULL_NUMBER, vec![], vec!["team1".to_string()])
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
@@ -0,0 +1,174 @@
+// Tests for calls to the /repos/{owner}/{repo}/pulls/comments API.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::{params::pulls::comments::Side, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const PULL_NUMBER: u64 = 42;
+const COMMENT_ID: u64 = 1;
+
+fn comment() -> serde_json::Value {
+    let event: serde_json::Value = serde_json::from_str(include_str!(
+        "resources/pull_request_review_comment_event.json"
+    ))
+    .unwrap();
+    event["payload"]["comment"].clone()
+}
+
+async fn setup_api(mock: Mock, description: &str) -> MockServer {
+    let mock_server = MockServer::start().await;
+    mock.mount(&mock_server).await;
+    setup_error_handler(&mock_server, description).await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_create_multi_line_review_comment() {
+    let route = format!("/repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}/comments");
+    let mock_server = setup_api(
+        Mock::given(method("POST"))
+            .and(path(&route))
+            .and(body_json(json!({
+                "body": "Great stuff!",
+                "commit_id": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+                "path": "file1.txt",
+                "start_line": 1,
+                "line": 2,
+                "side": "RIGHT"
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(comment())),
+        &format!("POST on {route} was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .create_review_comment(PULL_NUMBER, "Great stuff!")
+        .commit_id("6dcb09b5b57875f334f61aebed695e2e4193db5e")
+        .path("file1.txt")
+        .start_line(1u64)
+        .line(2u64)
+        .side(Side::Right)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_reply_to_review_comment() {
+    let route = format!("/repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}/comments");
+    let mock_server = setup_api(
+        Mock::given(method("POST"))
+            .and(path(&route))
+            .and(body_json(
+                json!({ "body": "Agreed", "in_reply_to": COMMENT_ID }),
+            ))
+            .respond_with(ResponseTemplate::new(201).set_body_json(comment())),
+        &format!("POST on {route} was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .create_review_comment(PULL_NUMBER, "Agreed")
+        .in_reply_to(COMMENT_ID)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_reject_invalid_location_without_sending() {
+    let mock_server = MockServer::start().await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .create_review_comment(PULL_NUMBER, "Great stuff!")
+        .commit_id("6dcb09b5b57875f334f61aebed695e2e4193db5e")
+        .path("file1.txt")
+        .position(4u64)
+        .line(2u64)
+        .send()
+        .await;
+
+    match result {
+        Err(octocrab::Error::Other { source, .. }) => {
+            assert!(source.to_string().contains("`position` can't be combined"));
+        }
+        result => panic!("expected a local error, got: {:#?}", result),
+    }
+    assert!(mock_server.received_requests().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn should_update_review_comment() {
+    let route = format!("/repos/{OWNER}/{REPO}/pulls/comments/{COMMENT_ID}");
+    let mock_server = setup_api(
+        Mock::given(method("PATCH"))
+            .and(path(&route))
+            .and(body_json(json!({ "body": "Even better stuff!" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(comment())),
+        &format!("PATCH on {route} was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .update_review_comment(COMMENT_ID, "Even better stuff!")
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_delete_review_comment() {
+    let route = format!("/repos/{OWNER}/{REPO}/pulls/comments/{COMMENT_ID}");
+    let mock_server = setup_api(
+        Mock::given(method("DELETE"))
+            .and(path(&route))
+            .respond_with(ResponseTemplate::new(204)),
+        &format!("DELETE on {route} was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .delete_review_comment(COMMENT_ID)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
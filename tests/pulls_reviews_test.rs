@@ -0,0 +1,198 @@
+// Tests for calls to the /repos/{owner}/{repo}/pulls/{pull_number}/reviews API.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::{
+    models::pulls::ReviewState,
+    params::pulls::{ReviewComment, ReviewEvent},
+    Octocrab,
+};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const PULL_NUMBER: u64 = 42;
+const REVIEW_ID: u64 = 80;
+
+fn review(state: &str) -> serde_json::Value {
+    let issue: serde_json::Value =
+        serde_json::from_str(include_str!("resources/issue.json")).unwrap();
+    json!({
+        "id": REVIEW_ID,
+        "node_id": "MDE3OlB1bGxSZXF1ZXN0UmV2aWV3ODA=",
+        "user": issue["user"],
+        "body": "Here is the body for the review.",
+        "state": state,
+        "html_url": format!("https://github.com/{OWNER}/{REPO}/pull/{PULL_NUMBER}#pullrequestreview-{REVIEW_ID}"),
+        "pull_request_url": format!("https://api.github.com/repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}"),
+        "submitted_at": "2019-11-17T17:43:43Z",
+        "commit_id": "ecdd80bb57125d7ba9641ffaa4d7d2c19d3f3091"
+    })
+}
+
+async fn setup_api(
+    verb: &str,
+    route: String,
+    body: serde_json::Value,
+    template: ResponseTemplate,
+) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method(verb))
+        .and(path(&route))
+        .and(body_json(body))
+        .respond_with(template)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, &format!("{verb} on {route} was not received")).await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn reviews_route() -> String {
+    format!("/repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}/reviews")
+}
+
+#[tokio::test]
+async fn should_create_review_with_comments() {
+    let mock_server = setup_api(
+        "POST",
+        reviews_route(),
+        json!({
+            "body": "Here is the body for the review.",
+            "event": "REQUEST_CHANGES",
+            "comments": [{ "path": "file.md", "position": 6, "body": "Please add more information here." }]
+        }),
+        ResponseTemplate::new(200).set_body_json(review("CHANGES_REQUESTED")),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .create_review(PULL_NUMBER)
+        .body("Here is the body for the review.")
+        .event(ReviewEvent::RequestChanges)
+        .comments([ReviewComment {
+            path: "file.md".to_string(),
+            position: Some(6),
+            body: "Please add more information here.".to_string(),
+            ..Default::default()
+        }])
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let review = result.unwrap();
+    assert_eq!(review.id.into_inner(), REVIEW_ID);
+    assert_eq!(review.state, Some(ReviewState::ChangesRequested));
+}
+
+#[tokio::test]
+async fn should_return_validation_error_when_approving_own_pull_request() {
+    let mock_server = setup_api(
+        "POST",
+        reviews_route(),
+        json!({ "event": "APPROVE" }),
+        ResponseTemplate::new(422).set_body_json(json!({
+            "message": "Unprocessable Entity",
+            "errors": ["Can not approve your own pull request"],
+            "documentation_url": "https://docs.github.com/rest/pulls/reviews#create-a-review-for-a-pull-request"
+        })),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .create_review(PULL_NUMBER)
+        .event(ReviewEvent::Approve)
+        .send()
+        .await;
+
+    match result {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, 422);
+            assert_eq!(
+                source.errors,
+                Some(vec![json!("Can not approve your own pull request")])
+            );
+        }
+        result => panic!("expected a GitHub error, got: {:#?}", result),
+    }
+}
+
+#[tokio::test]
+async fn should_submit_review() {
+    let mock_server = setup_api(
+        "POST",
+        format!("{}/{REVIEW_ID}/events", reviews_route()),
+        json!({ "event": "APPROVE" }),
+        ResponseTemplate::new(200).set_body_json(review("APPROVED")),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .submit_review(PULL_NUMBER, REVIEW_ID, ReviewEvent::Approve, None)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().state, Some(ReviewState::Approved));
+}
+
+#[tokio::test]
+async fn should_refuse_to_submit_pending_review() {
+    let mock_server = MockServer::start().await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .submit_review(PULL_NUMBER, REVIEW_ID, ReviewEvent::Pending, None)
+        .await;
+
+    match result {
+        Err(octocrab::Error::Other { .. }) => {}
+        result => panic!("expected an Other error, got: {:#?}", result),
+    }
+    assert!(mock_server.received_requests().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn should_dismiss_review() {
+    let mock_server = setup_api(
+        "PUT",
+        format!("{}/{REVIEW_ID}/dismissals", reviews_route()),
+        json!({ "message": "The requested changes were made." }),
+        ResponseTemplate::new(200).set_body_json(review("DISMISSED")),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .dismiss_review(PULL_NUMBER, REVIEW_ID, "The requested changes were made.")
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().state, Some(ReviewState::Dismissed));
+}
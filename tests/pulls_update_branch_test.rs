@@ -0,0 +1,91 @@
+use octocrab::{models::pulls::BranchUpdate, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const PULL_NUMBER: u64 = 42;
+
+async fn setup_update_branch_api(template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}/update-branch"
+        )))
+        .and(body_json(json!({
+            "expected_head_sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e"
+        })))
+        .respond_with(template)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+async fn update_branch(client: &Octocrab) -> octocrab::Result<BranchUpdate> {
+    client
+        .pulls(OWNER, REPO)
+        .update_branch(PULL_NUMBER)
+        .expected_head_sha("6dcb09b5b57875f334f61aebed695e2e4193db5e")
+        .send()
+        .await
+}
+
+#[tokio::test]
+async fn should_schedule_branch_update() {
+    let template = ResponseTemplate::new(202).set_body_json(json!({
+        "message": "Updating pull request branch.",
+        "url": "https://github.com/repos/org/some-repo/pulls/42"
+    }));
+    let mock_server = setup_update_branch_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = update_branch(&client).await;
+
+    match result {
+        Ok(BranchUpdate::Scheduled { message, .. }) => {
+            assert_eq!(message, "Updating pull request branch.");
+        }
+        result => panic!("expected a scheduled update, got: {:#?}", result),
+    }
+}
+
+#[tokio::test]
+async fn should_report_branch_already_up_to_date() {
+    let template = ResponseTemplate::new(422).set_body_json(json!({
+        "message": "There are no new commits on the base branch.",
+        "documentation_url": "https://docs.github.com/rest/pulls/pulls#update-a-pull-request-branch"
+    }));
+    let mock_server = setup_update_branch_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = update_branch(&client).await;
+
+    assert_eq!(result.unwrap(), BranchUpdate::AlreadyUpToDate);
+}
+
+#[tokio::test]
+async fn should_return_error_on_head_sha_mismatch() {
+    let template = ResponseTemplate::new(422).set_body_json(json!({
+        "message": "expected head sha didn't match current head ref.",
+        "documentation_url": "https://docs.github.com/rest/pulls/pulls#update-a-pull-request-branch"
+    }));
+    let mock_server = setup_update_branch_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = update_branch(&client).await;
+
+    match result {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, 422);
+        }
+        result => panic!("expected a GitHub error, got: {:#?}", result),
+    }
+}
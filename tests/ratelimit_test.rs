@@ -0,0 +1,94 @@
+use chrono::{TimeZone, Utc};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn rate(limit: usize, used: usize) -> serde_json::Value {
+    json!({
+        "limit": limit,
+        "used": used,
+        "remaining": limit - used,
+        "reset": 1691591363
+    })
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_get_rate_limit_buckets() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/rate_limit"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "resources": {
+                "core": rate(5000, 1),
+                "search": rate(30, 12),
+                "graphql": rate(5000, 0),
+                "integration_manifest": rate(5000, 0)
+            },
+            "rate": rate(5000, 1)
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.ratelimit().get().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let resources = result.unwrap().resources;
+    assert_eq!(resources.core.remaining, 4999);
+    assert_eq!(resources.search.used, 12);
+    assert!(resources.graphql.is_some());
+    assert!(resources.integration_manifest.is_some());
+    assert_eq!(resources.core.reset, 1691591363);
+    assert_eq!(
+        resources.core.reset_at(),
+        Utc.timestamp_opt(1691591363, 0).single()
+    );
+}
+
+#[tokio::test]
+async fn should_remember_rate_limit_headers() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/org/some-repo/labels"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!([]))
+                .append_header("x-ratelimit-limit", "5000")
+                .append_header("x-ratelimit-used", "42")
+                .append_header("x-ratelimit-remaining", "4958")
+                .append_header("x-ratelimit-reset", "1691591363")
+                .append_header("x-ratelimit-resource", "core"),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+    assert!(client.last_rate_limit().is_none());
+
+    client
+        .issues("org", "some-repo")
+        .list_labels_for_repo()
+        .send()
+        .await
+        .unwrap();
+
+    let rate = client
+        .last_rate_limit()
+        .expect("rate limit headers are recorded");
+    assert_eq!(rate.limit, 5000);
+    assert_eq!(rate.used, 42);
+    assert_eq!(rate.remaining, 4958);
+    assert_eq!(rate.reset, 1691591363);
+}
@@ -0,0 +1,101 @@
+use http::{HeaderMap, HeaderValue, Method, StatusCode};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ROUTE: &str = "/repos/org/some-repo/private-vulnerability-reporting";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder()
+        .base_uri(uri)
+        .unwrap()
+        .personal_token("secret".to_string())
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn should_apply_client_configuration_to_raw_requests() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(ROUTE))
+        .and(header("Authorization", "Bearer secret"))
+        .and(header(
+            "User-Agent",
+            concat!("octocrab/", env!("CARGO_PKG_VERSION")),
+        ))
+        .and(header("X-GitHub-Api-Version", "2022-11-28"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("X-GitHub-Request-Id", "CAFE:1234")
+                .set_body_json(json!({ "enabled": true })),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        "X-GitHub-Api-Version",
+        HeaderValue::from_static("2022-11-28"),
+    );
+
+    let result = client
+        .raw(Method::GET, ROUTE, None::<&()>, Some(headers))
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let response = result.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["X-GitHub-Request-Id"], "CAFE:1234");
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body, json!({ "enabled": true }));
+}
+
+#[tokio::test]
+async fn should_send_raw_request_body() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path(ROUTE))
+        .and(body_json(json!({ "enabled": true })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let response = client
+        .raw(Method::PUT, ROUTE, Some(&json!({ "enabled": true })), None)
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    assert!(response.bytes().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn should_return_error_statuses_as_is() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(ROUTE))
+        .respond_with(ResponseTemplate::new(404).set_body_string("Not Found"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let response = client
+        .raw(Method::GET, ROUTE, None::<&()>, None)
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(response.bytes().await.unwrap(), "Not Found");
+}
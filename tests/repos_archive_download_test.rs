@@ -0,0 +1,121 @@
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const TARBALL: &[u8] = &[0x1f, 0x8b, 0x08, 0x00];
+const ZIP: &[u8] = &[0x50, 0x4b, 0x03, 0x04, 0x14, 0x00];
+
+/// Mounts `api_path` on the API server, redirecting to an archive on a
+/// separate server, which stands in for `codeload.github.com`.
+async fn setup_redirect_api(api_path: String, archive: &'static [u8]) -> (MockServer, MockServer) {
+    let api_server = MockServer::start().await;
+    let codeload_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/archive"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(archive))
+        .expect(1)
+        .mount(&codeload_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(api_path))
+        .and(header("Authorization", "Bearer secret"))
+        .respond_with(
+            ResponseTemplate::new(302)
+                .append_header("Location", format!("{}/archive", codeload_server.uri())),
+        )
+        .expect(1)
+        .mount(&api_server)
+        .await;
+
+    (api_server, codeload_server)
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder()
+        .base_uri(uri)
+        .unwrap()
+        .personal_token("secret".to_string())
+        .build()
+        .unwrap()
+}
+
+async fn assert_no_credentials_sent(codeload_server: &MockServer) {
+    let requests = codeload_server.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 1);
+    assert!(
+        !requests[0].headers.contains_key("Authorization"),
+        "credentials leaked to the codeload host"
+    );
+}
+
+#[tokio::test]
+async fn should_download_tarball() {
+    let (api_server, codeload_server) =
+        setup_redirect_api(format!("/repos/{OWNER}/{REPO}/tarball/main"), TARBALL).await;
+    let client = setup_octocrab(&api_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .download_tarball("main".to_string())
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().as_ref(), TARBALL);
+    assert_no_credentials_sent(&codeload_server).await;
+}
+
+#[tokio::test]
+async fn should_download_zipball() {
+    let (api_server, codeload_server) =
+        setup_redirect_api(format!("/repos/{OWNER}/{REPO}/zipball/v1.0.0"), ZIP).await;
+    let client = setup_octocrab(&api_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .download_zipball("v1.0.0".to_string())
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().as_ref(), ZIP);
+    assert_no_credentials_sent(&codeload_server).await;
+}
+
+#[tokio::test]
+async fn should_return_error_for_unknown_reference() {
+    let api_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/tarball/missing")))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "message": "Not Found",
+            "documentation_url": "https://docs.github.com/rest/repos/contents#download-a-repository-archive-tar"
+        })))
+        .mount(&api_server)
+        .await;
+    let client = setup_octocrab(&api_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .download_tarball("missing".to_string())
+        .await;
+
+    match result {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, 404);
+        }
+        result => panic!("expected a GitHub error, got: {:#?}", result),
+    }
+}
@@ -0,0 +1,177 @@
+use octocrab::{
+    models::repos::RepoInvitation,
+    params::{repos::collaborators::Affiliation, teams::Permission},
+    Octocrab,
+};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "octocat";
+const REPO: &str = "Hello-World";
+const USERNAME: &str = "hubot";
+const INVITATION_ID: u64 = 1;
+
+fn invitation() -> serde_json::Value {
+    let repository: serde_json::Value =
+        serde_json::from_str(include_str!("resources/repository.json")).unwrap();
+    let issue: serde_json::Value =
+        serde_json::from_str(include_str!("resources/issue.json")).unwrap();
+    json!({
+        "id": INVITATION_ID,
+        "node_id": "MDEwOkludml0YXRpb24x",
+        "repository": repository,
+        "invitee": issue["user"],
+        "inviter": issue["user"],
+        "permissions": "write",
+        "created_at": "2016-06-13T14:52:50-05:00",
+        "expired": false,
+        "url": "https://api.github.com/user/repository_invitations/1296269",
+        "html_url": "https://github.com/octocat/Hello-World/invitations"
+    })
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+async fn setup_add_collaborator_api(template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/collaborators/{USERNAME}"
+        )))
+        .and(body_json(json!({ "permission": "maintain" })))
+        .respond_with(template)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    mock_server
+}
+
+async fn add_collaborator(client: &Octocrab) -> octocrab::Result<Option<RepoInvitation>> {
+    client
+        .repos(OWNER, REPO)
+        .add_collaborator(USERNAME)
+        .permission(Permission::Maintain)
+        .send()
+        .await
+}
+
+#[tokio::test]
+async fn should_return_invitation_for_outside_collaborator() {
+    let template = ResponseTemplate::new(201).set_body_json(invitation());
+    let mock_server = setup_add_collaborator_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = add_collaborator(&client).await;
+
+    let invitation = result.unwrap().expect("expected an invitation");
+    assert_eq!(invitation.id, INVITATION_ID.into());
+    assert_eq!(invitation.permissions, "write");
+}
+
+#[tokio::test]
+async fn should_return_no_invitation_for_existing_member() {
+    let template = ResponseTemplate::new(204);
+    let mock_server = setup_add_collaborator_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = add_collaborator(&client).await;
+
+    assert!(result.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn should_remove_collaborator() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/collaborators/{USERNAME}"
+        )))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .remove_collaborator(USERNAME)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_list_outside_collaborators() {
+    let issue: serde_json::Value =
+        serde_json::from_str(include_str!("resources/issue.json")).unwrap();
+    let mut collaborator = issue["user"].clone();
+    collaborator["permissions"] = json!({ "pull": true, "push": false });
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/collaborators")))
+        .and(query_param("affiliation", "outside"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([collaborator])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .list_collaborators()
+        .affiliation(Affiliation::Outside)
+        .send()
+        .await;
+
+    assert_eq!(result.unwrap().items.len(), 1);
+}
+
+#[tokio::test]
+async fn should_list_invitations() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/invitations")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([invitation()])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).list_invitations().send().await;
+
+    assert_eq!(result.unwrap().items.len(), 1);
+}
+
+#[tokio::test]
+async fn should_delete_invitation() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/invitations/{INVITATION_ID}"
+        )))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .delete_invitation(INVITATION_ID)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
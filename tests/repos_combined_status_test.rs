@@ -0,0 +1,72 @@
+use octocrab::{models::StatusState, params::repos::Reference, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const SHA: &str = "6dcb09b5b57875f334f61aebed695e2e4193db5e";
+
+fn status(id: u64, state: &str, context: &str) -> serde_json::Value {
+    json!({
+        "id": id,
+        "node_id": "MDY6U3RhdHVzMQ==",
+        "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/statuses/{SHA}"),
+        "avatar_url": "https://github.com/images/error/hubot_happy.gif",
+        "state": state,
+        "description": "Build has completed",
+        "target_url": "https://ci.example.com/1000/output",
+        "context": context,
+        "created_at": "2012-07-20T01:19:13Z",
+        "updated_at": "2012-07-20T01:19:13Z"
+    })
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_get_combined_status() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/commits/heads/main/status"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "state": "failure",
+            "sha": SHA,
+            "total_count": 2,
+            "statuses": [
+                status(1, "success", "continuous-integration/jenkins"),
+                status(2, "failure", "security/brakeman")
+            ],
+            "commit_url": format!("https://api.github.com/repos/{OWNER}/{REPO}/{SHA}"),
+            "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/{SHA}/status")
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .combined_status_for_ref(&Reference::Branch("main".to_string()))
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let combined = result.unwrap();
+    assert_eq!(combined.state, StatusState::Failure);
+    assert_eq!(combined.total_count, 2);
+    assert_eq!(combined.statuses[0].state, StatusState::Success);
+    assert_eq!(
+        combined.statuses[1].context.as_deref(),
+        Some("security/brakeman")
+    );
+}
@@ -0,0 +1,209 @@
+use octocrab::{
+    models::repos::deployments::{CreateDeploymentResponse, DeploymentState},
+    Octocrab,
+};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "octocat";
+const REPO: &str = "example";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn deployment() -> serde_json::Value {
+    json!({
+        "url": "https://api.github.com/repos/octocat/example/deployments/1",
+        "id": 1,
+        "node_id": "MDEwOkRlcGxveW1lbnQx",
+        "sha": "a84d88e7554fc1fa21bcbc4efae3c782a70d2b9d",
+        "ref": "topic-branch",
+        "task": "deploy",
+        "payload": {},
+        "original_environment": "staging",
+        "environment": "production",
+        "description": "Deploy request from hubot",
+        "creator": null,
+        "created_at": "2012-07-20T01:19:13Z",
+        "updated_at": "2012-07-20T01:19:13Z",
+        "statuses_url": "https://api.github.com/repos/octocat/example/deployments/1/statuses",
+        "repository_url": "https://api.github.com/repos/octocat/example",
+        "transient_environment": false,
+        "production_environment": true
+    })
+}
+
+async fn setup_create_api(template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/deployments")))
+        .and(body_json(
+            json!({ "ref": "topic-branch", "auto_merge": true }),
+        ))
+        .respond_with(template)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    mock_server
+}
+
+#[tokio::test]
+async fn should_create_deployment() {
+    let mock_server =
+        setup_create_api(ResponseTemplate::new(201).set_body_json(deployment())).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .deployments()
+        .create("topic-branch")
+        .auto_merge(true)
+        .send()
+        .await;
+
+    match result {
+        Ok(CreateDeploymentResponse::Created(deployment)) => {
+            assert_eq!(deployment.r#ref, "topic-branch");
+        }
+        result => panic!("expected a created deployment, got: {:#?}", result),
+    }
+}
+
+#[tokio::test]
+async fn should_report_merged_branch() {
+    let template = ResponseTemplate::new(202).set_body_json(json!({
+        "message": "Auto-merged master into topic-branch on deployment."
+    }));
+    let mock_server = setup_create_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .deployments()
+        .create("topic-branch")
+        .auto_merge(true)
+        .send()
+        .await;
+
+    match result {
+        Ok(CreateDeploymentResponse::MergedBranch { message }) => {
+            assert_eq!(
+                message,
+                "Auto-merged master into topic-branch on deployment."
+            );
+        }
+        result => panic!("expected a merged branch, got: {:#?}", result),
+    }
+}
+
+#[tokio::test]
+async fn should_return_conflict_error() {
+    let template = ResponseTemplate::new(409).set_body_json(json!({
+        "message": "Conflict merging master into topic-branch",
+        "documentation_url": "https://docs.github.com/rest/deployments/deployments#create-a-deployment"
+    }));
+    let mock_server = setup_create_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .deployments()
+        .create("topic-branch")
+        .auto_merge(true)
+        .send()
+        .await;
+
+    match result {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, 409);
+        }
+        result => panic!("expected a GitHub error, got: {:#?}", result),
+    }
+}
+
+#[tokio::test]
+async fn should_list_deployments() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/deployments")))
+        .and(query_param("environment", "production"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([deployment()])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .deployments()
+        .list()
+        .environment("production")
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().items.len(), 1);
+}
+
+#[tokio::test]
+async fn should_create_deployment_status() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/deployments/1/statuses"
+        )))
+        .and(body_json(json!({
+            "state": "success",
+            "log_url": "https://example.com/deployment/42/output",
+            "environment_url": "https://test-branch.lab.acme.com"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "url": "https://api.github.com/repos/octocat/example/deployments/1/statuses/2",
+            "id": 2,
+            "node_id": "MDE2OkRlcGxveW1lbnRTdGF0dXMy",
+            "state": "success",
+            "creator": null,
+            "description": "Deployment finished successfully.",
+            "environment": "production",
+            "target_url": "https://example.com/deployment/42/output",
+            "log_url": "https://example.com/deployment/42/output",
+            "environment_url": "https://test-branch.lab.acme.com",
+            "created_at": "2012-07-20T01:19:13Z",
+            "updated_at": "2012-07-20T01:19:13Z",
+            "deployment_url": "https://api.github.com/repos/octocat/example/deployments/1",
+            "repository_url": "https://api.github.com/repos/octocat/example"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .deployments()
+        .create_status(1u64.into(), DeploymentState::Success)
+        .log_url("https://example.com/deployment/42/output")
+        .environment_url("https://test-branch.lab.acme.com")
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let status = result.unwrap();
+    assert_eq!(status.state, DeploymentState::Success);
+    assert_eq!(
+        status.environment_url.unwrap().as_str(),
+        "https://test-branch.lab.acme.com/"
+    );
+}
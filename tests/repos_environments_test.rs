@@ -0,0 +1,141 @@
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "octocat";
+const REPO: &str = "hello-world";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn environment() -> serde_json::Value {
+    json!({
+        "id": 161088068,
+        "node_id": "MDExOkVudmlyb25tZW50MTYxMDg4MDY4",
+        "name": "staging",
+        "url": "https://api.github.com/repos/github/hello-world/environments/staging",
+        "html_url": "https://github.com/github/hello-world/deployments/activity_log?environments_filter=staging",
+        "created_at": "2020-11-23T22:00:40Z",
+        "updated_at": "2020-11-23T22:00:40Z",
+        "protection_rules": [
+            {
+                "id": 3736,
+                "node_id": "MDQ6R2F0ZTM3MzY=",
+                "type": "wait_timer",
+                "wait_timer": 30
+            },
+            {
+                "id": 3755,
+                "node_id": "MDQ6R2F0ZTM3NTU=",
+                "prevent_self_review": false,
+                "type": "required_reviewers",
+                "reviewers": []
+            }
+        ],
+        "deployment_branch_policy": {
+            "protected_branches": false,
+            "custom_branch_policies": true
+        }
+    })
+}
+
+#[tokio::test]
+async fn should_list_environments() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/environments")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "total_count": 1,
+            "environments": [environment()]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).environments().list().send().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let environments = result.unwrap();
+    assert_eq!(environments.total_count, 1);
+    assert_eq!(environments.environments[0].protection_rules.len(), 2);
+}
+
+#[tokio::test]
+async fn should_get_environment() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/environments/staging")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(environment()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .environments()
+        .get("staging")
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().name, "staging");
+}
+
+#[tokio::test]
+async fn should_list_repo_secrets_and_public_key() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/actions/secrets")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "total_count": 1,
+            "secrets": [{
+                "name": "GH_TOKEN",
+                "created_at": "2019-08-10T14:59:22Z",
+                "updated_at": "2020-01-10T14:59:22Z"
+            }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/actions/secrets/public-key"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "key_id": "012345678912345678",
+            "key": "2Sg8iYjAxxmI2LvUXpJjkYrMxURPc8r+dB7TJyvv1234"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let secrets = client.actions().list_repo_secrets(OWNER, REPO).await;
+    assert!(
+        secrets.is_ok(),
+        "expected successful result, got error: {:#?}",
+        secrets
+    );
+    assert_eq!(secrets.unwrap().secrets[0].name, "GH_TOKEN");
+
+    let public_key = client.actions().get_repo_public_key(OWNER, REPO).await;
+    assert!(
+        public_key.is_ok(),
+        "expected successful result, got error: {:#?}",
+        public_key
+    );
+    assert_eq!(public_key.unwrap().key_id, "012345678912345678");
+}
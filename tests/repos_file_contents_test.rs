@@ -0,0 +1,148 @@
+use octocrab::{models::repos::CommitAuthor, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_partial_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const FILE_PATH: &str = "crabs/ferris.txt";
+const COMMIT_SHA: &str = "7638417db6d59f3c431d3e1f261cc637155684cd";
+
+fn commit() -> serde_json::Value {
+    json!({
+        "sha": COMMIT_SHA,
+        "node_id": "MDY6Q29tbWl0NzYzODQxN2RiNmQ1OWYzYzQzMWQzZTFmMjYxY2M2MzcxNTU2ODRjZA==",
+        "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/git/commits/{COMMIT_SHA}"),
+        "html_url": format!("https://github.com/{OWNER}/{REPO}/git/commit/{COMMIT_SHA}"),
+        "author": {
+            "date": "2014-11-07T22:01:45Z",
+            "name": "Ferris",
+            "email": "ferris@rust-lang.org"
+        },
+        "committer": {
+            "date": "2014-11-07T22:01:45Z",
+            "name": "Octocat",
+            "email": "octocat@github.com"
+        }
+    })
+}
+
+fn content() -> serde_json::Value {
+    let url = format!("https://api.github.com/repos/{OWNER}/{REPO}/contents/{FILE_PATH}");
+    json!({
+        "name": "ferris.txt",
+        "path": FILE_PATH,
+        "sha": "95b966ae1c166bd92f8ae7d1c313e738c731dfc3",
+        "size": 9,
+        "url": url,
+        "html_url": format!("https://github.com/{OWNER}/{REPO}/blob/master/{FILE_PATH}"),
+        "git_url": format!("https://api.github.com/repos/{OWNER}/{REPO}/git/blobs/95b966ae1c166bd92f8ae7d1c313e738c731dfc3"),
+        "download_url": format!("https://raw.githubusercontent.com/{OWNER}/{REPO}/master/{FILE_PATH}"),
+        "type": "file",
+        "_links": {
+            "self": url,
+            "git": format!("https://api.github.com/repos/{OWNER}/{REPO}/git/blobs/95b966ae1c166bd92f8ae7d1c313e738c731dfc3"),
+            "html": format!("https://github.com/{OWNER}/{REPO}/blob/master/{FILE_PATH}")
+        }
+    })
+}
+
+fn committer() -> CommitAuthor {
+    CommitAuthor {
+        name: "Octocat".to_string(),
+        email: "octocat@github.com".to_string(),
+        date: None,
+    }
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_update_file_with_sha() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/contents/{FILE_PATH}")))
+        .and(body_partial_json(json!({
+            "message": "Updated ferris.txt",
+            "content": "SGkgRmVycmlzIQ==",
+            "sha": "95b966ae1c166bd92f8ae7d1c313e738c731dfc3",
+            "branch": "master",
+            "committer": {
+                "name": "Octocat",
+                "email": "octocat@github.com"
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "content": content(),
+            "commit": commit()
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .update_file(
+            FILE_PATH,
+            "Updated ferris.txt",
+            b"Hi Ferris!",
+            "95b966ae1c166bd92f8ae7d1c313e738c731dfc3",
+        )
+        .branch("master")
+        .committer(committer())
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let update = result.unwrap();
+    assert_eq!(update.commit.sha.as_deref(), Some(COMMIT_SHA));
+    assert_eq!(update.content.path, FILE_PATH);
+}
+
+#[tokio::test]
+async fn should_delete_file_with_sha() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/contents/{FILE_PATH}")))
+        .and(body_partial_json(json!({
+            "message": "Deleted ferris.txt",
+            "sha": "95b966ae1c166bd92f8ae7d1c313e738c731dfc3"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "content": null,
+            "commit": commit()
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .delete_file(
+            FILE_PATH,
+            "Deleted ferris.txt",
+            "95b966ae1c166bd92f8ae7d1c313e738c731dfc3",
+        )
+        .committer(committer())
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let deletion = result.unwrap();
+    assert!(deletion.content.is_none());
+    assert_eq!(deletion.commit.sha.as_deref(), Some(COMMIT_SHA));
+}
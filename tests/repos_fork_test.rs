@@ -0,0 +1,114 @@
+use octocrab::{models::Repository, Octocrab};
+use serde_json::json;
+#[cfg(feature = "poll")]
+use std::time::Duration;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "octocat";
+const REPO: &str = "Hello-World";
+const FORK_OWNER: &str = "weyland-yutani";
+
+#[cfg(feature = "poll")]
+fn not_found() -> ResponseTemplate {
+    ResponseTemplate::new(404).set_body_json(json!({
+        "message": "Not Found",
+        "documentation_url": "https://docs.github.com/rest/repos/repos#get-a-repository"
+    }))
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_create_fork_with_options() {
+    let repo: Repository = serde_json::from_str(include_str!("resources/repository.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/forks")))
+        .and(body_json(json!({
+            "organization": FORK_OWNER,
+            "name": "hello-fork",
+            "default_branch_only": true
+        })))
+        .respond_with(ResponseTemplate::new(202).set_body_json(repo))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .create_fork()
+        .organization(FORK_OWNER)
+        .name("hello-fork")
+        .default_branch_only(true)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[cfg(feature = "poll")]
+#[tokio::test]
+async fn should_wait_until_fork_is_ready() {
+    let repo: Repository = serde_json::from_str(include_str!("resources/repository.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{FORK_OWNER}/{REPO}")))
+        .respond_with(not_found())
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{FORK_OWNER}/{REPO}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(repo))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(FORK_OWNER, REPO)
+        .wait_until_ready(3, Duration::from_millis(1))
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[cfg(feature = "poll")]
+#[tokio::test]
+async fn should_give_up_waiting_after_attempts() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{FORK_OWNER}/{REPO}")))
+        .respond_with(not_found())
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(FORK_OWNER, REPO)
+        .wait_until_ready(2, Duration::from_millis(1))
+        .await;
+
+    match result {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, http::StatusCode::NOT_FOUND);
+        }
+        result => panic!("expected a GitHub error, got: {:#?}", result),
+    }
+}
@@ -0,0 +1,117 @@
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+
+fn content(
+    name: &str,
+    r#type: &str,
+    encoding: Option<&str>,
+    content: Option<&str>,
+) -> serde_json::Value {
+    let url = format!("https://api.github.com/repos/{OWNER}/{REPO}/contents/{name}?ref=main");
+    json!({
+        "type": r#type,
+        "encoding": encoding,
+        "size": 23,
+        "name": name,
+        "path": name,
+        "content": content,
+        "sha": "3d21ec53a331a6f037a91c368710b99387d012c1",
+        "url": url,
+        "git_url": "https://api.github.com/repos/org/some-repo/git/blobs/3d21ec53a331a6f037a91c368710b99387d012c1",
+        "html_url": format!("https://github.com/{OWNER}/{REPO}/blob/main/{name}"),
+        "download_url": format!("https://raw.githubusercontent.com/{OWNER}/{REPO}/main/{name}"),
+        "_links": {
+            "git": "https://api.github.com/repos/org/some-repo/git/blobs/3d21ec53a331a6f037a91c368710b99387d012c1",
+            "self": url,
+            "html": format!("https://github.com/{OWNER}/{REPO}/blob/main/{name}")
+        }
+    })
+}
+
+async fn setup_get_content_api(content_path: &str, body: serde_json::Value) -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/contents/{content_path}"
+        )))
+        .and(query_param("ref", "main"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_get_and_decode_file() {
+    let body = content(
+        "src/lib.rs",
+        "file",
+        Some("base64"),
+        Some("cHViIGZuIG1haW4oKSB7\nfQo=\n"),
+    );
+    let mock_server = setup_get_content_api("src/lib.rs", body).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .get_content()
+        .path("src/lib.rs")
+        .r#ref("main")
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let items = result.unwrap().take_items();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].encoding.as_deref(), Some("base64"));
+    assert_eq!(
+        items[0].decoded_content().as_deref(),
+        Some("pub fn main() {}\n")
+    );
+}
+
+#[tokio::test]
+async fn should_list_directory_entries() {
+    let body = json!([
+        content("src/lib.rs", "file", None, None),
+        content("src/api", "dir", None, None),
+    ]);
+    let mock_server = setup_get_content_api("src", body).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .get_content()
+        .path("src")
+        .r#ref("main")
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let items = result.unwrap().take_items();
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[1].r#type, "dir");
+    assert!(items[0].decoded_content().is_none());
+}
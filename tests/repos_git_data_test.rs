@@ -0,0 +1,130 @@
+use octocrab::{models::repos::TreeEntryType, params::repos::BlobEncoding, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const TREE_SHA: &str = "9fb037999f264ba9a7fc6274d15fa3ae2ab98312";
+const BLOB_SHA: &str = "3a0f86fb8db8eea7ccbb9a95f325ddbedfb25e15";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_get_truncated_recursive_tree() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/git/trees/{TREE_SHA}")))
+        .and(query_param("recursive", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "sha": TREE_SHA,
+            "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/git/trees/{TREE_SHA}"),
+            "tree": [
+                {
+                    "path": "file.rb",
+                    "mode": "100644",
+                    "type": "blob",
+                    "size": 30,
+                    "sha": "44b4fc6d56897b048c772eb4087f854f46256132",
+                    "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/git/blobs/44b4fc6d56897b048c772eb4087f854f46256132")
+                },
+                {
+                    "path": "subdir",
+                    "mode": "040000",
+                    "type": "tree",
+                    "sha": "f484d249c660418515fb01c2b9662073663c242e",
+                    "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/git/trees/f484d249c660418515fb01c2b9662073663c242e")
+                }
+            ],
+            "truncated": true
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .get_tree(TREE_SHA)
+        .recursive(true)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let tree = result.unwrap();
+    assert!(tree.truncated);
+    assert_eq!(tree.tree.len(), 2);
+    assert_eq!(tree.tree[0].size, Some(30));
+    assert_eq!(tree.tree[1].r#type, TreeEntryType::Tree);
+    assert_eq!(tree.tree[1].size, None);
+}
+
+#[tokio::test]
+async fn should_get_and_decode_blob() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/git/blobs/{BLOB_SHA}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "content": "Q29udGVudCBvZiB0aGUg\nYmxvYg==\n",
+            "encoding": "base64",
+            "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/git/blobs/{BLOB_SHA}"),
+            "sha": BLOB_SHA,
+            "size": 19,
+            "node_id": "Q29udGVudCBvZiB0aGUgYmxvYg=="
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).get_blob(BLOB_SHA).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(
+        result.unwrap().decoded().as_deref(),
+        Some(&b"Content of the blob"[..])
+    );
+}
+
+#[tokio::test]
+async fn should_create_blob() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/git/blobs")))
+        .and(body_json(json!({
+            "content": "Q29udGVudCBvZiB0aGUgYmxvYg==",
+            "encoding": "base64"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/git/blobs/{BLOB_SHA}"),
+            "sha": BLOB_SHA
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .create_blob("Q29udGVudCBvZiB0aGUgYmxvYg==", BlobEncoding::Base64)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().sha, BLOB_SHA);
+}
@@ -0,0 +1,211 @@
+use octocrab::{
+    models::{
+        hooks::{Config, ContentType},
+        webhook_events::WebhookEventType,
+    },
+    Octocrab,
+};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const HOOK_ID: u64 = 12345678;
+
+fn hook() -> serde_json::Value {
+    json!({
+        "type": "Repository",
+        "id": HOOK_ID,
+        "name": "web",
+        "active": true,
+        "events": ["push", "pull_request"],
+        "config": {
+            "content_type": "json",
+            "insecure_ssl": "0",
+            "secret": "********",
+            "url": "https://example.com/webhook"
+        },
+        "updated_at": "2019-06-03T00:57:16Z",
+        "created_at": "2019-06-03T00:57:16Z",
+        "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/hooks/{HOOK_ID}"),
+        "test_url": format!("https://api.github.com/repos/{OWNER}/{REPO}/hooks/{HOOK_ID}/test"),
+        "ping_url": format!("https://api.github.com/repos/{OWNER}/{REPO}/hooks/{HOOK_ID}/pings"),
+        "deliveries_url": format!("https://api.github.com/repos/{OWNER}/{REPO}/hooks/{HOOK_ID}/deliveries"),
+        "last_response": {
+            "code": null,
+            "status": "unused",
+            "message": null
+        }
+    })
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_create_hook_sending_the_secret() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/hooks")))
+        .and(body_json(json!({
+            "name": "web",
+            "config": {
+                "content_type": "json",
+                "url": "https://example.com/webhook",
+                "secret": "shh"
+            },
+            "events": ["push", "pull_request"]
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(hook()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .hooks()
+        .create(Config {
+            url: "https://example.com/webhook".to_string(),
+            content_type: Some(ContentType::Json),
+            insecure_ssl: None,
+            secret: Some("shh".to_string()),
+        })
+        .events(vec![WebhookEventType::Push, WebhookEventType::PullRequest])
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let hook = result.unwrap();
+    assert_eq!(hook.id, HOOK_ID);
+    assert_eq!(hook.config.secret, None);
+}
+
+#[tokio::test]
+async fn should_list_and_get_hooks() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/hooks")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([hook()])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/hooks/{HOOK_ID}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(hook()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+    let repo = client.repos(OWNER, REPO);
+
+    let list = repo.hooks().list().send().await;
+    assert!(
+        list.is_ok(),
+        "expected successful result, got error: {:#?}",
+        list
+    );
+    assert_eq!(list.unwrap().items.len(), 1);
+
+    let get = repo.hooks().get(HOOK_ID).await;
+    assert!(
+        get.is_ok(),
+        "expected successful result, got error: {:#?}",
+        get
+    );
+    assert_eq!(get.unwrap().events.len(), 2);
+}
+
+#[tokio::test]
+async fn should_update_hook() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PATCH"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/hooks/{HOOK_ID}")))
+        .and(body_json(json!({
+            "remove_events": ["pull_request"],
+            "active": false
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(hook()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .hooks()
+        .update(HOOK_ID)
+        .remove_events(vec![WebhookEventType::PullRequest])
+        .active(false)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_delete_and_ping_hook() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/hooks/{HOOK_ID}")))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/hooks/{HOOK_ID}/pings")))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+    let repo = client.repos(OWNER, REPO);
+
+    let ping = repo.hooks().ping(HOOK_ID).await;
+    assert!(
+        ping.is_ok(),
+        "expected successful result, got error: {:#?}",
+        ping
+    );
+
+    let delete = repo.hooks().delete(HOOK_ID).await;
+    assert!(
+        delete.is_ok(),
+        "expected successful result, got error: {:#?}",
+        delete
+    );
+}
+
+#[tokio::test]
+async fn should_fail_to_ping_missing_hook() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/hooks/{HOOK_ID}/pings")))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "message": "Not Found",
+            "documentation_url": "https://docs.github.com/rest/webhooks/repos#ping-a-repository-webhook"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    match client.repos(OWNER, REPO).hooks().ping(HOOK_ID).await {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, http::StatusCode::NOT_FOUND);
+        }
+        result => panic!("expected a GitHub error, got: {:#?}", result),
+    }
+}
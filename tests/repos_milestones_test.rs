@@ -0,0 +1,154 @@
+use octocrab::{models::Milestone, params, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "octocat";
+const REPO: &str = "hello-world";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn milestone() -> serde_json::Value {
+    json!({
+        "url": "https://api.github.com/repos/octocat/hello-world/milestones/1",
+        "html_url": "https://github.com/octocat/hello-world/milestones/v1.0",
+        "labels_url": "https://api.github.com/repos/octocat/hello-world/milestones/1/labels",
+        "id": 1002604,
+        "node_id": "MDk6TWlsZXN0b25lMTAwMjYwNA==",
+        "number": 1,
+        "state": "open",
+        "title": "v1.0",
+        "description": "Tracking milestone for version 1.0",
+        "open_issues": 4,
+        "closed_issues": 8,
+        "created_at": "2011-04-10T20:09:31Z",
+        "updated_at": "2014-03-03T18:58:10Z",
+        "closed_at": null,
+        "due_on": "2012-10-09T23:39:01Z"
+    })
+}
+
+#[tokio::test]
+async fn should_list_milestones() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/milestones")))
+        .and(query_param("state", "closed"))
+        .and(query_param("sort", "completeness"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([milestone()])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .milestones()
+        .list()
+        .state(params::State::Closed)
+        .sort(params::milestones::Sort::Completeness)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().items[0].title, "v1.0");
+}
+
+#[tokio::test]
+async fn should_create_milestone() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/milestones")))
+        .and(body_json(json!({
+            "title": "v1.0",
+            "description": "Tracking milestone for version 1.0"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(milestone()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .milestones()
+        .create("v1.0")
+        .description("Tracking milestone for version 1.0")
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_get_and_update_milestone() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/milestones/1")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(milestone()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/milestones/1")))
+        .and(body_json(json!({ "state": "closed" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(milestone()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+    let repo = client.repos(OWNER, REPO);
+
+    let result = repo.milestones().get(1).await;
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let milestone: Milestone = result.unwrap();
+    assert_eq!(milestone.number, 1);
+
+    let result = repo
+        .milestones()
+        .update(1)
+        .state(params::milestones::State::Closed)
+        .send()
+        .await;
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_delete_milestone() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/milestones/1")))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).milestones().delete(1).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
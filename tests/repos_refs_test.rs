@@ -0,0 +1,88 @@
+use octocrab::{params::repos::Reference, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const SHA: &str = "aa218f56b14c9653891f9e74264a383fa43fefbd";
+
+fn reference(name: &str) -> serde_json::Value {
+    json!({
+        "ref": name,
+        "node_id": "MDM6UmVmcmVmcy9oZWFkcy9mZWF0dXJlQQ==",
+        "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/git/{name}"),
+        "object": {
+            "type": "commit",
+            "sha": SHA,
+            "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/git/commits/{SHA}")
+        }
+    })
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_update_reference() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PATCH"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/git/refs/heads/release"
+        )))
+        .and(body_json(json!({ "sha": SHA, "force": true })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(reference("refs/heads/release")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .update_ref(&Reference::Branch("release".to_string()), SHA, true)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let reference = result.unwrap();
+    assert_eq!(reference.ref_field, "refs/heads/release");
+    assert!(matches!(
+        reference.object,
+        octocrab::models::repos::Object::Commit { ref sha, .. } if sha == SHA
+    ));
+}
+
+#[tokio::test]
+async fn should_fail_to_create_existing_reference() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/git/refs")))
+        .and(body_json(json!({ "ref": "refs/tags/1.0", "sha": SHA })))
+        .respond_with(ResponseTemplate::new(422).set_body_json(json!({
+            "message": "Reference already exists",
+            "documentation_url": "https://docs.github.com/rest/git/refs#create-a-reference"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .create_ref(&Reference::Tag("1.0".to_string()), SHA)
+        .await;
+
+    match result {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, http::StatusCode::UNPROCESSABLE_ENTITY);
+            assert_eq!(source.message, "Reference already exists");
+        }
+        result => panic!("expected a GitHub error, got: {:#?}", result),
+    }
+}
@@ -0,0 +1,203 @@
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_bytes, header, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const RELEASE_ID: u64 = 1;
+const DATA: &[u8] = &[0x1f, 0x8b, 0x08, 0x00, 0xff, 0xfe];
+
+fn asset() -> serde_json::Value {
+    json!({
+        "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/releases/assets/1"),
+        "browser_download_url": format!("https://github.com/{OWNER}/{REPO}/releases/download/v1.0.0/octocrab.tar.gz"),
+        "id": 1,
+        "node_id": "MDEyOlJlbGVhc2VBc3NldDE=",
+        "name": "octocrab.tar.gz",
+        "label": "Source code",
+        "state": "uploaded",
+        "content_type": "application/gzip",
+        "size": 6,
+        "download_count": 0,
+        "created_at": "2013-02-27T19:35:32Z",
+        "updated_at": "2013-02-27T19:35:32Z",
+        "uploader": null
+    })
+}
+
+/// Mounts the upload endpoint on its own server, so requests reaching it
+/// prove the upload host is used instead of the API host.
+async fn setup_upload_api(template: ResponseTemplate) -> MockServer {
+    let upload_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/releases/{RELEASE_ID}/assets"
+        )))
+        .and(query_param("name", "octocrab.tar.gz"))
+        .and(query_param("label", "Source code"))
+        .and(header("Content-Type", "application/gzip"))
+        .and(header("Authorization", "Bearer secret"))
+        .and(body_bytes(DATA))
+        .respond_with(template)
+        .expect(1)
+        .mount(&upload_server)
+        .await;
+
+    upload_server
+}
+
+async fn setup_octocrab(upload_uri: &str) -> (MockServer, Octocrab) {
+    let api_server = MockServer::start().await;
+    let client = Octocrab::builder()
+        .base_uri(api_server.uri())
+        .unwrap()
+        .upload_uri(upload_uri)
+        .unwrap()
+        .personal_token("secret".to_string())
+        .build()
+        .unwrap();
+    (api_server, client)
+}
+
+#[tokio::test]
+async fn should_upload_asset_to_upload_host() {
+    let upload_server = setup_upload_api(ResponseTemplate::new(201).set_body_json(asset())).await;
+    let (_api_server, client) = setup_octocrab(&upload_server.uri()).await;
+
+    let result = client
+        .repos(OWNER, REPO)
+        .releases()
+        .upload_asset(RELEASE_ID, "octocrab.tar.gz", "application/gzip", DATA)
+        .label("Source code")
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let asset = result.unwrap();
+    assert_eq!(asset.name, "octocrab.tar.gz");
+    assert_eq!(asset.content_type, "application/gzip");
+}
+
+fn chunks() -> impl futures::Stream<Item = Result<bytes::Bytes, std::io::Error>> {
+    futures::stream::iter(
+        DATA.chunks(4)
+            .map(|chunk| Ok(bytes::Bytes::copy_from_slice(chunk)))
+            .collect::<Vec<_>>(),
+    )
+}
+
+#[tokio::test]
+async fn should_stream_asset_to_upload_host() {
+    let upload_server = setup_upload_api(ResponseTemplate::new(201).set_body_json(asset())).await;
+    let (_api_server, client) = setup_octocrab(&upload_server.uri()).await;
+
+    let result = client
+        .repos(OWNER, REPO)
+        .releases()
+        .upload_asset_stream(RELEASE_ID, "octocrab.tar.gz", "application/gzip", chunks())
+        .label("Source code")
+        .content_length(DATA.len() as u64)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().name, "octocrab.tar.gz");
+    let requests = upload_server.received_requests().await.unwrap();
+    assert_eq!(
+        requests[0].headers.get("Content-Length").unwrap(),
+        &DATA.len().to_string()
+    );
+}
+
+#[tokio::test]
+async fn should_stream_asset_with_chunked_encoding() {
+    let upload_server = setup_upload_api(ResponseTemplate::new(201).set_body_json(asset())).await;
+    let (_api_server, client) = setup_octocrab(&upload_server.uri()).await;
+
+    let result = client
+        .repos(OWNER, REPO)
+        .releases()
+        .upload_asset_stream(RELEASE_ID, "octocrab.tar.gz", "application/gzip", chunks())
+        .label("Source code")
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let requests = upload_server.received_requests().await.unwrap();
+    assert_eq!(
+        requests[0].headers.get("Transfer-Encoding").unwrap(),
+        "chunked"
+    );
+}
+
+#[tokio::test]
+async fn should_not_retry_streamed_asset() {
+    // `expect(1)` fails the test if the server error is retried
+    let upload_server = setup_upload_api(ResponseTemplate::new(503).set_body_json(json!({
+        "message": "Service Unavailable",
+        "documentation_url": "https://docs.github.com/rest"
+    })))
+    .await;
+    let (_api_server, client) = setup_octocrab(&upload_server.uri()).await;
+
+    let result = client
+        .repos(OWNER, REPO)
+        .releases()
+        .upload_asset_stream(RELEASE_ID, "octocrab.tar.gz", "application/gzip", chunks())
+        .label("Source code")
+        .send()
+        .await;
+
+    match result {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, http::StatusCode::SERVICE_UNAVAILABLE);
+        }
+        result => panic!("expected a GitHub error, got: {:#?}", result),
+    }
+}
+
+#[tokio::test]
+async fn should_fail_to_upload_existing_asset() {
+    let upload_server = setup_upload_api(ResponseTemplate::new(422).set_body_json(json!({
+        "message": "Validation Failed",
+        "errors": [{
+            "resource": "ReleaseAsset",
+            "code": "already_exists",
+            "field": "name"
+        }],
+        "documentation_url": "https://docs.github.com/rest/releases/assets#upload-a-release-asset"
+    })))
+    .await;
+    let (_api_server, client) = setup_octocrab(&upload_server.uri()).await;
+
+    let result = client
+        .repos(OWNER, REPO)
+        .releases()
+        .upload_asset(RELEASE_ID, "octocrab.tar.gz", "application/gzip", DATA)
+        .label("Source code")
+        .send()
+        .await;
+
+    match result {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, http::StatusCode::UNPROCESSABLE_ENTITY);
+        }
+        result => panic!("expected a GitHub error, got: {:#?}", result),
+    }
+}
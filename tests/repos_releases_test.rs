@@ -0,0 +1,126 @@
+use octocrab::{models::repos::Release, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_omit_unset_fields_when_creating() {
+    let release: Release = serde_json::from_str(include_str!("resources/release.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/releases")))
+        .and(body_json(json!({
+            "tag_name": "v1.0.0",
+            "name": "Version 1.0.0",
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(&release))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .releases()
+        .create("v1.0.0")
+        .name("Version 1.0.0")
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().id, release.id);
+}
+
+#[tokio::test]
+async fn should_request_generated_release_notes() {
+    let release: Release = serde_json::from_str(include_str!("resources/release.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/releases")))
+        .and(body_json(json!({
+            "tag_name": "v1.0.0",
+            "target_commitish": "main",
+            "draft": true,
+            "prerelease": false,
+            "generate_release_notes": true,
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(&release))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .releases()
+        .create("v1.0.0")
+        .target_commitish("main")
+        .draft(true)
+        .prerelease(false)
+        .generate_release_notes(true)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_delete_release() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/releases/1")))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).releases().delete(1).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_fail_to_delete_missing_release() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/releases/1")))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "message": "Not Found",
+            "documentation_url": "https://docs.github.com/rest/releases/releases#delete-a-release"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).releases().delete(1).await;
+
+    match result {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, http::StatusCode::NOT_FOUND);
+        }
+        result => panic!("expected a GitHub error, got: {:#?}", result),
+    }
+}
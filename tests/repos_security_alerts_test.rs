@@ -0,0 +1,282 @@
+use octocrab::models::repos::{
+    code_scanning::CodeScanningAlertState,
+    dependabot::{AdvisorySeverity, DependabotAlertState},
+};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+
+fn dependabot_alert() -> serde_json::Value {
+    json!({
+        "number": 2,
+        "state": "open",
+        "dependency": {
+            "package": { "ecosystem": "rust", "name": "hyper" },
+            "manifest_path": "Cargo.lock",
+            "scope": "runtime"
+        },
+        "security_advisory": {
+            "ghsa_id": "GHSA-f3pg-qwvg-p99c",
+            "cve_id": "CVE-2021-32714",
+            "summary": "Integer overflow in hyper's parsing of the Transfer-Encoding header",
+            "description": "hyper can overflow when decoding chunk sizes that are too big.",
+            "vulnerabilities": [{
+                "package": { "ecosystem": "rust", "name": "hyper" },
+                "severity": "critical",
+                "vulnerable_version_range": "< 0.14.10",
+                "first_patched_version": { "identifier": "0.14.10" }
+            }],
+            "severity": "critical",
+            "cvss": {
+                "vector_string": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:N/I:H/A:H",
+                "score": 9.1
+            },
+            "cwes": [{ "cwe_id": "CWE-190", "name": "Integer Overflow or Wraparound" }],
+            "identifiers": [
+                { "type": "GHSA", "value": "GHSA-f3pg-qwvg-p99c" },
+                { "type": "CVE", "value": "CVE-2021-32714" }
+            ],
+            "references": [{ "url": "https://nvd.nist.gov/vuln/detail/CVE-2021-32714" }],
+            "published_at": "2021-07-12T16:54:20Z",
+            "updated_at": "2023-01-09T05:02:49Z",
+            "withdrawn_at": null
+        },
+        "security_vulnerability": {
+            "package": { "ecosystem": "rust", "name": "hyper" },
+            "severity": "critical",
+            "vulnerable_version_range": "< 0.14.10",
+            "first_patched_version": { "identifier": "0.14.10" }
+        },
+        "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/dependabot/alerts/2"),
+        "html_url": format!("https://github.com/{OWNER}/{REPO}/security/dependabot/2"),
+        "created_at": "2022-06-15T07:43:03Z",
+        "updated_at": "2022-08-23T14:29:47Z",
+        "dismissed_at": null,
+        "dismissed_by": null,
+        "dismissed_reason": null,
+        "dismissed_comment": null,
+        "fixed_at": null,
+        "auto_dismissed_at": null
+    })
+}
+
+fn code_scanning_alert() -> serde_json::Value {
+    json!({
+        "number": 4,
+        "created_at": "2020-02-13T12:29:18Z",
+        "updated_at": "2020-02-13T12:29:18Z",
+        "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/code-scanning/alerts/4"),
+        "html_url": format!("https://github.com/{OWNER}/{REPO}/code-scanning/4"),
+        "state": "open",
+        "fixed_at": null,
+        "dismissed_by": null,
+        "dismissed_at": null,
+        "dismissed_reason": null,
+        "dismissed_comment": null,
+        "rule": {
+            "id": "js/zipslip",
+            "severity": "error",
+            "tags": ["security", "external/cwe/cwe-022"],
+            "description": "Arbitrary file write during zip extraction",
+            "name": "js/zipslip",
+            "security_severity_level": "high"
+        },
+        "tool": { "name": "CodeQL", "guid": null, "version": "2.4.0" },
+        "most_recent_instance": {
+            "ref": "refs/heads/main",
+            "analysis_key": ".github/workflows/codeql-analysis.yml:CodeQL-Build",
+            "environment": "{}",
+            "category": ".github/workflows/codeql-analysis.yml:CodeQL-Build",
+            "state": "open",
+            "commit_sha": "39406e42cb832f683daa691dd652a8dc36ee8930",
+            "message": { "text": "This path depends on a user-provided value." },
+            "location": {
+                "path": "spec-main/api-session-spec.ts",
+                "start_line": 917,
+                "end_line": 917,
+                "start_column": 7,
+                "end_column": 18
+            },
+            "classifications": ["test"]
+        },
+        "instances_url": format!("https://api.github.com/repos/{OWNER}/{REPO}/code-scanning/alerts/4/instances")
+    })
+}
+
+async fn setup_api(route: &str, template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/{route}")))
+        .respond_with(template)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn forbidden(message: &str) -> ResponseTemplate {
+    ResponseTemplate::new(403).set_body_json(json!({
+        "message": message,
+        "documentation_url": "https://docs.github.com/rest"
+    }))
+}
+
+#[tokio::test]
+async fn should_list_dependabot_alerts() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/dependabot/alerts")))
+        .and(query_param("state", "open"))
+        .and(query_param("severity", "critical"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([dependabot_alert()])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .list_dependabot_alerts()
+        .state(octocrab::params::repos::dependabot::State::Open)
+        .severity(octocrab::params::repos::dependabot::Severity::Critical)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let alerts = result.unwrap().items;
+    assert_eq!(alerts.len(), 1);
+    let alert = &alerts[0];
+    assert_eq!(alert.state, DependabotAlertState::Open);
+    assert_eq!(alert.dependency.package.name, "hyper");
+    assert_eq!(alert.security_advisory.ghsa_id, "GHSA-f3pg-qwvg-p99c");
+    assert_eq!(alert.security_advisory.severity, AdvisorySeverity::Critical);
+    assert_eq!(
+        alert
+            .security_vulnerability
+            .first_patched_version
+            .as_ref()
+            .unwrap()
+            .identifier,
+        "0.14.10"
+    );
+}
+
+#[tokio::test]
+async fn should_list_code_scanning_alerts() {
+    let mock_server = setup_api(
+        "code-scanning/alerts",
+        ResponseTemplate::new(200).set_body_json(json!([code_scanning_alert()])),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .list_code_scanning_alerts()
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let alerts = result.unwrap().items;
+    assert_eq!(alerts.len(), 1);
+    let alert = &alerts[0];
+    assert_eq!(alert.state, CodeScanningAlertState::Open);
+    assert_eq!(alert.rule.id.as_deref(), Some("js/zipslip"));
+    assert_eq!(alert.rule.security_severity_level.as_deref(), Some("high"));
+    assert_eq!(alert.tool.name, "CodeQL");
+    let location = alert
+        .most_recent_instance
+        .as_ref()
+        .unwrap()
+        .location
+        .as_ref()
+        .unwrap();
+    assert_eq!(location.start_line, 917);
+}
+
+#[tokio::test]
+async fn should_report_disabled_dependabot_alerts() {
+    let mock_server = setup_api(
+        "dependabot/alerts",
+        forbidden("Dependabot alerts are disabled for this repository."),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .list_dependabot_alerts()
+        .send()
+        .await;
+
+    match result {
+        Err(octocrab::Error::FeatureNotEnabled { source, .. }) => {
+            assert_eq!(source.status_code, http::StatusCode::FORBIDDEN);
+        }
+        result => panic!("expected a FeatureNotEnabled error, got: {:#?}", result),
+    }
+}
+
+#[tokio::test]
+async fn should_report_disabled_code_scanning() {
+    let mock_server = setup_api(
+        "code-scanning/alerts",
+        forbidden("Advanced Security must be enabled for this repository to use code scanning."),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .list_code_scanning_alerts()
+        .send()
+        .await;
+
+    match result {
+        Err(octocrab::Error::FeatureNotEnabled { source, .. }) => {
+            assert_eq!(source.status_code, http::StatusCode::FORBIDDEN);
+        }
+        result => panic!("expected a FeatureNotEnabled error, got: {:#?}", result),
+    }
+}
+
+#[tokio::test]
+async fn should_keep_permission_errors_as_github_errors() {
+    let mock_server = setup_api(
+        "dependabot/alerts",
+        forbidden("Resource not accessible by integration"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .list_dependabot_alerts()
+        .send()
+        .await;
+
+    match result {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, http::StatusCode::FORBIDDEN);
+        }
+        result => panic!("expected a GitHub error, got: {:#?}", result),
+    }
+}
@@ -0,0 +1,161 @@
+use octocrab::models::TagProtectionId;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+
+fn tag_protection() -> serde_json::Value {
+    json!({
+        "id": 2,
+        "pattern": "v1.*",
+        "created_at": "2011-01-26T19:01:12Z",
+        "updated_at": "2011-01-26T19:01:12Z",
+        "enabled": true
+    })
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_list_tags_in_creation_order() {
+    let mock_server = MockServer::start().await;
+    let tag = |name: &str| {
+        json!({
+            "name": name,
+            "commit": {
+                "sha": "c5b97d5ae6c19d5c5df71a34c7fbeeda2479ccbc",
+                "url": "https://api.github.com/repos/org/some-repo/commits/c5b97d5ae6c19d5c5df71a34c7fbeeda2479ccbc"
+            },
+            "zipball_url": format!("https://github.com/{OWNER}/{REPO}/zipball/{name}"),
+            "tarball_url": format!("https://github.com/{OWNER}/{REPO}/tarball/{name}"),
+            "node_id": "MDQ6VXNlcjE="
+        })
+    };
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/tags")))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!([tag("v1.9.0"), tag("v1.10.0")])),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).list_tags().send().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let names: Vec<_> = result
+        .unwrap()
+        .items
+        .into_iter()
+        .map(|tag| tag.name)
+        .collect();
+    assert_eq!(names, ["v1.9.0", "v1.10.0"]);
+}
+
+#[tokio::test]
+async fn should_list_tag_protections() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/tags/protection")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([tag_protection()])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).list_tag_protections().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let protections = result.unwrap();
+    assert_eq!(protections.len(), 1);
+    assert_eq!(protections[0].id, TagProtectionId(2));
+    assert_eq!(protections[0].pattern, "v1.*");
+}
+
+#[tokio::test]
+async fn should_create_tag_protection() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/tags/protection")))
+        .and(body_json(json!({ "pattern": "v1.*" })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(tag_protection()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .create_tag_protection("v1.*")
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().enabled, Some(true));
+}
+
+#[tokio::test]
+async fn should_delete_tag_protection() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/tags/protection/2")))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).delete_tag_protection(2u64).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_fail_to_delete_missing_tag_protection() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/tags/protection/3")))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "message": "Not Found",
+            "documentation_url": "https://docs.github.com/rest/repos/tags#delete-a-tag-protection-state-for-a-repository"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .delete_tag_protection(TagProtectionId(3))
+        .await;
+
+    match result {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, http::StatusCode::NOT_FOUND);
+        }
+        result => panic!("expected a GitHub error, got: {:#?}", result),
+    }
+}
@@ -0,0 +1,142 @@
+use chrono::{TimeZone, Utc};
+use octocrab::{params::repos::traffic::Per, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockBuilder, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+
+fn traffic(route: &str) -> MockBuilder {
+    Mock::given(method("GET")).and(path(format!("/repos/{OWNER}/{REPO}/traffic/{route}")))
+}
+
+async fn setup_api(mock: MockBuilder, template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+    mock.respond_with(template)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_get_views_per_week() {
+    let mock_server = setup_api(
+        traffic("views").and(query_param("per", "week")),
+        ResponseTemplate::new(200).set_body_json(json!({
+            "count": 14850,
+            "uniques": 3782,
+            "views": [
+                { "timestamp": "2016-10-10T00:00:00Z", "count": 440, "uniques": 143 },
+                { "timestamp": "2016-10-17T00:00:00Z", "count": 1308, "uniques": 414 }
+            ]
+        })),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).traffic().views(Per::Week).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let views = result.unwrap();
+    assert_eq!(views.count, 14850);
+    assert_eq!(views.uniques, 3782);
+    assert_eq!(views.views.len(), 2);
+    assert_eq!(
+        views.views[1].timestamp,
+        Utc.with_ymd_and_hms(2016, 10, 17, 0, 0, 0).unwrap()
+    );
+    assert_eq!(views.views[1].count, 1308);
+}
+
+#[tokio::test]
+async fn should_get_clones_per_day() {
+    let mock_server = setup_api(
+        traffic("clones").and(query_param("per", "day")),
+        ResponseTemplate::new(200).set_body_json(json!({
+            "count": 173,
+            "uniques": 128,
+            "clones": [
+                { "timestamp": "2016-10-10T00:00:00Z", "count": 2, "uniques": 1 }
+            ]
+        })),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).traffic().clones(Per::Day).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let clones = result.unwrap();
+    assert_eq!(clones.count, 173);
+    assert_eq!(clones.clones[0].uniques, 1);
+}
+
+#[tokio::test]
+async fn should_get_top_referrers_and_paths() {
+    let mock_server = MockServer::start().await;
+    traffic("popular/referrers")
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            { "referrer": "Google", "count": 4, "uniques": 3 }
+        ])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    traffic("popular/paths")
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([{
+            "path": format!("/{OWNER}/{REPO}"),
+            "title": format!("{OWNER}/{REPO}"),
+            "count": 3542,
+            "uniques": 2225
+        }])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+    let repo = client.repos(OWNER, REPO);
+
+    let referrers = repo.traffic().top_referrers().await.unwrap();
+    let paths = repo.traffic().top_paths().await.unwrap();
+
+    assert_eq!(referrers[0].referrer, "Google");
+    assert_eq!(referrers[0].uniques, 3);
+    assert_eq!(paths[0].path, format!("/{OWNER}/{REPO}"));
+    assert_eq!(paths[0].count, 3542);
+}
+
+#[tokio::test]
+async fn should_fail_without_push_access() {
+    let mock_server = setup_api(
+        traffic("views"),
+        ResponseTemplate::new(403).set_body_json(json!({
+            "message": "Must have push access to repository",
+            "documentation_url": "https://docs.github.com/rest/metrics/traffic#get-page-views"
+        })),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).traffic().views(Per::Day).await;
+
+    match result {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, http::StatusCode::FORBIDDEN);
+        }
+        result => panic!("expected a GitHub error, got: {:#?}", result),
+    }
+}
@@ -0,0 +1,79 @@
+use octocrab::models::{Repository, TeamId};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const NEW_OWNER: &str = "new-org";
+
+fn repository() -> Repository {
+    serde_json::from_str(include_str!("resources/repository.json")).unwrap()
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_transfer_repository() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/transfer")))
+        .and(body_json(
+            json!({ "new_owner": NEW_OWNER, "team_ids": [12, 345] }),
+        ))
+        .respond_with(ResponseTemplate::new(202).set_body_json(repository()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .transfer(NEW_OWNER)
+        .team_ids(vec![TeamId(12), TeamId(345)])
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().name, repository().name);
+}
+
+#[cfg(feature = "follow-redirect")]
+#[tokio::test]
+async fn should_follow_redirect_from_old_path() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}")))
+        .respond_with(ResponseTemplate::new(301).insert_header(
+            "Location",
+            format!("{}/repositories/1", mock_server.uri()).as_str(),
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repositories/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(repository()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).get().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().id, repository().id);
+}
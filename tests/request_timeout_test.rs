@@ -0,0 +1,64 @@
+#![cfg(all(feature = "timeout", feature = "retry"))]
+
+use std::time::Duration;
+
+use octocrab::{service::middleware::retry::RetryConfig, Octocrab};
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str, retry_config: RetryConfig) -> Octocrab {
+    let mut builder = Octocrab::builder().base_uri(uri).unwrap();
+    builder.add_retry_config(retry_config);
+    builder
+        .set_request_timeout(Some(Duration::from_millis(100)))
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn should_return_timeout_error() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/zen"))
+        .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(5)))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri(), RetryConfig::None);
+
+    let result = client._get("/zen").await;
+
+    match result {
+        Err(octocrab::Error::Timeout { .. }) => {}
+        result => panic!("expected a timeout error, got: {:#?}", result),
+    }
+}
+
+#[tokio::test]
+async fn should_retry_timed_out_request() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/zen"))
+        .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(5)))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/zen"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("Keep it logically awesome."))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri(), RetryConfig::Simple(1));
+
+    let result = client._get("/zen").await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
@@ -0,0 +1,100 @@
+#![cfg(feature = "retry")]
+
+use std::time::Duration;
+
+use octocrab::{service::middleware::retry::BackoffConfig, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    let mut builder = Octocrab::builder().base_uri(uri).unwrap();
+    builder.add_retry_config(BackoffConfig {
+        max_retries: 3,
+        base_delay: Duration::from_millis(1),
+        max_delay: Duration::from_millis(10),
+        retry_non_idempotent: false,
+    });
+    builder.build().unwrap()
+}
+
+async fn mount_rate_limited_then(mock_server: &MockServer, verb: &str, template: ResponseTemplate) {
+    let labels_path = format!("/repos/{OWNER}/{REPO}/labels");
+    Mock::given(method(verb))
+        .and(path(&labels_path))
+        .respond_with(
+            ResponseTemplate::new(429)
+                .append_header("Retry-After", "1")
+                .set_body_json(json!({
+                    "message": "You have exceeded a secondary rate limit.",
+                    "documentation_url": "https://docs.github.com/rest/overview/resources-in-the-rest-api#secondary-rate-limits"
+                })),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(mock_server)
+        .await;
+    Mock::given(method(verb))
+        .and(path(&labels_path))
+        .respond_with(template)
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn should_retry_get_after_too_many_requests() {
+    let mock_server = MockServer::start().await;
+    mount_rate_limited_then(
+        &mock_server,
+        "GET",
+        ResponseTemplate::new(200).set_body_json(json!([])),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .issues(OWNER, REPO)
+        .list_labels_for_repo()
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_not_retry_post_by_default() {
+    let mock_server = MockServer::start().await;
+    mount_rate_limited_then(
+        &mock_server,
+        "POST",
+        ResponseTemplate::new(201).set_body_json(json!({})),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .issues(OWNER, REPO)
+        .create_label("bug", "f29513", "")
+        .await;
+
+    match result {
+        Err(octocrab::Error::SecondaryRateLimit {
+            source,
+            retry_after,
+            ..
+        }) => {
+            assert_eq!(source.status_code, http::StatusCode::TOO_MANY_REQUESTS);
+            assert_eq!(retry_after, Some(std::time::Duration::from_secs(1)));
+        }
+        result => panic!("expected a secondary rate limit error, got: {:#?}", result),
+    }
+}
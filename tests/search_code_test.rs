@@ -0,0 +1,93 @@
+use octocrab::{models::Repository, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{header, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const QUERY: &str = "addClass in:file language:js repo:jquery/jquery";
+
+fn code_result(text_matches: bool) -> serde_json::Value {
+    let repository: Repository =
+        serde_json::from_str(include_str!("resources/repository.json")).unwrap();
+    let mut item = json!({
+        "name": "classes.js",
+        "path": "src/attributes/classes.js",
+        "sha": "d7212f9dee2dcc18f084d7df8f417b80846ded5a",
+        "url": "https://api.github.com/repositories/167174/contents/src/attributes/classes.js?ref=825ac3773694e0cd23ee74895fd5aeb535b27da4",
+        "git_url": "https://api.github.com/repositories/167174/git/blobs/d7212f9dee2dcc18f084d7df8f417b80846ded5a",
+        "html_url": "https://github.com/jquery/jquery/blob/825ac3773694e0cd23ee74895fd5aeb535b27da4/src/attributes/classes.js",
+        "repository": repository,
+    });
+    if text_matches {
+        item["text_matches"] = json!([{
+            "object_url": "https://api.github.com/repositories/167174/contents/src/attributes/classes.js?ref=825ac3773694e0cd23ee74895fd5aeb535b27da4",
+            "object_type": "FileContent",
+            "property": "content",
+            "fragment": "jQuery.fn.extend( {\n\taddClass: function( value ) {",
+            "matches": [{ "text": "addClass", "indices": [21, 29] }]
+        }]);
+    }
+    json!({ "total_count": 1, "incomplete_results": false, "items": [item] })
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_request_text_matches_when_enabled() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search/code"))
+        .and(query_param("q", QUERY))
+        .and(header("Accept", "application/vnd.github.text-match+json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(code_result(true)))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.search().code(QUERY).text_match(true).send().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let text_matches = result.unwrap().items[0]
+        .text_matches
+        .clone()
+        .expect("text matches to be present");
+    assert_eq!(text_matches[0].property, "content");
+    assert_eq!(text_matches[0].matches[0].text, "addClass");
+    assert_eq!(text_matches[0].matches[0].indices, vec![21, 29]);
+}
+
+#[tokio::test]
+async fn should_not_request_text_matches_by_default() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search/code"))
+        .and(header("Accept", "application/vnd.github.text-match+json"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/search/code"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(code_result(false)))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.search().code(QUERY).send().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert!(result.unwrap().items[0].text_matches.is_none());
+}
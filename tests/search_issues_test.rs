@@ -0,0 +1,50 @@
+use octocrab::{models::issues::Issue, params, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_search_issues_and_pull_requests() {
+    let issue: Issue = serde_json::from_str(include_str!("resources/issue.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search/issues"))
+        .and(query_param("q", "is:open is:pr author:ferris"))
+        .and(query_param("sort", "comments"))
+        .and(query_param("order", "asc"))
+        .and(query_param("per_page", "100"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "total_count": 280,
+            "incomplete_results": true,
+            "items": [issue]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .search()
+        .issues_and_pull_requests("is:open is:pr author:ferris")
+        .sort("comments")
+        .order(params::Direction::Ascending)
+        .per_page(100)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let page = result.unwrap();
+    assert_eq!(page.total_count, Some(280));
+    assert_eq!(page.incomplete_results, Some(true));
+    assert_eq!(page.items.len(), 1);
+}
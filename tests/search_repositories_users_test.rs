@@ -0,0 +1,110 @@
+use octocrab::{params, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param, query_param_is_missing},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_search_repositories_by_stars() {
+    let repository: serde_json::Value =
+        serde_json::from_str(include_str!("resources/repository.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search/repositories"))
+        .and(query_param("q", "tetris language:rust"))
+        .and(query_param("sort", "stars"))
+        .and(query_param("order", "desc"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "total_count": 40,
+            "incomplete_results": false,
+            "items": [repository]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .search()
+        .repositories("tetris language:rust")
+        .sort(params::search::RepositorySort::Stars)
+        .order(params::Direction::Descending)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let page = result.unwrap();
+    assert_eq!(page.total_count, Some(40));
+    assert_eq!(page.items.len(), 1);
+}
+
+#[tokio::test]
+async fn should_only_send_query_when_options_are_unset() {
+    let issue: serde_json::Value =
+        serde_json::from_str(include_str!("resources/issue.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search/users"))
+        .and(query_param("q", "bors type:user"))
+        .and(query_param_is_missing("sort"))
+        .and(query_param_is_missing("order"))
+        .and(query_param_is_missing("per_page"))
+        .and(query_param_is_missing("page"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "total_count": 1,
+            "incomplete_results": false,
+            "items": [issue["user"]]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.search().users("bors type:user").send().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let page = result.unwrap();
+    assert_eq!(page.total_count, Some(1));
+    assert_eq!(page.items[0].login, "gagbo");
+}
+
+#[tokio::test]
+async fn should_surface_incomplete_results() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search/commits"))
+        .and(query_param("q", "fix repo:octocat/Spoon-Knife"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "total_count": 1000,
+            "incomplete_results": true,
+            "items": []
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .search()
+        .commits("fix repo:octocat/Spoon-Knife")
+        .send()
+        .await;
+
+    let page = result.unwrap();
+    assert_eq!(page.total_count, Some(1000));
+    assert_eq!(page.incomplete_results, Some(true));
+    assert!(page.items.is_empty());
+}
@@ -0,0 +1,102 @@
+// Tests for telling secondary rate limits apart from other errors.
+mod mock_error;
+
+use std::time::Duration;
+
+use mock_error::setup_error_handler;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+
+async fn setup_api(template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/issues")))
+        .respond_with(template)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("POST on /repos/{OWNER}/{REPO}/issues was not received"),
+    )
+    .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn abuse_detection() -> serde_json::Value {
+    json!({
+        "message": "You have triggered an abuse detection mechanism. Please wait a few minutes before you try again.",
+        "documentation_url": "https://docs.github.com/rest/overview/resources-in-the-rest-api#abuse-rate-limits"
+    })
+}
+
+#[tokio::test]
+async fn should_detect_abuse_detection_with_retry_after() {
+    let template = ResponseTemplate::new(403)
+        .append_header("Retry-After", "60")
+        .set_body_json(abuse_detection());
+    let mock_server = setup_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.issues(OWNER, REPO).create("title").send().await;
+
+    match result {
+        Err(octocrab::Error::SecondaryRateLimit {
+            source,
+            retry_after,
+            ..
+        }) => {
+            assert_eq!(source.status_code, 403);
+            assert_eq!(retry_after, Some(Duration::from_secs(60)));
+        }
+        result => panic!("expected a secondary rate limit error, got: {:#?}", result),
+    }
+}
+
+#[tokio::test]
+async fn should_detect_secondary_rate_limit_without_retry_after() {
+    let template = ResponseTemplate::new(403).set_body_json(json!({
+        "message": "You have exceeded a secondary rate limit and have been temporarily blocked from content creation. Please retry your request again later.",
+        "documentation_url": "https://docs.github.com/rest/overview/resources-in-the-rest-api#secondary-rate-limits"
+    }));
+    let mock_server = setup_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.issues(OWNER, REPO).create("title").send().await;
+
+    match result {
+        Err(octocrab::Error::SecondaryRateLimit { retry_after, .. }) => {
+            assert_eq!(retry_after, None);
+        }
+        result => panic!("expected a secondary rate limit error, got: {:#?}", result),
+    }
+}
+
+#[tokio::test]
+async fn should_keep_other_forbidden_errors() {
+    let template = ResponseTemplate::new(403).set_body_json(json!({
+        "message": "Resource not accessible by integration",
+        "documentation_url": "https://docs.github.com/rest/issues/issues#create-an-issue"
+    }));
+    let mock_server = setup_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.issues(OWNER, REPO).create("title").send().await;
+
+    match result {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, 403);
+        }
+        result => panic!("expected a GitHub error, got: {:#?}", result),
+    }
+}
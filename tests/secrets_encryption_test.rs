@@ -0,0 +1,79 @@
+#![cfg(feature = "secrets-encryption")]
+
+use base64::{engine::general_purpose::STANDARD as B64, Engine};
+use octocrab::{models::repos::secrets::CreateRepositorySecretResponse, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "octocat";
+const REPO: &str = "hello-world";
+const SECRET_KEY: [u8; crypto_box::KEY_SIZE] = [7; crypto_box::KEY_SIZE];
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_encrypt_secret_with_repo_public_key() {
+    let secret_key = crypto_box::SecretKey::from(SECRET_KEY);
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/actions/secrets/public-key"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "key_id": "012345678912345678",
+            "key": B64.encode(secret_key.public_key().as_bytes())
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/actions/secrets/GH_TOKEN"
+        )))
+        .respond_with(ResponseTemplate::new(201))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .actions()
+        .create_or_update_repo_secret(OWNER, REPO, "GH_TOKEN", "Very secret value")
+        .await;
+
+    assert!(
+        matches!(result, Ok(CreateRepositorySecretResponse::Created)),
+        "expected a created secret, got: {:#?}",
+        result
+    );
+    let requests = mock_server.received_requests().await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+    assert_eq!(body["key_id"], "012345678912345678");
+    let encrypted_value = B64
+        .decode(body["encrypted_value"].as_str().unwrap())
+        .unwrap();
+    let decrypted = crypto_box::seal_open(&secret_key, &encrypted_value).unwrap();
+    assert_eq!(decrypted, b"Very secret value");
+}
+
+#[tokio::test]
+async fn should_reject_invalid_public_key() {
+    let public_key: octocrab::models::PublicKey = serde_json::from_value(json!({
+        "key_id": "012345678912345678",
+        "key": B64.encode(b"too short")
+    }))
+    .unwrap();
+
+    let result = public_key.encrypt(b"Very secret value");
+
+    assert!(
+        matches!(result, Err(octocrab::Error::Other { .. })),
+        "expected an error, got: {:#?}",
+        result
+    );
+}
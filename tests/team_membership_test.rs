@@ -0,0 +1,100 @@
+use octocrab::{params, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ORG: &str = "org";
+const TEAM: &str = "team-name";
+const REPO: &str = "testing";
+const USERNAME: &str = "ferris";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_add_or_update_team_membership() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path(format!(
+            "/orgs/{ORG}/teams/{TEAM}/memberships/{USERNAME}"
+        )))
+        .and(body_json(json!({ "role": "maintainer" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "url": format!("https://api.github.com/teams/1/memberships/{USERNAME}"),
+            "role": "maintainer",
+            "state": "active"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .teams(ORG)
+        .add_or_update_membership(TEAM, USERNAME, params::teams::Role::Maintainer)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let membership = result.unwrap();
+    assert_eq!(membership.role, "maintainer");
+    assert_eq!(membership.state, "active");
+}
+
+#[tokio::test]
+async fn should_add_or_update_repo_permissions() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path(format!("/orgs/{ORG}/teams/{TEAM}/repos/{ORG}/{REPO}")))
+        .and(body_json(json!({ "permission": "triage" })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .teams(ORG)
+        .add_or_update_repo_permissions(TEAM, ORG, REPO, params::teams::Permission::Triage)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_fail_to_add_member_to_missing_team() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path(format!(
+            "/orgs/{ORG}/teams/{TEAM}/memberships/{USERNAME}"
+        )))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "message": "Not Found",
+            "documentation_url": "https://docs.github.com/rest/teams/members#add-or-update-team-membership-for-a-user"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    match client
+        .teams(ORG)
+        .add_or_update_membership(TEAM, USERNAME, params::teams::Role::Member)
+        .await
+    {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, http::StatusCode::NOT_FOUND);
+        }
+        result => panic!("expected a GitHub error, got: {:#?}", result),
+    }
+}
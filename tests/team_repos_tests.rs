@@ -87,3 +87,25 @@ async fn should_add_or_update_team_repo() {
     );
     eprintln!("Result: {result:#?}");
 }
+
+#[tokio::test]
+async fn should_return_error_when_removing_team_repo_fails() {
+    let template = ResponseTemplate::new(403).set_body_json(serde_json::json!({
+        "message": "Must have admin rights to Repository.",
+        "documentation_url": "https://docs.github.com/rest/teams/teams#remove-a-repository-from-a-team"
+    }));
+    let mock_server = setup_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+    let teams = client.teams(ORG.to_owned());
+
+    let result = teams
+        .repos(TEAM.to_owned())
+        .remove(ORG.to_owned(), REPO.to_owned())
+        .await;
+    match result {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, 403);
+        }
+        result => panic!("expected a GitHub error, got: {:#?}", result),
+    }
+}
@@ -0,0 +1,97 @@
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const USER_AGENT: &str = "my-bot/1.2";
+
+#[tokio::test]
+async fn should_send_default_user_agent() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/zen"))
+        .and(header(
+            "User-Agent",
+            concat!("octocrab/", env!("CARGO_PKG_VERSION")),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_string("Keep it logically awesome."))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let result = client._get("/zen").await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_send_custom_user_agent_to_api_and_upload_hosts() {
+    let api_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/zen"))
+        .and(header("User-Agent", USER_AGENT))
+        .respond_with(ResponseTemplate::new(200).set_body_string("Keep it logically awesome."))
+        .expect(1)
+        .mount(&api_server)
+        .await;
+    let upload_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/repos/org/some-repo/releases/1/assets"))
+        .and(header("User-Agent", USER_AGENT))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "url": "https://api.github.com/repos/org/some-repo/releases/assets/1",
+            "browser_download_url": "https://github.com/org/some-repo/releases/download/v1.0.0/notes.txt",
+            "id": 1,
+            "node_id": "MDEyOlJlbGVhc2VBc3NldDE=",
+            "name": "notes.txt",
+            "label": null,
+            "state": "uploaded",
+            "content_type": "text/plain",
+            "size": 5,
+            "download_count": 0,
+            "created_at": "2013-02-27T19:35:32Z",
+            "updated_at": "2013-02-27T19:35:32Z",
+            "uploader": null
+        })))
+        .expect(1)
+        .mount(&upload_server)
+        .await;
+    let client = Octocrab::builder()
+        .base_uri(api_server.uri())
+        .unwrap()
+        .upload_uri(upload_server.uri())
+        .unwrap()
+        .user_agent(USER_AGENT)
+        .build()
+        .unwrap();
+
+    let zen = client._get("/zen").await;
+    let upload = client
+        .repos("org", "some-repo")
+        .releases()
+        .upload_asset(1, "notes.txt", "text/plain", &b"hello"[..])
+        .send()
+        .await;
+
+    assert!(
+        zen.is_ok(),
+        "expected successful result, got error: {:#?}",
+        zen
+    );
+    assert!(
+        upload.is_ok(),
+        "expected successful result, got error: {:#?}",
+        upload
+    );
+}
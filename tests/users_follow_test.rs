@@ -0,0 +1,98 @@
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const USER: &str = "ferris";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+async fn setup_following_api(http_method: &str, template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method(http_method))
+        .and(path(format!("/user/following/{USER}")))
+        .respond_with(template)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    mock_server
+}
+
+#[tokio::test]
+async fn should_follow_user() {
+    let mock_server = setup_following_api("PUT", ResponseTemplate::new(204)).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.users(USER).follow().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_unfollow_user() {
+    let mock_server = setup_following_api("DELETE", ResponseTemplate::new(204)).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.users(USER).unfollow().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_report_followed_user() {
+    let mock_server = setup_following_api("GET", ResponseTemplate::new(204)).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.users(USER).is_following().await;
+
+    assert!(
+        matches!(result, Ok(true)),
+        "expected Ok(true), got: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_report_user_not_followed() {
+    let mock_server = setup_following_api("GET", ResponseTemplate::new(404)).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.users(USER).is_following().await;
+
+    assert!(
+        matches!(result, Ok(false)),
+        "expected Ok(false), got: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_return_other_errors_from_is_following() {
+    let template = ResponseTemplate::new(401).set_body_json(json!({
+        "message": "Bad credentials",
+        "documentation_url": "https://docs.github.com/rest"
+    }));
+    let mock_server = setup_following_api("GET", template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.users(USER).is_following().await;
+
+    match result {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, 401);
+        }
+        result => panic!("expected a GitHub error, got: {:#?}", result),
+    }
+}
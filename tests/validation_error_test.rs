@@ -0,0 +1,113 @@
+use octocrab::{Octocrab, ValidationError};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_parse_validation_errors_from_422_response() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/pulls")))
+        .respond_with(ResponseTemplate::new(422).set_body_json(json!({
+            "message": "Validation Failed",
+            "errors": [
+                {
+                    "resource": "PullRequest",
+                    "field": "head",
+                    "code": "invalid"
+                },
+                {
+                    "resource": "PullRequest",
+                    "code": "custom",
+                    "message": "No commits between main and feature"
+                },
+                "an unstructured error"
+            ],
+            "documentation_url": "https://docs.github.com/rest/pulls/pulls#create-a-pull-request"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .create("Add feature", "no-such-branch", "main")
+        .send()
+        .await;
+
+    match result {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, http::StatusCode::UNPROCESSABLE_ENTITY);
+            assert_eq!(source.message, "Validation Failed");
+            assert_eq!(
+                source.documentation_url.as_deref(),
+                Some("https://docs.github.com/rest/pulls/pulls#create-a-pull-request")
+            );
+
+            let errors: Vec<ValidationError> = source.validation_errors();
+            assert_eq!(errors.len(), 2);
+            assert_eq!(errors[0].resource.as_deref(), Some("PullRequest"));
+            assert_eq!(errors[0].field.as_deref(), Some("head"));
+            assert_eq!(errors[0].code, "invalid");
+            assert_eq!(errors[1].field, None);
+            assert_eq!(
+                errors[1].message.as_deref(),
+                Some("No commits between main and feature")
+            );
+        }
+        result => panic!("expected a GitHub error, got: {:#?}", result),
+    }
+}
+
+#[tokio::test]
+async fn should_parse_required_permission_of_fine_grained_token_error() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/pulls")))
+        .respond_with(
+            ResponseTemplate::new(403)
+                .insert_header("X-Accepted-GitHub-Permissions", "pull_requests=write")
+                .set_body_json(json!({
+                    "message": "Resource not accessible by personal access token",
+                    "documentation_url": "https://docs.github.com/rest/pulls/pulls#create-a-pull-request"
+                })),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .create("Add feature", "feature", "main")
+        .send()
+        .await;
+
+    match result {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, http::StatusCode::FORBIDDEN);
+            assert_eq!(
+                source.message,
+                "Resource not accessible by personal access token"
+            );
+            assert_eq!(
+                source.required_permission.as_deref(),
+                Some("pull_requests=write")
+            );
+            assert!(source
+                .to_string()
+                .contains("Required permission: pull_requests=write"));
+        }
+        result => panic!("expected a GitHub error, got: {:#?}", result),
+    }
+}
@@ -0,0 +1,147 @@
+// Tests for clients derived with `Octocrab::with_auth`.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::issues::Issue;
+use octocrab::{auth::Auth, Octocrab};
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+async fn setup_api() -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/zen"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("Keep it logically awesome."))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /zen was not received").await;
+    mock_server
+}
+
+async fn send(client: &Octocrab) {
+    let result = client._get("/zen").await;
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+async fn received_auth_headers(mock_server: &MockServer) -> Vec<Vec<String>> {
+    mock_server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .map(|request| {
+            request
+                .headers
+                .get_all("authorization")
+                .iter()
+                .map(|value| value.to_str().unwrap().to_string())
+                .collect()
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn should_send_the_auth_of_each_derived_client() {
+    let mock_server = setup_api().await;
+    let base = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .personal_token("base-token".to_string())
+        .build()
+        .unwrap();
+    let first = base.with_auth(Auth::PersonalToken(String::from("first-token").into()));
+    let second = base.with_auth(Auth::UserAccessToken(String::from("second-token").into()));
+
+    send(&first).await;
+    send(&second).await;
+    send(&base).await;
+
+    assert_eq!(
+        received_auth_headers(&mock_server).await,
+        vec![
+            vec!["Bearer first-token".to_string()],
+            vec!["Bearer second-token".to_string()],
+            vec!["Bearer base-token".to_string()],
+        ]
+    );
+}
+
+#[tokio::test]
+async fn should_send_no_auth_for_a_derived_anonymous_client() {
+    let mock_server = setup_api().await;
+    let base = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .personal_token("base-token".to_string())
+        .build()
+        .unwrap();
+    let anonymous = base.with_auth(Auth::None);
+
+    send(&anonymous).await;
+
+    assert_eq!(
+        received_auth_headers(&mock_server).await,
+        vec![Vec::<String>::new()]
+    );
+}
+
+#[tokio::test]
+async fn should_send_the_derived_auth_when_following_page_links() {
+    let mock_server = MockServer::start().await;
+    let issues_path = "/repos/org/some-repo/issues";
+    let issue: Issue = serde_json::from_str(include_str!("resources/issue.json")).unwrap();
+    Mock::given(method("GET"))
+        .and(path(issues_path))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(vec![issue.clone()]))
+        .with_priority(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(issues_path))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header(
+                    "Link",
+                    format!("<{}{issues_path}?page=2>; rel=\"next\"", mock_server.uri()).as_str(),
+                )
+                .set_body_json(vec![issue]),
+        )
+        .mount(&mock_server)
+        .await;
+    let base = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .personal_token("base-token".to_string())
+        .build()
+        .unwrap();
+    let derived = base.with_auth(Auth::PersonalToken(String::from("derived-token").into()));
+
+    let first = derived
+        .issues("org", "some-repo")
+        .list()
+        .send()
+        .await
+        .unwrap();
+    let second = derived.get_page::<Issue>(&first.next).await;
+
+    assert!(
+        second.is_ok(),
+        "expected successful result, got error: {:#?}",
+        second
+    );
+    assert!(second.unwrap().is_some());
+    assert_eq!(
+        received_auth_headers(&mock_server).await,
+        vec![
+            vec!["Bearer derived-token".to_string()],
+            vec!["Bearer derived-token".to_string()],
+        ]
+    );
+}
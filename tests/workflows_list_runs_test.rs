@@ -0,0 +1,85 @@
+use octocrab::{models::workflows::Run, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+
+async fn setup_list_runs_api(runs_path: String) -> MockServer {
+    let run: Run = serde_json::from_str(include_str!("resources/workflow_run.json")).unwrap();
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(runs_path))
+        .and(query_param("branch", "trunk"))
+        .and(query_param("event", "schedule"))
+        .and(query_param("status", "queued"))
+        .and(query_param("actor", "ferris"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "total_count": 1,
+            "workflow_runs": [run]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_list_filtered_runs_for_repo() {
+    let mock_server = setup_list_runs_api(format!("/repos/{OWNER}/{REPO}/actions/runs")).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .workflows(OWNER, REPO)
+        .list_all_runs()
+        .branch("trunk")
+        .event("schedule")
+        .status("queued")
+        .actor("ferris")
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let page = result.unwrap();
+    assert_eq!(page.total_count, Some(1));
+    assert_eq!(page.items[0].head_branch, "trunk");
+}
+
+#[tokio::test]
+async fn should_list_filtered_runs_for_workflow() {
+    let mock_server = setup_list_runs_api(format!(
+        "/repos/{OWNER}/{REPO}/actions/workflows/ci.yml/runs"
+    ))
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .workflows(OWNER, REPO)
+        .list_runs("ci.yml")
+        .branch("trunk")
+        .event("schedule")
+        .status("queued")
+        .actor("ferris")
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().items.len(), 1);
+}